@@ -0,0 +1,124 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Index};
+
+/// 🧩 Derives `pencil_box::array::compact::IsEmpty` for a struct.
+///
+/// # Behavior
+/// - A struct is empty when **every** field is empty, according to each field's own `IsEmpty` impl.
+/// - Fields marked `#[is_empty(skip)]` are ignored. They do not need to implement `IsEmpty`.
+/// - Unit structs, and structs whose fields are all skipped, are always empty.
+/// - Named and tuple structs are supported. Enums and unions are rejected with a compile error.
+/// - Generic structs get a `where FieldType: IsEmpty` bound for each checked field.
+///
+/// # Performance
+/// - The generated `is_empty` short-circuits on the first non-empty field, in declaration order.
+///
+/// # Examples
+/// ```ignore
+/// use pencil_box::array::compact::{compact, IsEmpty};
+///
+/// #[derive(IsEmpty)]
+/// struct Contact {
+///     name: String,
+///     tags: Vec<String>,
+///     #[is_empty(skip)]
+///     id: u64,
+/// }
+/// ```
+#[proc_macro_derive(IsEmpty, attributes(is_empty))]
+pub fn derive_is_empty(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span(),
+                "#[derive(IsEmpty)] is only supported on structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "#[derive(IsEmpty)] is only supported on structs",
+            ))
+        }
+    };
+
+    let mut checks = Vec::new();
+    let mut bounds = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        if is_skipped(field)? {
+            continue;
+        }
+        let ty = &field.ty;
+        let access = match &field.ident {
+            Some(ident) => quote!(self.#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(self.#index)
+            }
+        };
+        checks.push(quote!(::pencil_box::array::compact::IsEmpty::is_empty(&#access)));
+        bounds.push(quote!(#ty: ::pencil_box::array::compact::IsEmpty));
+    }
+
+    let body = if checks.is_empty() {
+        quote!(true)
+    } else {
+        quote!(#(#checks)&&*)
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut predicates = where_clause
+        .map(|clause| {
+            clause
+                .predicates
+                .iter()
+                .map(|p| quote!(#p))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !input.generics.params.is_empty() {
+        predicates.extend(bounds);
+    }
+    let where_clause = if predicates.is_empty() {
+        quote!()
+    } else {
+        quote!(where #(#predicates),*)
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::pencil_box::array::compact::IsEmpty for #name #ty_generics #where_clause {
+            fn is_empty(&self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns `true` if the field carries `#[is_empty(skip)]`.
+fn is_skipped(field: &syn::Field) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("is_empty") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skipped = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported is_empty attribute, expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skipped)
+}
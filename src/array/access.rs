@@ -0,0 +1,123 @@
+/// 🥇 Returns a reference to the first element of a slice, if any.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice.
+///
+/// # Arguments
+/// - `values`: The slice to read from.
+///
+/// # Returns
+/// `Some(&T)` for the first element, or `None` if the slice is empty.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::access::head;
+///
+/// assert_eq!(head(&[1, 2, 3]), Some(&1));
+/// assert_eq!(head::<i32>(&[]), None);
+///
+/// const FIRST_PORT: Option<&u16> = head(&[8080, 8443]);
+/// assert_eq!(FIRST_PORT, Some(&8080));
+/// ```
+pub const fn head<T>(values: &[T]) -> Option<&T> {
+    values.first()
+}
+
+/// 🏁 Returns a reference to the last element of a slice, if any.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice.
+///
+/// # Arguments
+/// - `values`: The slice to read from.
+///
+/// # Returns
+/// `Some(&T)` for the last element, or `None` if the slice is empty.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::access::last;
+///
+/// assert_eq!(last(&[1, 2, 3]), Some(&3));
+/// assert_eq!(last::<i32>(&[]), None);
+/// ```
+pub const fn last<T>(values: &[T]) -> Option<&T> {
+    values.last()
+}
+
+/// ✂️ Returns every element except the first.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice.
+///
+/// # Arguments
+/// - `values`: The slice to read from.
+///
+/// # Returns
+/// A borrowed sub-slice without the first element. Empty input yields an empty slice.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::access::tail;
+///
+/// assert_eq!(tail(&[1, 2, 3]), &[2, 3]);
+/// assert!(tail::<i32>(&[]).is_empty());
+/// ```
+pub const fn tail<T>(values: &[T]) -> &[T] {
+    match values {
+        [_, rest @ ..] => rest,
+        [] => &[],
+    }
+}
+
+/// ✂️ Returns every element except the last.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice.
+///
+/// # Arguments
+/// - `values`: The slice to read from.
+///
+/// # Returns
+/// A borrowed sub-slice without the last element. Empty input yields an empty slice.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::access::initial;
+///
+/// assert_eq!(initial(&[1, 2, 3]), &[1, 2]);
+/// assert!(initial::<i32>(&[]).is_empty());
+/// ```
+pub const fn initial<T>(values: &[T]) -> &[T] {
+    match values {
+        [rest @ .., _] => rest,
+        [] => &[],
+    }
+}
+
+/// 🔚 Returns up to the last `n` elements of a slice.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice.
+///
+/// # Arguments
+/// - `values`: The slice to read from.
+/// - `n`: The maximum number of trailing elements to return.
+///
+/// # Returns
+/// A borrowed sub-slice holding the last `min(n, values.len())` elements.
+///
+/// # Behavior
+/// - The borrowing counterpart of [`take_end`](crate::array::take_end::take_end).
+/// - Like every helper in this module, usable in `const` contexts.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::access::last_n;
+///
+/// assert_eq!(last_n(&[1, 2, 3, 4], 2), &[3, 4]);
+/// assert_eq!(last_n(&[1, 2], 5), &[1, 2]);
+/// ```
+pub const fn last_n<T>(values: &[T], n: usize) -> &[T] {
+    values.split_at(values.len().saturating_sub(n)).1
+}
@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+
+/// ⬇️ Returns the index of the smallest element in a slice.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`], so floats are supported.
+///
+/// # Arguments
+/// - `values`: The slice to search.
+///
+/// # Returns
+/// - `Some(index)` of the smallest element, or
+/// - `None` if the slice is empty or contains only incomparable values.
+///
+/// # Behavior
+/// - On ties, the **first** index is returned.
+/// - Values that are not comparable with themselves (such as `f64::NAN`) are skipped.
+///
+/// # Performance
+/// - **O(n)** single pass with no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::arg_extremes::argmin;
+///
+/// assert_eq!(argmin(&[3.5, 1.0, f64::NAN, 1.0]), Some(1));
+/// assert_eq!(argmin::<i32>(&[]), None);
+/// ```
+pub fn argmin<T: PartialOrd>(values: &[T]) -> Option<usize> {
+    arg_best(values, Ordering::Less)
+}
+
+/// ⬆️ Returns the index of the largest element in a slice.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`], so floats are supported.
+///
+/// # Arguments
+/// - `values`: The slice to search.
+///
+/// # Returns
+/// - `Some(index)` of the largest element, or
+/// - `None` if the slice is empty or contains only incomparable values.
+///
+/// # Behavior
+/// - On ties, the **first** index is returned.
+/// - Values that are not comparable with themselves (such as `f64::NAN`) are skipped.
+///
+/// # Performance
+/// - **O(n)** single pass with no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::arg_extremes::argmax;
+///
+/// assert_eq!(argmax(&[2, 9, 4, 9]), Some(1));
+/// assert_eq!(argmax(&[f64::NAN, 0.5]), Some(1));
+/// ```
+pub fn argmax<T: PartialOrd>(values: &[T]) -> Option<usize> {
+    arg_best(values, Ordering::Greater)
+}
+
+/// ⬇️ Returns the index of the element with the smallest key.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A function of the form `Fn(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: The slice to search.
+/// - `key_fn`: Extracts the key to compare.
+///
+/// # Returns
+/// `Some(index)` of the element with the smallest key (first on ties), or `None` if the slice is empty.
+///
+/// # Performance
+/// - **O(n)**; `key_fn` is called once per element.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::arg_extremes::argmin_by_key;
+///
+/// let words = ["pear", "fig", "banana", "kiwi"];
+/// assert_eq!(argmin_by_key(&words, |w| w.len()), Some(1));
+/// ```
+pub fn argmin_by_key<T, K: Ord, F: Fn(&T) -> K>(values: &[T], key_fn: F) -> Option<usize> {
+    arg_best_by_key(values, key_fn, Ordering::Less)
+}
+
+/// ⬆️ Returns the index of the element with the largest key.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A function of the form `Fn(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: The slice to search.
+/// - `key_fn`: Extracts the key to compare.
+///
+/// # Returns
+/// `Some(index)` of the element with the largest key (first on ties), or `None` if the slice is empty.
+///
+/// # Performance
+/// - **O(n)**; `key_fn` is called once per element.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::arg_extremes::argmax_by_key;
+///
+/// let scores = [("ana", 7), ("bo", 9), ("cy", 9)];
+/// assert_eq!(argmax_by_key(&scores, |(_, score)| *score), Some(1));
+/// ```
+pub fn argmax_by_key<T, K: Ord, F: Fn(&T) -> K>(values: &[T], key_fn: F) -> Option<usize> {
+    arg_best_by_key(values, key_fn, Ordering::Greater)
+}
+
+/// ⬇️ Returns the position and a reference to the element with the smallest key.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A function of the form `Fn(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: The slice to search.
+/// - `key_fn`: Extracts the key to compare.
+///
+/// # Returns
+/// `Some((index, &element))` for the smallest key (first on ties), or `None` if the slice is empty.
+///
+/// # Behavior
+/// - Same selection as [`argmin_by_key`], also handing back the element.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::arg_extremes::min_by_key_with_index;
+///
+/// let tasks = [("build", 30), ("lint", 5), ("test", 5)];
+/// assert_eq!(min_by_key_with_index(&tasks, |(_, secs)| *secs), Some((1, &("lint", 5))));
+/// ```
+pub fn min_by_key_with_index<T, K: Ord, F: Fn(&T) -> K>(
+    values: &[T],
+    key_fn: F,
+) -> Option<(usize, &T)> {
+    argmin_by_key(values, key_fn).map(|index| (index, &values[index]))
+}
+
+/// ⬆️ Returns the position and a reference to the element with the largest key.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A function of the form `Fn(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: The slice to search.
+/// - `key_fn`: Extracts the key to compare.
+///
+/// # Returns
+/// `Some((index, &element))` for the largest key (first on ties), or `None` if the slice is empty.
+///
+/// # Behavior
+/// - Same selection as [`argmax_by_key`], also handing back the element.
+/// - Unlike [`Iterator::max_by_key`], ties resolve to the **first** element, not the last.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::arg_extremes::max_by_key_with_index;
+///
+/// let mut sizes = vec![120, 640, 80];
+/// if let Some((index, _)) = max_by_key_with_index(&sizes, |size| *size) {
+///     sizes[index] = 0;
+/// }
+/// assert_eq!(sizes, vec![120, 0, 80]);
+/// ```
+pub fn max_by_key_with_index<T, K: Ord, F: Fn(&T) -> K>(
+    values: &[T],
+    key_fn: F,
+) -> Option<(usize, &T)> {
+    argmax_by_key(values, key_fn).map(|index| (index, &values[index]))
+}
+
+/// Returns the first index whose value beats every other in the direction of `wanted`.
+fn arg_best<T: PartialOrd>(values: &[T], wanted: Ordering) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    for (index, value) in values.iter().enumerate() {
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+        match best {
+            Some(current) if value.partial_cmp(&values[current]) != Some(wanted) => {}
+            _ => best = Some(index),
+        }
+    }
+    best
+}
+
+/// Returns the first index whose key beats every other in the direction of `wanted`.
+fn arg_best_by_key<T, K: Ord, F: Fn(&T) -> K>(
+    values: &[T],
+    key_fn: F,
+    wanted: Ordering,
+) -> Option<usize> {
+    let mut best: Option<(usize, K)> = None;
+    for (index, value) in values.iter().enumerate() {
+        let key = key_fn(value);
+        match &best {
+            Some((_, current)) if key.cmp(current) != wanted => {}
+            _ => best = Some((index, key)),
+        }
+    }
+    best.map(|(index, _)| index)
+}
@@ -0,0 +1,109 @@
+use crate::array::stable_hash::stable_hash;
+use std::hash::Hash;
+
+/// 🔄 Distributes items across `worker_count` buckets in round-robin order.
+///
+/// # Type Parameters
+/// - `T`: The item type. Items are moved, so no trait bounds are required.
+///
+/// # Arguments
+/// - `items`: The items to distribute. Ownership is taken to avoid cloning.
+/// - `worker_count`: The number of buckets. Must be greater than 0.
+///
+/// # Returns
+/// Returns a [`Result`]:
+/// - `Ok(Vec<Vec<T>>)` with exactly `worker_count` buckets; item `i` lands in bucket `i % worker_count`.
+/// - `Err(&'static str)` if `worker_count` is `0`.
+///
+/// # Behavior
+/// - Bucket sizes differ by at most one.
+/// - Items keep their relative order within each bucket.
+/// - Buckets may be empty when there are fewer items than workers.
+///
+/// # Performance
+/// - **O(n)**; each bucket is pre-allocated with its final size.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::assign::assign_round_robin;
+///
+/// let buckets = assign_round_robin(vec!["a", "b", "c", "d", "e"], 2).unwrap();
+/// assert_eq!(buckets, vec![vec!["a", "c", "e"], vec!["b", "d"]]);
+/// assert!(assign_round_robin(vec![1], 0).is_err());
+/// ```
+pub fn assign_round_robin<T>(
+    items: Vec<T>,
+    worker_count: usize,
+) -> Result<Vec<Vec<T>>, &'static str> {
+    if worker_count == 0 {
+        return Err("worker_count must be greater than 0");
+    }
+
+    let base = items.len() / worker_count;
+    let extra = items.len() % worker_count;
+    let mut buckets: Vec<Vec<T>> = (0..worker_count)
+        .map(|worker| Vec::with_capacity(base + usize::from(worker < extra)))
+        .collect();
+
+    for (index, item) in items.into_iter().enumerate() {
+        buckets[index % worker_count].push(item);
+    }
+    Ok(buckets)
+}
+
+/// #️⃣ Distributes items across `worker_count` buckets by hashing a key, so equal keys always share a bucket.
+///
+/// # Type Parameters
+/// - `T`: The item type. Items are moved, so no trait bounds are required.
+/// - `K`: The key type. Must implement [`Hash`].
+/// - `F`: A function of the form `Fn(&T) -> K` extracting the sharding key.
+///
+/// # Arguments
+/// - `items`: The items to distribute. Ownership is taken to avoid cloning.
+/// - `worker_count`: The number of buckets. Must be greater than 0.
+/// - `key_fn`: Returns the key used to pick a bucket.
+///
+/// # Returns
+/// Returns a [`Result`]:
+/// - `Ok(Vec<Vec<T>>)` with exactly `worker_count` buckets; each item lands in `hash(key) % worker_count`.
+/// - `Err(&'static str)` if `worker_count` is `0`.
+///
+/// # Behavior
+/// - Deterministic: uses [`stable_hash`], so the same key maps to the same bucket across calls,
+///   processes, platforms, and Rust releases.
+/// - Items keep their relative order within each bucket. Bucket sizes depend on the key distribution.
+///
+/// # Performance
+/// - **O(n)** with one key hash per item.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::assign::assign_by_hash;
+///
+/// let jobs = vec![("tenant-a", 1), ("tenant-b", 2), ("tenant-a", 3)];
+/// let buckets = assign_by_hash(jobs, 4, |(tenant, _)| *tenant).unwrap();
+///
+/// assert_eq!(buckets.len(), 4);
+/// let bucket_a = buckets.iter().find(|b| b.contains(&("tenant-a", 1))).unwrap();
+/// assert!(bucket_a.contains(&("tenant-a", 3)));
+/// ```
+pub fn assign_by_hash<T, K, F>(
+    items: Vec<T>,
+    worker_count: usize,
+    key_fn: F,
+) -> Result<Vec<Vec<T>>, &'static str>
+where
+    K: Hash,
+    F: Fn(&T) -> K,
+{
+    if worker_count == 0 {
+        return Err("worker_count must be greater than 0");
+    }
+
+    let mut buckets: Vec<Vec<T>> = (0..worker_count).map(|_| Vec::new()).collect();
+    for item in items {
+        let bucket = (stable_hash(&key_fn(&item)) % worker_count as u64) as usize;
+        buckets[bucket].push(item);
+    }
+    Ok(buckets)
+}
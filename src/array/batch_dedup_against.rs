@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// 👀 Remembers every value passed through [`filter_new`], across any number of batches.
+///
+/// Hold one `SeenSet` for the lifetime of a stream and call [`filter_new`] per incoming batch.
+/// Unlike calling [`difference`](crate::array::difference::difference) against all previous
+/// batches, the hash set is built once and grows incrementally.
+///
+/// # Type Parameters
+/// - `T`: The value type. Must implement [`Eq`] and [`Hash`].
+///
+/// # Performance
+/// - Memory grows with the number of distinct values seen; call [`clear`](Self::clear) to reset.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::batch_dedup_against::{filter_new, SeenSet};
+///
+/// let mut seen = SeenSet::new();
+/// assert_eq!(filter_new(&mut seen, vec![1, 2, 3]), vec![1, 2, 3]);
+/// assert_eq!(filter_new(&mut seen, vec![2, 3, 4]), vec![4]);
+/// assert_eq!(seen.len(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeenSet<T: Eq + Hash> {
+    seen: HashSet<T>,
+}
+
+impl<T: Eq + Hash> SeenSet<T> {
+    /// Creates an empty `SeenSet`.
+    pub fn new() -> Self {
+        Self {
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Creates an empty `SeenSet` with room for `capacity` values before reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            seen: HashSet::with_capacity(capacity),
+        }
+    }
+
+    /// Returns `true` if `value` has already been seen.
+    pub fn contains(&self, value: &T) -> bool {
+        self.seen.contains(value)
+    }
+
+    /// Marks `value` as seen. Returns `true` if it had not been seen before.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.seen.insert(value)
+    }
+
+    /// Returns the number of distinct values seen.
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns `true` if nothing has been seen yet.
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Forgets every seen value.
+    pub fn clear(&mut self) {
+        self.seen.clear();
+    }
+}
+
+impl<T: Eq + Hash> Default for SeenSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 🆕 Returns the values of `batch` that `seen` has not seen before, and records them.
+///
+/// # Type Parameters
+/// - `T`: The value type. Must implement [`Clone`], [`Eq`], and [`Hash`].
+/// - `I`: Any iterable of owned values.
+///
+/// # Arguments
+/// - `seen`: The persistent set of values from earlier batches. Updated in place.
+/// - `batch`: The incoming values.
+///
+/// # Returns
+/// A `Vec<T>` with the new values, in batch order.
+///
+/// # Behavior
+/// - Duplicates within the same batch are also removed; the first occurrence is kept.
+/// - Values already in `seen` are dropped.
+///
+/// # Performance
+/// - **O(n)** for a batch of `n` values, independent of how many values were seen before.
+/// - One `clone()` per new value, stored in `seen`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::batch_dedup_against::{filter_new, SeenSet};
+///
+/// let mut seen = SeenSet::new();
+/// let first = filter_new(&mut seen, ["a", "b", "a"]);
+/// let second = filter_new(&mut seen, ["b", "c"]);
+///
+/// assert_eq!(first, vec!["a", "b"]);
+/// assert_eq!(second, vec!["c"]);
+/// ```
+pub fn filter_new<T, I>(seen: &mut SeenSet<T>, batch: I) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+    I: IntoIterator<Item = T>,
+{
+    batch
+        .into_iter()
+        .filter(|value| {
+            if seen.contains(value) {
+                false
+            } else {
+                seen.insert(value.clone())
+            }
+        })
+        .collect()
+}
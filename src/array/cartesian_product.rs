@@ -0,0 +1,271 @@
+use std::iter::FusedIterator;
+
+/// ✖️ Returns every pair `(a, b)` with `a` from `left` and `b` from `right`.
+///
+/// # Type Parameters
+/// - `A`: The element type of `left`. Must implement [`Clone`].
+/// - `B`: The element type of `right`. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `left`: The first collection.
+/// - `right`: The second collection.
+///
+/// # Returns
+/// A `Vec<(A, B)>` of length `left.len() * right.len()`, ordered by `left` first, then `right`.
+///
+/// # Behavior
+/// - If either input is empty, the result is empty.
+///
+/// # Performance
+/// - ✅ **O(|left| × |right|)**, with the output allocated once at its final size.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::cartesian_product::cartesian_product;
+///
+/// let grid = cartesian_product(&["linux", "macos"], &[1.0, 2.0]);
+/// assert_eq!(
+///     grid,
+///     vec![("linux", 1.0), ("linux", 2.0), ("macos", 1.0), ("macos", 2.0)]
+/// );
+/// ```
+pub fn cartesian_product<A: Clone, B: Clone>(left: &[A], right: &[B]) -> Vec<(A, B)> {
+    let mut product = Vec::with_capacity(left.len().saturating_mul(right.len()));
+    for a in left {
+        for b in right {
+            product.push((a.clone(), b.clone()));
+        }
+    }
+    product
+}
+
+/// ✖️ Returns every combination that takes one element from each collection, in order.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+/// - `S`: A slice-like container that implements `AsRef<[T]>`, such as `Vec<T>` or `&[T]`.
+///
+/// # Arguments
+/// - `sets`: The collections to combine. Combination `i` holds one element of each.
+///
+/// # Returns
+/// A `Vec<Vec<T>>` in lexicographic order: the last collection varies fastest.
+///
+/// # Behavior
+/// - If any collection is empty, the result is empty.
+/// - If `sets` itself is empty, the result is a single empty combination, `vec![vec![]]`.
+///
+/// # Performance
+/// - **O(k × ∏|set|)** for `k` collections; the output is allocated once at its final size.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::cartesian_product::cartesian_product_all;
+///
+/// let matrix = cartesian_product_all(&[vec!["debug", "release"], vec!["x86", "arm"], vec!["gnu"]]);
+/// assert_eq!(matrix.len(), 4);
+/// assert_eq!(matrix[0], vec!["debug", "x86", "gnu"]);
+/// assert_eq!(matrix[3], vec!["release", "arm", "gnu"]);
+/// ```
+pub fn cartesian_product_all<T: Clone, S: AsRef<[T]>>(sets: &[S]) -> Vec<Vec<T>> {
+    let total = sets.iter().fold(1usize, |total, set| {
+        total.saturating_mul(set.as_ref().len())
+    });
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut product = Vec::with_capacity(total);
+    let mut positions = vec![0usize; sets.len()];
+    loop {
+        product.push(
+            sets.iter()
+                .zip(&positions)
+                .map(|(set, &position)| set.as_ref()[position].clone())
+                .collect(),
+        );
+
+        // Advance the positions like an odometer, last collection first.
+        let mut slot = sets.len();
+        loop {
+            if slot == 0 {
+                return product;
+            }
+            slot -= 1;
+            positions[slot] += 1;
+            if positions[slot] < sets[slot].as_ref().len() {
+                break;
+            }
+            positions[slot] = 0;
+        }
+    }
+}
+
+/// 🔢 Returns every length-`k` tuple over a single alphabet, in lexicographic order.
+///
+/// Equivalent to [`cartesian_product_all`] with `k` copies of `alphabet`. For large spaces, use
+/// [`cartesian_power_iter`] to iterate or sample without materializing everything.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `alphabet`: The values each position can take.
+/// - `k`: The tuple length.
+///
+/// # Returns
+/// A `Vec<Vec<T>>` of `alphabet.len()^k` tuples; the last position varies fastest.
+///
+/// # Behavior
+/// - If `k == 0`, the result is a single empty tuple, `vec![vec![]]`.
+/// - If `alphabet` is empty and `k > 0`, the result is empty.
+///
+/// # Performance
+/// - **O(k × n^k)**, which grows very quickly.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::cartesian_product::cartesian_power;
+///
+/// let bits = cartesian_power(&[0, 1], 2);
+/// assert_eq!(bits, vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]]);
+/// ```
+pub fn cartesian_power<T: Clone>(alphabet: &[T], k: usize) -> Vec<Vec<T>> {
+    cartesian_power_iter(alphabet, k).collect()
+}
+
+/// 🔢 Lazy iterator over the length-`k` tuples of an alphabet, with random access.
+///
+/// Created by [`cartesian_power_iter`]. Tuples are decoded from their index on demand, so
+/// [`nth_product`](CartesianPower::nth_product) and [`Iterator::nth`] are **O(k)** regardless of
+/// how large the product space is.
+#[derive(Debug, Clone)]
+pub struct CartesianPower<'a, T> {
+    alphabet: &'a [T],
+    k: usize,
+    total: Option<usize>,
+    next: usize,
+    exhausted: bool,
+}
+
+impl<T: Clone> CartesianPower<'_, T> {
+    /// Returns the number of tuples, or `None` if it does not fit in a `usize`.
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Returns the tuple at `index` in lexicographic order, or `None` if it is out of range.
+    ///
+    /// Does not advance the iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use pencil_box::array::cartesian_product::cartesian_power_iter;
+    ///
+    /// let codes = cartesian_power_iter(&['a', 'b', 'c'], 3);
+    /// assert_eq!(codes.total(), Some(27));
+    /// assert_eq!(codes.nth_product(5), Some(vec!['a', 'b', 'c']));
+    /// assert_eq!(codes.nth_product(26), Some(vec!['c', 'c', 'c']));
+    /// assert_eq!(codes.nth_product(27), None);
+    /// ```
+    pub fn nth_product(&self, index: usize) -> Option<Vec<T>> {
+        if self.total.is_some_and(|total| index >= total) {
+            return None;
+        }
+        let base = self.alphabet.len();
+        let mut tuple = Vec::with_capacity(self.k);
+        let mut remaining = index;
+        for _ in 0..self.k {
+            tuple.push(self.alphabet[remaining % base].clone());
+            remaining /= base;
+        }
+        tuple.reverse();
+        Some(tuple)
+    }
+}
+
+impl<T: Clone> Iterator for CartesianPower<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.exhausted {
+            return None;
+        }
+        let tuple = self.nth_product(self.next);
+        match (tuple.is_some(), self.next.checked_add(1)) {
+            (true, Some(next)) => self.next = next,
+            _ => self.exhausted = true,
+        }
+        tuple
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Vec<T>> {
+        match self.next.checked_add(n) {
+            Some(index) if !self.exhausted => {
+                self.next = index;
+                self.next()
+            }
+            _ => {
+                self.exhausted = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted {
+            return (0, Some(0));
+        }
+        match self.total {
+            Some(total) => {
+                let remaining = total.saturating_sub(self.next);
+                (remaining, Some(remaining))
+            }
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: Clone> FusedIterator for CartesianPower<'_, T> {}
+
+/// 🔢 Returns a lazy iterator over every length-`k` tuple of `alphabet`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`] to build each tuple.
+///
+/// # Arguments
+/// - `alphabet`: The values each position can take.
+/// - `k`: The tuple length.
+///
+/// # Returns
+/// A [`CartesianPower`] iterator in the same order as [`cartesian_power`].
+///
+/// # Behavior
+/// - Same edge cases as [`cartesian_power`].
+/// - When `alphabet.len()^k` exceeds `usize::MAX`, [`CartesianPower::total`] is `None`, and only
+///   the first `usize::MAX` tuples are reachable by index.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::cartesian_product::cartesian_power_iter;
+///
+/// // Skip straight to the 1000th PIN without generating the first 999.
+/// let mut pins = cartesian_power_iter(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], 4);
+/// assert_eq!(pins.nth(999), Some(vec![0, 9, 9, 9]));
+/// assert_eq!(pins.next(), Some(vec![1, 0, 0, 0]));
+/// ```
+pub fn cartesian_power_iter<T: Clone>(alphabet: &[T], k: usize) -> CartesianPower<'_, T> {
+    let total = match alphabet.len() {
+        0 | 1 if k > 0 => Some(alphabet.len()),
+        base => u32::try_from(k)
+            .ok()
+            .and_then(|exponent| base.checked_pow(exponent)),
+    };
+    CartesianPower {
+        alphabet,
+        k,
+        total,
+        next: 0,
+        exhausted: false,
+    }
+}
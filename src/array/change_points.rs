@@ -0,0 +1,54 @@
+/// 🔀 Returns the indices where consecutive elements change, according to a comparator.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice.
+/// - `C`: A comparator that receives each consecutive pair `(previous, current)` and returns
+///   `true` when the pair marks a change.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be scanned, e.g. an event stream.
+/// - `is_change`: The comparator applied to every adjacent pair.
+///
+/// # Returns
+/// A `Vec<usize>` containing the index of the **second** element of each pair for which
+/// `is_change` returned `true` — i.e. the index at which the new state begins.
+///
+/// # Behavior
+/// - Pairs are visited in order: `(values[0], values[1])`, `(values[1], values[2])`, …
+/// - Index `0` is never reported, since the first element has no predecessor.
+/// - Slices with fewer than two elements yield an empty vector.
+///
+/// # Performance
+/// - ✅ Linear time complexity **O(n)**; the comparator is called exactly `n - 1` times.
+/// - 🚫 No cloning; elements are compared by reference through `slice::windows(2)`.
+///
+/// # Examples
+///
+/// ### 🔀 Detect state transitions
+/// ```
+/// use pencil_box::array::change_points::change_points;
+///
+/// let states = ["idle", "idle", "busy", "busy", "idle"];
+/// assert_eq!(change_points(&states, |a, b| a != b), vec![2, 4]);
+/// ```
+///
+/// ### 📈 Detect jumps larger than a threshold
+/// ```
+/// use pencil_box::array::change_points::change_points;
+///
+/// let readings = [10.0, 10.2, 14.9, 15.0, 9.8];
+/// let jumps = change_points(&readings, |a: &f64, b: &f64| (b - a).abs() > 2.0);
+/// assert_eq!(jumps, vec![2, 4]);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all valid inputs.
+pub fn change_points<T, C: Fn(&T, &T) -> bool>(values: &[T], is_change: C) -> Vec<usize> {
+    let mut indexes = Vec::new();
+    for (index, pair) in values.windows(2).enumerate() {
+        if is_change(&pair[0], &pair[1]) {
+            indexes.push(index + 1);
+        }
+    }
+    indexes
+}
@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+
+/// ❗ Error returned by [`try_insert_at`] and [`try_replace_at`] for an out-of-bounds index.
+///
+/// The rejected value is handed back, so nothing is lost for non-`Clone` types.
+///
+/// # Fields
+/// - `index`: The index that was requested.
+/// - `len`: The length of the vector at the time of the call.
+/// - `value`: The value that was not inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexError<T> {
+    pub index: usize,
+    pub len: usize,
+    pub value: T,
+}
+
+impl<T> fmt::Display for IndexError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl<T: Debug> Error for IndexError<T> {}
+
+/// ➕ Inserts `value` at `index`, returning an error instead of panicking when out of bounds.
+///
+/// A checked counterpart to [`Vec::insert`].
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector.
+/// - `index`: The position for the new element. Any index up to and including `values.len()`
+///   is valid; `values.len()` appends.
+/// - `value`: The element to insert.
+///
+/// # Returns
+/// - `Ok(())` once `value` has been inserted.
+/// - `Err(IndexError)` if `index > values.len()`, carrying `value` back; the vector is unchanged.
+///
+/// # Performance
+/// - **O(n - index)**, shifting the later elements, exactly like [`Vec::insert`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::checked_index::try_insert_at;
+///
+/// let mut steps = vec!["build", "deploy"];
+/// try_insert_at(&mut steps, 1, "test").unwrap();
+/// assert_eq!(steps, vec!["build", "test", "deploy"]);
+///
+/// let error = try_insert_at(&mut steps, 9, "lint").unwrap_err();
+/// assert_eq!(error.value, "lint");
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all indices.
+pub fn try_insert_at<T>(values: &mut Vec<T>, index: usize, value: T) -> Result<(), IndexError<T>> {
+    if index > values.len() {
+        return Err(IndexError {
+            index,
+            len: values.len(),
+            value,
+        });
+    }
+    values.insert(index, value);
+    Ok(())
+}
+
+/// 🔁 Replaces the element at `index`, returning the old one, or an error when out of bounds.
+///
+/// A checked counterpart to `std::mem::replace(&mut values[index], value)`.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice. No specific traits are required.
+///
+/// # Arguments
+/// - `values`: The elements. Accepts a `&mut Vec<T>` or any mutable slice.
+/// - `index`: The position to overwrite. Must be `< values.len()`.
+/// - `value`: The new element.
+///
+/// # Returns
+/// - `Ok(old)` with the replaced element.
+/// - `Err(IndexError)` if `index >= values.len()`, carrying `value` back; nothing is changed.
+///
+/// # Performance
+/// - ✅ **O(1)**; no shifting or cloning.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::checked_index::try_replace_at;
+///
+/// let mut slots = vec![Some(1), None, Some(3)];
+/// assert_eq!(try_replace_at(&mut slots, 1, Some(2)), Ok(None));
+/// assert_eq!(slots, vec![Some(1), Some(2), Some(3)]);
+/// assert!(try_replace_at(&mut slots, 3, None).is_err());
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all indices.
+pub fn try_replace_at<T>(values: &mut [T], index: usize, value: T) -> Result<T, IndexError<T>> {
+    match values.get_mut(index) {
+        Some(slot) => Ok(std::mem::replace(slot, value)),
+        None => Err(IndexError {
+            index,
+            len: values.len(),
+            value,
+        }),
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 /// 🧩 Splits a slice into chunks of a specified size, cloning elements into new `Vec`s.
 ///
 /// # Type Parameters
@@ -82,3 +84,61 @@ pub fn chunk<T: Clone>(array: &[T], chunk_size: usize) -> Result<Vec<Vec<T>>, &'
     Ok(chunks)
 }
 
+/// 📍 A chunk produced by [`chunk_with_ranges`], paired with its range in the source slice.
+pub type RangedChunk<T> = (Range<usize>, Vec<T>);
+
+/// 🧩 Splits a slice into chunks like [`chunk`], pairing each chunk with its source range.
+///
+/// Useful when a problem found while processing a chunk (a validation error, a failed batch
+/// upload) must be reported against positions in the original slice.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the input slice. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `array`: A reference to a slice of elements to be chunked.
+/// - `chunk_size`: The number of elements per chunk. Must be greater than 0.
+///
+/// # Returns
+/// Returns a [`Result`]:
+/// - `Ok(Vec<RangedChunk<T>>)` of `(range, chunk)` pairs, where `chunk == array[range]`.
+/// - `Err(&'static str)` if `chunk_size` is `0`.
+///
+/// # Behavior
+/// - Chunks are identical to those returned by [`chunk`]; the ranges are contiguous and cover
+///   `0..array.len()`.
+/// - If `array` is empty, returns an empty vector.
+///
+/// # Performance
+/// - **O(n)** time and memory, with the outer vector allocated once.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::chunk::chunk_with_ranges;
+///
+/// let rows = ["ok", "ok", "bad", "ok", "ok"];
+/// for (range, batch) in chunk_with_ranges(&rows, 2).unwrap() {
+///     if let Some(offset) = batch.iter().position(|row| *row == "bad") {
+///         assert_eq!(range.start + offset, 2);
+///     }
+/// }
+///
+/// let chunks = chunk_with_ranges(&[1, 2, 3], 2).unwrap();
+/// assert_eq!(chunks, vec![(0..2, vec![1, 2]), (2..3, vec![3])]);
+/// ```
+pub fn chunk_with_ranges<T: Clone>(
+    array: &[T],
+    chunk_size: usize,
+) -> Result<Vec<RangedChunk<T>>, &'static str> {
+    if chunk_size == 0 {
+        return Err("chunk_size must be greater than 0");
+    }
+
+    let mut chunks = Vec::with_capacity(array.len().div_ceil(chunk_size));
+    for (index, chunk) in array.chunks(chunk_size).enumerate() {
+        let start = index * chunk_size;
+        chunks.push((start..start + chunk.len(), chunk.to_vec()));
+    }
+
+    Ok(chunks)
+}
@@ -0,0 +1,63 @@
+/// 🔃 Turns parallel column vectors into row-wise batches of at most `batch_size` rows.
+///
+/// Columnar data (one `Vec` per field) is convenient to build, but many batch APIs expect
+/// rows. This transposes and batches in one step.
+///
+/// # Type Parameters
+/// - `T`: The cell type. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `columns`: The columns, all of the same length. Row `i` is `columns[0][i], columns[1][i], …`.
+/// - `batch_size`: The maximum number of rows per batch.
+///
+/// # Returns
+/// - `Ok(Vec<Vec<Vec<T>>>)`: batches of rows, each row holding one cell per column.
+/// - `Err(&'static str)` if `batch_size` is `0` or the columns differ in length.
+///
+/// # Behavior
+/// - Every batch has exactly `batch_size` rows, except possibly the last.
+/// - No columns, or columns with no rows, yield `Ok(vec![])`.
+///
+/// # Performance
+/// - ✅ **O(rows × columns)**; every cell is cloned exactly once and every row and batch is
+///   allocated at its final size.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::chunk_transpose::chunk_transpose;
+///
+/// let ids = vec!["1", "2", "3"];
+/// let names = vec!["ana", "bo", "cy"];
+///
+/// let batches = chunk_transpose(&[ids, names], 2).unwrap();
+/// assert_eq!(
+///     batches,
+///     vec![
+///         vec![vec!["1", "ana"], vec!["2", "bo"]],
+///         vec![vec!["3", "cy"]],
+///     ]
+/// );
+/// ```
+pub fn chunk_transpose<T: Clone>(
+    columns: &[Vec<T>],
+    batch_size: usize,
+) -> Result<Vec<Vec<Vec<T>>>, &'static str> {
+    if batch_size == 0 {
+        return Err("batch_size must be greater than 0");
+    }
+
+    let rows = columns.first().map_or(0, Vec::len);
+    if columns.iter().any(|column| column.len() != rows) {
+        return Err("all columns must have the same length");
+    }
+
+    let mut batches = Vec::with_capacity(rows.div_ceil(batch_size));
+    for start in (0..rows).step_by(batch_size) {
+        let end = (start + batch_size).min(rows);
+        let batch = (start..end)
+            .map(|row| columns.iter().map(|column| column[row].clone()).collect())
+            .collect();
+        batches.push(batch);
+    }
+    Ok(batches)
+}
@@ -0,0 +1,67 @@
+/// 📏 Clamps the length of a vector into `min..=max`, truncating or padding in place.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. Must implement [`Clone`] so `pad_value` can be repeated.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to resize.
+/// - `min`: The minimum length. Shorter vectors are padded up to this length.
+/// - `max`: The maximum length. Longer vectors are truncated down to this length.
+/// - `pad_value`: The value appended (cloned as needed) when padding.
+///
+/// # Returns
+/// Returns a [`Result`]:
+/// - `Ok(())` once the vector length lies within `min..=max`.
+/// - `Err(&'static str)` if `min > max`; the vector is left untouched.
+///
+/// # Behavior
+/// - If `values.len() > max`, the trailing elements are dropped.
+/// - If `values.len() < min`, clones of `pad_value` are appended until the length is `min`.
+/// - Otherwise the vector is left unchanged.
+/// - Useful for fixed-size protocol fields, where `min == max`.
+///
+/// # Performance
+/// - ✅ Truncation is **O(k)** in the number of removed elements and never reallocates.
+/// - ✅ Padding reserves the missing capacity once and clones `pad_value` at most `min - len - 1` times.
+///
+/// # Examples
+///
+/// ### 📏 Fixed-size field
+/// ```
+/// use pencil_box::array::clamp_len::clamp_len;
+///
+/// let mut field = vec![b'A', b'B'];
+/// clamp_len(&mut field, 4, 4, b' ').unwrap();
+/// assert_eq!(field, b"AB  ".to_vec());
+///
+/// let mut field = b"TOO LONG".to_vec();
+/// clamp_len(&mut field, 4, 4, b' ').unwrap();
+/// assert_eq!(field, b"TOO ".to_vec());
+/// ```
+///
+/// ### ⚠️ Invalid bounds return an error
+/// ```
+/// use pencil_box::array::clamp_len::clamp_len;
+///
+/// let mut values = vec![1, 2, 3];
+/// assert!(clamp_len(&mut values, 5, 2, 0).is_err());
+/// assert_eq!(values, vec![1, 2, 3]);
+/// ```
+pub fn clamp_len<T: Clone>(
+    values: &mut Vec<T>,
+    min: usize,
+    max: usize,
+    pad_value: T,
+) -> Result<(), &'static str> {
+    if min > max {
+        return Err("min must not be greater than max");
+    }
+
+    if values.len() > max {
+        values.truncate(max);
+    } else if values.len() < min {
+        values.resize(min, pad_value);
+    }
+
+    Ok(())
+}
@@ -1,3 +1,35 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Derive macro generating an [`IsEmpty`] impl for structs, available with the `derive` feature.
+///
+/// A struct is empty when all of its fields are empty. Fields marked `#[is_empty(skip)]` are ignored.
+///
+/// ```
+/// use pencil_box::array::compact::{compact, IsEmpty};
+///
+/// #[derive(IsEmpty)]
+/// struct Draft {
+///     title: String,
+///     body: String,
+///     #[is_empty(skip)]
+///     revision: u32,
+/// }
+///
+/// let mut drafts = vec![
+///     Draft { title: String::new(), body: String::new(), revision: 3 },
+///     Draft { title: "Hello".into(), body: String::new(), revision: 1 },
+/// ];
+/// compact(&mut drafts);
+/// assert_eq!(drafts.len(), 1);
+/// ```
+#[cfg(feature = "derive")]
+pub use pencil_box_derive::IsEmpty;
+
 /// A trait defining an `is_empty` method for various types.
 ///
 /// This trait provides a generic way to determine if a value of a given type
@@ -6,11 +38,17 @@
 /// booleans, and numeric types.
 ///
 /// # Implementations:
-/// - `String` and `&str`: Returns `true` if the string contains no characters.
+/// - `String` and `str`: Returns `true` if the string contains no characters.
+/// - `OsString`, `OsStr`, `PathBuf`, and `Path`: Returns `true` if they contain no characters.
+/// - `char`: Returns `true` for `'\0'` and whitespace characters.
 /// - `Vec<T>`: Returns `true` if the vector contains no elements.
+/// - `VecDeque<T>`, `HashMap<K, V>`, `HashSet<T>`, `BTreeMap<K, V>`, `BTreeSet<T>`: Returns `true` if the collection contains no elements.
+/// - `[T; N]` and `[T]`: Returns `true` if the array or slice has length zero.
 /// - `bool`: Returns `true` if the boolean value is `false`.
 /// - Numeric types (integers and floats): Returns `true` if the value is `0` or `0.0`.
 /// - `Option<T>`: Returns `true` if the `Option` is `None` or if `Some(value)` and `value` is `is_empty()`.
+/// - `&T`, `Box<T>`, `Rc<T>`, `Arc<T>`, and `Cow<'_, T>`: Delegate to the pointed-to value, so
+///   `&str`, `Box<str>`, `&[T]`, `Rc<String>`, and similar are all supported.
 ///
 /// # Usage
 /// This trait is particularly useful for filtering or compacting collections
@@ -40,19 +78,86 @@ impl IsEmpty for String {
     }
 }
 
-/// Implements `IsEmpty` for string slices (`&str`).
+/// Implements `IsEmpty` for string slices (`str`).
 ///
-/// A `&str` is considered empty if its length is zero.
+/// A `str` is considered empty if its length is zero. Borrowed and shared forms such as
+/// `&str`, `Box<str>`, `Rc<str>`, and `Cow<str>` are covered by the pointer impls below.
 ///
 /// # Performance
-/// This implementation directly calls the `&str::is_empty()` method,
+/// This implementation directly calls the `str::is_empty()` method,
 /// which is an efficient O(1) operation.
-impl IsEmpty for &str {
+impl IsEmpty for str {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
 }
 
+/// Implements `IsEmpty` for owned OS strings (`OsString`).
+///
+/// An `OsString` is considered empty if its length is zero.
+///
+/// # Performance
+/// This implementation calls `OsStr::is_empty()` on the borrowed view,
+/// which is an efficient O(1) operation.
+impl IsEmpty for OsString {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for borrowed OS strings (`OsStr`).
+///
+/// An `OsStr` is considered empty if its length is zero. `&OsStr` and other pointer
+/// forms are covered by the pointer impls below.
+///
+/// # Performance
+/// This implementation directly calls the `OsStr::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl IsEmpty for OsStr {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for owned paths (`PathBuf`).
+///
+/// A `PathBuf` is considered empty if it holds no characters at all (`PathBuf::new()`).
+/// Note that `"."` and `"/"` are **not** empty.
+///
+/// # Performance
+/// This implementation checks the underlying `OsStr` length, an efficient O(1) operation.
+impl IsEmpty for PathBuf {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for borrowed paths (`Path`).
+///
+/// A `Path` is considered empty if it holds no characters at all. `&Path` and other
+/// pointer forms are covered by the pointer impls below.
+///
+/// # Performance
+/// This implementation checks the underlying `OsStr` length, an efficient O(1) operation.
+impl IsEmpty for Path {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for characters (`char`).
+///
+/// A `char` is considered empty if it is the NUL character (`'\0'`) or Unicode whitespace,
+/// mirroring how an empty or blank string is treated as "nothing" in text processing.
+///
+/// # Performance
+/// This is a direct comparison plus [`char::is_whitespace`], an efficient O(1) operation.
+impl IsEmpty for char {
+    fn is_empty(&self) -> bool {
+        *self == '\0' || self.is_whitespace()
+    }
+}
+
 /// Implements `IsEmpty` for dynamic vectors (`Vec<T>`).
 ///
 /// A `Vec<T>` is considered empty if it contains no elements.
@@ -69,6 +174,181 @@ impl<T> IsEmpty for Vec<T> {
     }
 }
 
+/// Implements `IsEmpty` for double-ended queues (`VecDeque<T>`).
+///
+/// A `VecDeque<T>` is considered empty if it contains no elements.
+///
+/// # Performance
+/// This implementation directly calls the `VecDeque::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl<T> IsEmpty for VecDeque<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for hash maps (`HashMap<K, V, S>`).
+///
+/// A `HashMap` is considered empty if it contains no entries.
+///
+/// # Type Parameters
+/// - `K`, `V`: The key and value types.
+/// - `S`: The hasher, so maps built with custom hashers (e.g. `ahash`) are supported too.
+///
+/// # Performance
+/// This implementation directly calls the `HashMap::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl<K, V, S> IsEmpty for HashMap<K, V, S> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for hash sets (`HashSet<T, S>`).
+///
+/// A `HashSet` is considered empty if it contains no elements.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `S`: The hasher, so sets built with custom hashers (e.g. `ahash`) are supported too.
+///
+/// # Performance
+/// This implementation directly calls the `HashSet::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl<T, S> IsEmpty for HashSet<T, S> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for ordered maps (`BTreeMap<K, V>`).
+///
+/// A `BTreeMap` is considered empty if it contains no entries.
+///
+/// # Performance
+/// This implementation directly calls the `BTreeMap::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl<K, V> IsEmpty for BTreeMap<K, V> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for ordered sets (`BTreeSet<T>`).
+///
+/// A `BTreeSet` is considered empty if it contains no elements.
+///
+/// # Performance
+/// This implementation directly calls the `BTreeSet::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl<T> IsEmpty for BTreeSet<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for fixed-size arrays (`[T; N]`).
+///
+/// An array is considered empty only if `N == 0`; the contents are not inspected.
+///
+/// # Performance
+/// `N` is known at compile time, so this is an O(1) constant check.
+impl<T, const N: usize> IsEmpty for [T; N] {
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+/// Implements `IsEmpty` for slices (`[T]`).
+///
+/// A slice is considered empty if its length is zero. Borrowed and shared forms such as
+/// `&[T]`, `Box<[T]>`, and `Rc<[T]>` are covered by the pointer impls below.
+///
+/// # Performance
+/// This implementation directly calls the `<[T]>::is_empty()` method,
+/// which is an efficient O(1) operation.
+impl<T> IsEmpty for [T] {
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+/// Implements `IsEmpty` for shared references (`&T`).
+///
+/// A reference is considered empty if the value it points to is empty.
+///
+/// # Type Parameters
+/// - `T`: The referenced type, which must implement `IsEmpty`. May be unsized (e.g. `str`, `[U]`).
+///
+/// # Performance
+/// Delegates to the inner `is_empty()`; the reference itself adds no cost.
+impl<T: IsEmpty + ?Sized> IsEmpty for &T {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for boxed values (`Box<T>`).
+///
+/// A `Box<T>` is considered empty if the boxed value is empty.
+///
+/// # Type Parameters
+/// - `T`: The boxed type, which must implement `IsEmpty`. May be unsized (e.g. `str`, `[U]`).
+///
+/// # Performance
+/// Delegates to the inner `is_empty()`; no allocation or cloning occurs.
+impl<T: IsEmpty + ?Sized> IsEmpty for Box<T> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for reference-counted values (`Rc<T>`).
+///
+/// An `Rc<T>` is considered empty if the shared value is empty.
+///
+/// # Type Parameters
+/// - `T`: The shared type, which must implement `IsEmpty`. May be unsized (e.g. `str`, `[U]`).
+///
+/// # Performance
+/// Delegates to the inner `is_empty()`; the reference count is not touched.
+impl<T: IsEmpty + ?Sized> IsEmpty for Rc<T> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for atomically reference-counted values (`Arc<T>`).
+///
+/// An `Arc<T>` is considered empty if the shared value is empty.
+///
+/// # Type Parameters
+/// - `T`: The shared type, which must implement `IsEmpty`. May be unsized (e.g. `str`, `[U]`).
+///
+/// # Performance
+/// Delegates to the inner `is_empty()`; the reference count is not touched.
+impl<T: IsEmpty + ?Sized> IsEmpty for Arc<T> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implements `IsEmpty` for clone-on-write values (`Cow<'_, T>`).
+///
+/// A `Cow` is considered empty if the value it borrows or owns is empty,
+/// regardless of which variant it currently holds.
+///
+/// # Type Parameters
+/// - `T`: The borrowed type, which must implement `IsEmpty` and [`ToOwned`] (e.g. `str`, `[U]`).
+///
+/// # Performance
+/// Delegates to the inner `is_empty()` through `Deref`; nothing is cloned.
+impl<T: IsEmpty + ToOwned + ?Sized> IsEmpty for Cow<'_, T> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
 /// Implements `IsEmpty` for boolean values (`bool`).
 ///
 /// A `bool` is considered empty if its value is `false`.
@@ -175,9 +455,14 @@ impl_is_empty_for_numerics!(
 /// # Supported Types
 /// This function works with any type that implements the `IsEmpty` trait, such as:
 /// - `String`, `&str`
+/// - `PathBuf`, `&Path`, `OsString`, `&OsStr`
+/// - `char` (`'\0'` and whitespace are "empty")
 /// - All integers and floats (`0`, `0.0` are "empty")
 /// - `bool` (`false` is "empty")
 /// - `Vec<T>` where `T: IsEmpty`
+/// - `VecDeque<T>`, `HashMap<K, V>`, `HashSet<T>`, `BTreeMap<K, V>`, `BTreeSet<T>`
+/// - `[T; N]` and `&[T]`
+/// - `&T`, `Box<T>`, `Rc<T>`, `Arc<T>`, and `Cow<'_, T>` where the pointed-to type implements `IsEmpty`
 /// - `Option<T>` where `T: IsEmpty`
 ///
 /// # Examples
@@ -230,3 +515,177 @@ pub fn compact<T: IsEmpty>(values: &mut Vec<T>) {
     values.retain(|v| !v.is_empty());
 }
 
+/// 🌊 Compacts a numeric vector in place, removing "empty" values **and** non-finite floats.
+///
+/// [`IsEmpty`] for floats only treats `0.0` as empty, so `NaN` and infinities survive [`compact`].
+/// This helper removes them as well.
+///
+/// # Type Parameters
+/// - `T`: A numeric element type that implements [`IsEmpty`] and converts losslessly into `f64`
+///   (e.g. `f32`, `f64`, `i32`, `u32`, `u8`).
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to compact.
+///
+/// # Behavior
+/// - Removes every element for which `is_empty()` is true (`0`, `0.0`, `-0.0`).
+/// - Removes `NaN`, `+∞`, and `-∞`.
+/// - Integer inputs are always finite, so only zeros are removed — identical to [`compact`].
+/// - Preserves the original order of retained elements.
+///
+/// # Performance
+/// - Runs in **O(n)** time using `Vec::retain()` — no reallocations.
+/// - The `f64` conversion is a register-level widening with no allocation.
+///
+/// # Examples
+///
+/// ### 🌊 Drop `NaN`, infinities, and zeros
+/// ```
+/// use pencil_box::array::compact::compact_finite;
+///
+/// let mut readings = vec![1.5, f64::NAN, 0.0, f64::INFINITY, -2.0, f64::NEG_INFINITY];
+/// compact_finite(&mut readings);
+/// assert_eq!(readings, vec![1.5, -2.0]);
+/// ```
+///
+/// ### 🔢 Works with `f32`
+/// ```
+/// use pencil_box::array::compact::compact_finite;
+///
+/// let mut values: Vec<f32> = vec![f32::NAN, 3.0];
+/// compact_finite(&mut values);
+/// assert_eq!(values, vec![3.0]);
+/// ```
+pub fn compact_finite<T: IsEmpty + Copy + Into<f64>>(values: &mut Vec<T>) {
+    values.retain(|v| !v.is_empty() && (*v).into().is_finite());
+}
+
+/// 🧾 Returns a new vector containing clones of all elements that are **not** considered "empty".
+///
+/// This is the non-mutating counterpart of [`compact`]: the input slice is left untouched,
+/// which is useful when the original data must be preserved (e.g. for later auditing).
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice. Must implement [`IsEmpty`] and [`Clone`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to read from.
+///
+/// # Returns
+/// A new `Vec<T>` holding clones of the non-empty elements, in their original order.
+///
+/// # Behavior
+/// - Emptiness is decided by [`IsEmpty::is_empty`], exactly as in [`compact`].
+/// - An empty input, or one where every element is empty, yields an empty vector.
+///
+/// # Performance
+/// - Runs in **O(n)** time, where `n` is the number of elements.
+/// - Clones only the retained elements; empty elements are never cloned.
+///
+/// # Examples
+///
+/// ### 📜 Keep the original intact
+/// ```
+/// use pencil_box::array::compact::compacted;
+///
+/// let original = vec!["a".to_string(), "".to_string(), "b".to_string()];
+/// let cleaned = compacted(&original);
+/// assert_eq!(cleaned, vec!["a", "b"]);
+/// assert_eq!(original.len(), 3);
+/// ```
+///
+/// ### 🧹 Works on any slice
+/// ```
+/// use pencil_box::array::compact::compacted;
+///
+/// let nums = [0, 4, 0, 2];
+/// assert_eq!(compacted(&nums), vec![4, 2]);
+/// ```
+pub fn compacted<T: IsEmpty + Clone>(values: &[T]) -> Vec<T> {
+    values.iter().filter(|v| !v.is_empty()).cloned().collect()
+}
+
+/// 🚮 Compacts a mutable vector by removing all elements that a caller-supplied predicate deems "empty".
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the vector. No trait bounds are required.
+/// - `P`: A predicate that takes a reference to an element and returns `true` if it should be removed.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the `Vec<T>` to be compacted.
+/// - `is_empty`: The predicate defining "empty" for this call.
+///
+/// # Behavior
+/// - Modifies the input vector **in-place**, removing elements for which `is_empty` returns `true`.
+/// - Preserves the original order of retained elements.
+/// - Useful when the [`IsEmpty`] definition for a type does not fit, e.g. whitespace-only strings
+///   or sentinel values, without introducing a newtype.
+///
+/// # Performance
+/// - Runs in **O(n)** time, where `n` is the number of elements.
+/// - Uses `Vec::retain()` under the hood — no reallocations.
+/// - The predicate is invoked exactly once per element.
+///
+/// # Examples
+///
+/// ### 📜 Treat whitespace-only strings as empty
+/// ```
+/// use pencil_box::array::compact::compact_by;
+///
+/// let mut lines = vec!["a".to_string(), "   ".to_string(), "".to_string(), "b".to_string()];
+/// compact_by(&mut lines, |line| line.trim().is_empty());
+/// assert_eq!(lines, vec!["a", "b"]);
+/// ```
+///
+/// ### 🧹 Remove sentinel values
+/// ```
+/// use pencil_box::array::compact::compact_by;
+///
+/// let mut readings = vec![12, -1, 0, 7, -1];
+/// compact_by(&mut readings, |r| *r == -1);
+/// assert_eq!(readings, vec![12, 0, 7]);
+/// ```
+pub fn compact_by<T, P: Fn(&T) -> bool>(values: &mut Vec<T>, is_empty: P) {
+    values.retain(|v| !is_empty(v));
+}
+
+/// 🎁 Unwraps a vector of `Option<T>`, keeping only the `Some` values by move.
+///
+/// # Type Parameters
+/// - `T`: The wrapped element type. No trait bounds are required.
+///
+/// # Arguments
+/// - `values`: The vector of options to consume.
+///
+/// # Returns
+/// A `Vec<T>` containing the inner value of every `Some`, in their original order.
+///
+/// # Behavior
+/// - Only `None` is dropped. Unlike [`compact`], the inner value is **not** checked with
+///   [`IsEmpty`], so `Some(0)`, `Some("")`, or `Some(false)` are kept.
+/// - Values are moved out of their `Option`; nothing is cloned.
+///
+/// # Performance
+/// - Runs in **O(n)** time, where `n` is the number of elements.
+/// - A single output allocation; no `T: Clone` or `T: IsEmpty` bound is needed.
+///
+/// # Examples
+///
+/// ### 🎁 Keep `Some(0)`
+/// ```
+/// use pencil_box::array::compact::compact_options;
+///
+/// let values = vec![Some(0), None, Some(3)];
+/// assert_eq!(compact_options(values), vec![0, 3]);
+/// ```
+///
+/// ### 🧵 Move owned strings out
+/// ```
+/// use pencil_box::array::compact::compact_options;
+///
+/// let names = vec![None, Some("ada".to_string()), Some(String::new())];
+/// assert_eq!(compact_options(names), vec!["ada".to_string(), String::new()]);
+/// ```
+pub fn compact_options<T>(values: Vec<Option<T>>) -> Vec<T> {
+    values.into_iter().flatten().collect()
+}
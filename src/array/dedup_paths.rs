@@ -0,0 +1,139 @@
+use std::path::Path;
+
+/// 🌳 Removes paths that are already covered by an ancestor path in the same vector.
+///
+/// # Type Parameters
+/// - `P`: The path-like element type. Must implement `AsRef<Path>` (e.g. `PathBuf`, `&Path`, `String`, `&str`).
+///
+/// # Arguments
+/// - `paths`: A mutable reference to the vector of paths to deduplicate.
+///
+/// # Returns
+/// This function returns no value. It modifies the input vector in place, keeping only the
+/// **top-most** paths — those that are not descendants of any other entry.
+///
+/// # Behavior
+/// - Ancestry is decided **per component** via [`Path::starts_with`], so `/a/bc` is *not* under `/a/b`.
+/// - Exact duplicates are collapsed; the **first** occurrence is kept.
+/// - Preserves the original order of retained entries.
+/// - Comparison is purely lexical: paths are not canonicalized, and `..` or symlinks are not resolved.
+///   Repeated separators and interior `.` components are ignored, as in [`Path::components`].
+///
+/// # Performance
+/// - Runs in **O(n log n)** comparisons: indices are sorted by path, after which every descendant
+///   sits directly behind its ancestor and a single linear scan suffices.
+/// - Allocates one index buffer and one keep-mask of length `n`; no path is cloned.
+///
+/// # Examples
+///
+/// ### 📁 Keep only the watch roots
+/// ```
+/// use pencil_box::array::dedup_paths::dedup_paths;
+///
+/// let mut paths = vec!["/srv/app/src", "/srv/app", "/var/log", "/srv/app/src/main.rs", "/srv/application"];
+/// dedup_paths(&mut paths);
+/// assert_eq!(paths, vec!["/srv/app", "/var/log", "/srv/application"]);
+/// ```
+///
+/// ### 🔁 Duplicates are collapsed
+/// ```
+/// use pencil_box::array::dedup_paths::dedup_paths;
+/// use std::path::PathBuf;
+///
+/// let mut paths = vec![PathBuf::from("a/b"), PathBuf::from("a//b/"), PathBuf::from("c")];
+/// dedup_paths(&mut paths);
+/// assert_eq!(paths, vec![PathBuf::from("a/b"), PathBuf::from("c")]);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn dedup_paths<P: AsRef<Path>>(paths: &mut Vec<P>) {
+    let order = sorted_order(paths);
+    let mut keep = vec![false; paths.len()];
+
+    let mut root: Option<&Path> = None;
+    for &index in &order {
+        let path = paths[index].as_ref();
+        match root {
+            Some(ancestor) if path.starts_with(ancestor) => {}
+            _ => {
+                keep[index] = true;
+                root = Some(path);
+            }
+        }
+    }
+
+    retain_marked(paths, &keep);
+}
+
+/// 🍃 Removes paths that are ancestors of another path in the same vector, keeping only the deepest entries.
+///
+/// # Type Parameters
+/// - `P`: The path-like element type. Must implement `AsRef<Path>`.
+///
+/// # Arguments
+/// - `paths`: A mutable reference to the vector of paths to deduplicate.
+///
+/// # Returns
+/// This function returns no value. It modifies the input vector in place, keeping only the
+/// **leaf** paths — those that have no descendant among the other entries.
+///
+/// # Behavior
+/// - The mirror image of [`dedup_paths`]: an entry is dropped when another entry lies beneath it.
+/// - Ancestry is decided **per component** via [`Path::starts_with`].
+/// - Exact duplicates are collapsed; the **first** occurrence is kept.
+/// - Preserves the original order of retained entries.
+///
+/// # Performance
+/// - Runs in **O(n log n)** comparisons using the same sort-then-scan approach as [`dedup_paths`].
+/// - Allocates one index buffer and one keep-mask of length `n`; no path is cloned.
+///
+/// # Examples
+///
+/// ### 🍃 Keep only the most specific paths
+/// ```
+/// use pencil_box::array::dedup_paths::dedup_paths_deepest;
+///
+/// let mut paths = vec!["/srv", "/srv/app/src", "/srv/app", "/var/log"];
+/// dedup_paths_deepest(&mut paths);
+/// assert_eq!(paths, vec!["/srv/app/src", "/var/log"]);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn dedup_paths_deepest<P: AsRef<Path>>(paths: &mut Vec<P>) {
+    let order = sorted_order(paths);
+    let mut keep = vec![false; paths.len()];
+
+    for (position, &index) in order.iter().enumerate() {
+        let path = paths[index].as_ref();
+        if position > 0 && paths[order[position - 1]].as_ref() == path {
+            continue;
+        }
+        let has_descendant = order[position + 1..]
+            .iter()
+            .map(|&next| paths[next].as_ref())
+            .find(|next| *next != path)
+            .is_some_and(|next| next.starts_with(path));
+        keep[index] = !has_descendant;
+    }
+
+    retain_marked(paths, &keep);
+}
+
+/// Returns the indices of `paths` ordered by their components, ties broken by original position.
+fn sorted_order<P: AsRef<Path>>(paths: &[P]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..paths.len()).collect();
+    order.sort_by(|&a, &b| paths[a].as_ref().cmp(paths[b].as_ref()));
+    order
+}
+
+/// Retains only the elements whose index is marked in `keep`.
+fn retain_marked<P>(paths: &mut Vec<P>, keep: &[bool]) {
+    let mut index = 0;
+    paths.retain(|_| {
+        let kept = keep[index];
+        index += 1;
+        kept
+    });
+}
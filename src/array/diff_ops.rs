@@ -0,0 +1,409 @@
+use std::error::Error;
+use std::fmt;
+
+/// ✏️ A single step of an edit script turning one sequence into another.
+///
+/// Produced by [`diff_ops`]. Reading the script in order, `Equal` and `Delete` values come
+/// from the old sequence, and `Equal` and `Insert` values make up the new one.
+///
+/// # Display
+/// When `T` implements [`Display`](fmt::Display), each op prints as a single diff line:
+/// `" value"` for `Equal`, `"-value"` for `Delete`, and `"+value"` for `Insert`. Use
+/// [`DiffScript`] to print a whole script. With the `color` feature enabled, the alternate form
+/// (`{:#}`) colors deletions red and insertions green with ANSI escape codes.
+///
+/// With the `serde` feature enabled, `DiffOp` implements `Serialize` and `Deserialize`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::diff_ops::DiffOp;
+///
+/// assert_eq!(DiffOp::Insert("b").to_string(), "+b");
+/// assert_eq!(DiffOp::Delete(3).to_string(), "-3");
+/// assert_eq!(DiffOp::Equal('x').to_string(), " x");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiffOp<T> {
+    /// The value is present, unchanged, in both sequences.
+    Equal(T),
+    /// The value is only present in the old sequence.
+    Delete(T),
+    /// The value is only present in the new sequence.
+    Insert(T),
+}
+
+impl<T> DiffOp<T> {
+    /// Returns a reference to the value carried by this op.
+    pub fn value(&self) -> &T {
+        match self {
+            DiffOp::Equal(value) | DiffOp::Delete(value) | DiffOp::Insert(value) => value,
+        }
+    }
+
+    /// Returns `true` for `Delete` and `Insert`, i.e. anything that is not `Equal`.
+    pub fn is_change(&self) -> bool {
+        !matches!(self, DiffOp::Equal(_))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DiffOp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, color, value) = match self {
+            DiffOp::Equal(value) => (' ', None, value),
+            DiffOp::Delete(value) => ('-', Some("\x1b[31m"), value),
+            DiffOp::Insert(value) => ('+', Some("\x1b[32m"), value),
+        };
+        match color {
+            Some(color) if cfg!(feature = "color") && f.alternate() => {
+                write!(f, "{color}{sign}{value}\x1b[0m")
+            }
+            _ => write!(f, "{sign}{value}"),
+        }
+    }
+}
+
+/// 🖨️ Prints a whole edit script, one [`DiffOp`] per line.
+///
+/// # Fields
+/// - `ops`: The edit script to print, usually from [`diff_ops`].
+///
+/// # Behavior
+/// - Every op is written in its [`DiffOp`] format and followed by `\n`.
+/// - An empty script displays as an empty string.
+/// - With the `color` feature enabled, the alternate form (`{:#}`) colors deletions red and
+///   insertions green; the plain form never contains escape codes.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::diff_ops::{diff_ops, DiffScript};
+///
+/// let ops = diff_ops(&["a", "b", "c"], &["a", "B", "c"]);
+/// let script = DiffScript { ops: &ops };
+/// assert_eq!(script.to_string(), " a\n-b\n+B\n c\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffScript<'a, T> {
+    pub ops: &'a [DiffOp<T>],
+}
+
+impl<T: fmt::Display> fmt::Display for DiffScript<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for op in self.ops {
+            if f.alternate() {
+                writeln!(f, "{op:#}")?;
+            } else {
+                writeln!(f, "{op}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 🧮 Computes a minimal edit script turning `old` into `new`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialEq`] and [`Clone`].
+///
+/// # Arguments
+/// - `old`: The original sequence.
+/// - `new`: The updated sequence.
+///
+/// # Returns
+/// A `Vec<DiffOp<T>>` that, read in order, deletes and inserts the fewest elements needed.
+///
+/// # Behavior
+/// - Keeps a longest common subsequence as `Equal`, so the script is as short as possible.
+/// - Within each changed block, `Delete` ops come before `Insert` ops.
+/// - Values in `Equal` ops are cloned from `old`.
+/// - Identical inputs produce only `Equal` ops; empty inputs produce an empty script.
+///
+/// # Performance
+/// - Common prefixes and suffixes are matched in **O(n)** first.
+/// - The remaining middle uses Myers' **O((n + m) × D)** algorithm, where `D` is the number of
+///   inserted and deleted elements, with **O(n + m)** extra memory. Large inputs with few
+///   changes stay fast; inputs that share almost nothing approach **O(n × m)** time.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::diff_ops::{diff_ops, DiffOp};
+///
+/// let ops = diff_ops(&["a", "b", "c"], &["a", "x", "c", "d"]);
+/// assert_eq!(
+///     ops,
+///     vec![
+///         DiffOp::Equal("a"),
+///         DiffOp::Delete("b"),
+///         DiffOp::Insert("x"),
+///         DiffOp::Equal("c"),
+///         DiffOp::Insert("d"),
+///     ]
+/// );
+/// ```
+pub fn diff_ops<T: PartialEq + Clone>(old: &[T], new: &[T]) -> Vec<DiffOp<T>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+    ops.extend(old[..prefix].iter().cloned().map(DiffOp::Equal));
+    diff_middle(old_middle, new_middle, &mut ops);
+    // The bisection may interleave a block's changes; list its deletions first.
+    for block in ops[prefix..].split_mut(|op| !op.is_change()) {
+        block.sort_by_key(|op| matches!(op, DiffOp::Insert(_)));
+    }
+    ops.extend(old[old.len() - suffix..].iter().cloned().map(DiffOp::Equal));
+    ops
+}
+
+/// Appends a minimal edit script for `old` → `new`, recursing around a middle snake.
+///
+/// Uses Myers' O(ND) algorithm with the linear-space refinement, so memory stays
+/// **O(n + m)** however far apart the inputs are.
+fn diff_middle<T: PartialEq + Clone>(old: &[T], new: &[T], ops: &mut Vec<DiffOp<T>>) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    ops.extend(old[..prefix].iter().cloned().map(DiffOp::Equal));
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+    if old_middle.is_empty() || new_middle.is_empty() {
+        ops.extend(old_middle.iter().cloned().map(DiffOp::Delete));
+        ops.extend(new_middle.iter().cloned().map(DiffOp::Insert));
+    } else if let Some((x, y)) = middle_snake(old_middle, new_middle) {
+        diff_middle(&old_middle[..x], &new_middle[..y], ops);
+        diff_middle(&old_middle[x..], &new_middle[y..], ops);
+    } else {
+        ops.extend(old_middle.iter().cloned().map(DiffOp::Delete));
+        ops.extend(new_middle.iter().cloned().map(DiffOp::Insert));
+    }
+
+    ops.extend(old[old.len() - suffix..].iter().cloned().map(DiffOp::Equal));
+}
+
+/// Finds a point `(x, y)` on a shortest edit path where the forward and backward searches meet.
+///
+/// Both inputs must be non-empty and differ in their first and last elements. Returns `None`
+/// when they share no element at all.
+fn middle_snake<T: PartialEq>(old: &[T], new: &[T]) -> Option<(usize, usize)> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max_d = (n + m + 1) / 2;
+    let offset = max_d;
+    let width = 2 * max_d + 2;
+    // forward[offset + k] / backward[offset + k]: furthest x reached on diagonal k, or -1.
+    let mut forward = vec![-1isize; width as usize];
+    let mut backward = vec![-1isize; width as usize];
+    forward[(offset + 1) as usize] = 0;
+    backward[(offset + 1) as usize] = 0;
+
+    let delta = n - m;
+    let check_forward = delta % 2 != 0;
+    let (mut k1_start, mut k1_end, mut k2_start, mut k2_end) = (0, 0, 0, 0);
+
+    for d in 0..max_d {
+        let mut k1 = -d + k1_start;
+        while k1 <= d - k1_end {
+            let k1_index = (offset + k1) as usize;
+            let mut x1 = if k1 == -d || (k1 != d && forward[k1_index - 1] < forward[k1_index + 1]) {
+                forward[k1_index + 1]
+            } else {
+                forward[k1_index - 1] + 1
+            };
+            let mut y1 = x1 - k1;
+            while x1 < n && y1 < m && old[x1 as usize] == new[y1 as usize] {
+                x1 += 1;
+                y1 += 1;
+            }
+            forward[k1_index] = x1;
+            if x1 > n {
+                k1_end += 2;
+            } else if y1 > m {
+                k1_start += 2;
+            } else if check_forward {
+                let k2_index = offset + delta - k1;
+                if (0..width).contains(&k2_index) && backward[k2_index as usize] != -1 {
+                    let x2 = n - backward[k2_index as usize];
+                    if x1 >= x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+            k1 += 2;
+        }
+
+        let mut k2 = -d + k2_start;
+        while k2 <= d - k2_end {
+            let k2_index = (offset + k2) as usize;
+            let mut x2 = if k2 == -d || (k2 != d && backward[k2_index - 1] < backward[k2_index + 1])
+            {
+                backward[k2_index + 1]
+            } else {
+                backward[k2_index - 1] + 1
+            };
+            let mut y2 = x2 - k2;
+            while x2 < n && y2 < m && old[(n - x2 - 1) as usize] == new[(m - y2 - 1) as usize] {
+                x2 += 1;
+                y2 += 1;
+            }
+            backward[k2_index] = x2;
+            if x2 > n {
+                k2_end += 2;
+            } else if y2 > m {
+                k2_start += 2;
+            } else if !check_forward {
+                let k1_index = offset + delta - k2;
+                if (0..width).contains(&k1_index) && forward[k1_index as usize] != -1 {
+                    let x1 = forward[k1_index as usize];
+                    let y1 = offset + x1 - k1_index;
+                    if x1 >= n - x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+            k2 += 2;
+        }
+    }
+    None
+}
+
+/// ❗ Error returned by [`apply_diff`] when an edit script does not fit the target vector.
+///
+/// `op` is the index of the offending op in the script and `position` is the index in the
+/// original vector it was checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyDiffError {
+    /// An `Equal` or `Delete` op carries a value that differs from the element at `position`.
+    Mismatch { op: usize, position: usize },
+    /// An `Equal` or `Delete` op was reached after every element had been consumed.
+    UnexpectedEnd { op: usize, position: usize },
+    /// The script ended with `remaining` elements still unconsumed, starting at `position`.
+    TrailingElements { position: usize, remaining: usize },
+}
+
+impl fmt::Display for ApplyDiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplyDiffError::Mismatch { op, position } => {
+                write!(f, "op {op} does not match the element at index {position}")
+            }
+            ApplyDiffError::UnexpectedEnd { op, position } => {
+                write!(
+                    f,
+                    "op {op} expects an element at index {position} past the end"
+                )
+            }
+            ApplyDiffError::TrailingElements {
+                position,
+                remaining,
+            } => write!(
+                f,
+                "script ended with {remaining} element(s) left from index {position}"
+            ),
+        }
+    }
+}
+
+impl Error for ApplyDiffError {}
+
+/// 🩹 Applies an edit script produced by [`diff_ops`] to `values`, turning old into new.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialEq`] and [`Clone`].
+///
+/// # Arguments
+/// - `values`: The vector to patch. It must hold the old sequence the script was computed from.
+/// - `ops`: The edit script to apply.
+///
+/// # Returns
+/// - `Ok(())` if the script was applied; `values` now holds the new sequence.
+///
+/// # Errors
+/// - [`ApplyDiffError::Mismatch`] if an `Equal` or `Delete` value differs from the element it
+///   refers to.
+/// - [`ApplyDiffError::UnexpectedEnd`] if the script refers to more elements than `values` has.
+/// - [`ApplyDiffError::TrailingElements`] if elements are left once the script is exhausted.
+///
+/// # Behavior
+/// - The whole script is validated before anything is changed, so `values` is left untouched
+///   on error.
+/// - Kept elements are moved, not cloned; only `Insert` values are cloned out of the script.
+/// - An empty script only applies to an empty vector.
+///
+/// # Performance
+/// - **O(n + k)** for `n` elements and `k` ops, with one allocation for the patched vector.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::diff_ops::{apply_diff, diff_ops, ApplyDiffError, DiffOp};
+///
+/// let old = vec!["a", "b", "c"];
+/// let new = vec!["a", "x", "c", "d"];
+/// let ops = diff_ops(&old, &new);
+///
+/// let mut values = old.clone();
+/// apply_diff(&mut values, &ops).unwrap();
+/// assert_eq!(values, new);
+///
+/// let mut stale = vec!["a", "q", "c"];
+/// assert_eq!(
+///     apply_diff(&mut stale, &ops),
+///     Err(ApplyDiffError::Mismatch { op: 1, position: 1 })
+/// );
+/// assert_eq!(stale, vec!["a", "q", "c"]);
+/// ```
+pub fn apply_diff<T: PartialEq + Clone>(
+    values: &mut Vec<T>,
+    ops: &[DiffOp<T>],
+) -> Result<(), ApplyDiffError> {
+    let mut position = 0;
+    let mut new_len = 0;
+    for (op, step) in ops.iter().enumerate() {
+        match step {
+            DiffOp::Equal(value) | DiffOp::Delete(value) => {
+                match values.get(position) {
+                    None => return Err(ApplyDiffError::UnexpectedEnd { op, position }),
+                    Some(current) if current != value => {
+                        return Err(ApplyDiffError::Mismatch { op, position })
+                    }
+                    Some(_) => position += 1,
+                }
+                if !step.is_change() {
+                    new_len += 1;
+                }
+            }
+            DiffOp::Insert(_) => new_len += 1,
+        }
+    }
+    if position < values.len() {
+        return Err(ApplyDiffError::TrailingElements {
+            position,
+            remaining: values.len() - position,
+        });
+    }
+
+    let mut old = std::mem::take(values).into_iter();
+    let mut patched = Vec::with_capacity(new_len);
+    for step in ops {
+        match step {
+            DiffOp::Equal(_) => patched.extend(old.next()),
+            DiffOp::Delete(_) => {
+                old.next();
+            }
+            DiffOp::Insert(value) => patched.push(value.clone()),
+        }
+    }
+    *values = patched;
+    Ok(())
+}
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 use std::hash::Hash;
 use ahash::AHashSet;
 
-/// Computes the difference between a primary list and multiple exclusion lists using [`std::collections::HashSet`].
+/// Computes the difference between a primary list and multiple exclusion lists.
 ///
 /// # Type Parameters
 /// - `T`: The element type. Must implement [`Clone`], [`Eq`], and [`Hash`].
@@ -19,10 +19,12 @@ use ahash::AHashSet;
 /// - Performs equality comparison using `==`, backed by `Eq` + `Hash`.
 ///
 /// # Performance
-/// - Uses [`HashSet`] (SipHash): **secure and collision-resistant**, suitable for untrusted input.
+/// - By default, uses [`HashSet`] (SipHash): **secure and collision-resistant**, suitable for
+///   untrusted input, exactly like [`difference_secure`].
+/// - With the `fast-hash` feature, uses [`AHashSet`] instead, exactly like
+///   [`difference_performant`].
 /// - Preallocates capacity for efficiency and avoids unnecessary allocations.
 /// - Performs at most one `clone()` per included or excluded item.
-/// - For large datasets where security is not a concern, see [`difference_performant`].
 ///
 /// # Examples
 ///
@@ -57,6 +59,33 @@ use ahash::AHashSet;
 pub fn difference<T: Eq + Hash + Clone>(
     to_compare: &Vec<T>,
     others: &Vec<&Vec<T>>,
+) -> Vec<T> {
+    if cfg!(feature = "fast-hash") {
+        difference_performant(to_compare, others)
+    } else {
+        difference_secure(to_compare, others)
+    }
+}
+
+/// 🔒 Computes the difference using [`HashSet`] (SipHash), regardless of the `fast-hash` feature.
+///
+/// # Behavior
+/// - Identical in output to [`difference`].
+///
+/// # Performance
+/// - Uses [`HashSet`] (SipHash): **secure and collision-resistant**, suitable for untrusted input.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::difference::difference_secure;
+///
+/// let requested = vec!["read", "write", "admin"];
+/// let denied = vec!["admin"];
+/// assert_eq!(difference_secure(&requested, &vec![&denied]), vec!["read", "write"]);
+/// ```
+pub fn difference_secure<T: Eq + Hash + Clone>(
+    to_compare: &Vec<T>,
+    others: &Vec<&Vec<T>>,
 ) -> Vec<T> {
     let capacity = others.iter().map(|sub_array| sub_array.len()).sum();
     let mut set: HashSet<T> = HashSet::with_capacity(capacity);
@@ -0,0 +1,97 @@
+/// 📉 Downsamples a series to `target_points` with Largest-Triangle-Three-Buckets (LTTB).
+///
+/// LTTB keeps the visual shape of a line chart far better than taking every n-th point:
+/// peaks and dips survive because each bucket keeps the point that forms the largest triangle
+/// with its neighbours.
+///
+/// # Arguments
+/// - `points`: The series as `(x, y)` pairs, sorted by ascending `x`.
+/// - `target_points`: The number of points to keep.
+///
+/// # Returns
+/// A `Vec<(f64, f64)>` with `min(target_points, points.len())` points taken from `points`, in
+/// their original order.
+///
+/// # Behavior
+/// - The first and last points are always kept.
+/// - The points in between are split into `target_points - 2` equally sized buckets, and one
+///   point is selected from each.
+/// - If `target_points >= points.len()`, the series is returned unchanged.
+/// - A `target_points` of `0` gives an empty result, `1` the first point, and `2` the first
+///   and last points.
+/// - Points whose triangle area is `NaN` are only selected if nothing else in their bucket is
+///   comparable.
+///
+/// # Performance
+/// - ✅ **O(n)**: every point is visited once for bucket averages and once for triangle areas.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::downsample_lttb::downsample_lttb;
+///
+/// // A flat signal with a single spike; the spike survives downsampling.
+/// let series: Vec<(f64, f64)> = (0..100)
+///     .map(|x| (x as f64, if x == 42 { 50.0 } else { 0.0 }))
+///     .collect();
+///
+/// let reduced = downsample_lttb(&series, 10);
+/// assert_eq!(reduced.len(), 10);
+/// assert_eq!(reduced[0], (0.0, 0.0));
+/// assert_eq!(reduced[9], (99.0, 0.0));
+/// assert!(reduced.contains(&(42.0, 50.0)));
+/// ```
+pub fn downsample_lttb(points: &[(f64, f64)], target_points: usize) -> Vec<(f64, f64)> {
+    let len = points.len();
+    if target_points >= len {
+        return points.to_vec();
+    }
+    match target_points {
+        0 => return Vec::new(),
+        1 => return vec![points[0]],
+        2 => return vec![points[0], points[len - 1]],
+        _ => {}
+    }
+
+    // Interior points are split into `buckets` non-empty ranges; bucket `buckets` starts at the
+    // last point. Integer arithmetic keeps the boundaries exact.
+    let buckets = target_points - 2;
+    let bucket_start = |i: usize| 1 + i * (len - 2) / buckets;
+
+    let mut sampled = Vec::with_capacity(target_points);
+    sampled.push(points[0]);
+    let mut anchor = points[0];
+
+    for bucket in 0..buckets {
+        // The average of the next bucket (or the last point) is the triangle's third vertex.
+        let next_start = bucket_start(bucket + 1);
+        let next_end = if bucket + 1 == buckets {
+            len
+        } else {
+            bucket_start(bucket + 2)
+        };
+        let next = &points[next_start..next_end];
+        let count = next.len() as f64;
+        let (sum_x, sum_y) = next
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+        let (avg_x, avg_y) = (sum_x / count, sum_y / count);
+
+        let current = &points[bucket_start(bucket)..next_start];
+        let mut selected = current[0];
+        let mut max_area = f64::NEG_INFINITY;
+        for &(x, y) in current {
+            let area =
+                ((anchor.0 - avg_x) * (y - anchor.1) - (anchor.0 - x) * (avg_y - anchor.1)).abs();
+            if area > max_area {
+                max_area = area;
+                selected = (x, y);
+            }
+        }
+
+        sampled.push(selected);
+        anchor = selected;
+    }
+
+    sampled.push(points[len - 1]);
+    sampled
+}
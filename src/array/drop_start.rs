@@ -78,3 +78,57 @@ pub fn drop_start<T>(values: &mut Vec<T>, no_of_elements_to_drop: usize) {
         values.drain(0..no_of_elements_to_drop);
     }
 }
+
+/// Removes elements from the start of a vector and returns them, instead of discarding them.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector from which elements will be removed.
+/// - `no_of_elements_to_drop`: The number of elements to remove from the start of the vector.
+///
+/// # Returns
+/// A `Vec<T>` holding the removed prefix, in its original order.
+///
+/// # Behavior
+/// - Same semantics as [`drop_start`], but the dropped elements are handed back to the caller.
+/// - If `no_of_elements_to_drop` is `0`, the vector remains unchanged and an empty vector is returned.
+/// - If `no_of_elements_to_drop` is greater than or equal to the vector’s length, the whole
+///   vector is moved out and `values` is left empty.
+///
+/// # Performance
+/// - Time complexity is **O(n)**: the removed prefix is moved out and the remaining elements are shifted left.
+/// - When the whole vector is taken, its buffer is moved out without copying.
+///
+/// # Examples
+///
+/// ## 📦 Process a batch from a queue-like vector
+/// ```
+/// use pencil_box::array::drop_start::drop_start_collect;
+///
+/// let mut queue = vec![1, 2, 3, 4, 5];
+/// let batch = drop_start_collect(&mut queue, 2);
+/// assert_eq!(batch, vec![1, 2]);
+/// assert_eq!(queue, vec![3, 4, 5]);
+/// ```
+///
+/// ## ⛔ Drop more elements than present (takes everything)
+/// ```
+/// use pencil_box::array::drop_start::drop_start_collect;
+///
+/// let mut queue = vec![1, 2, 3];
+/// assert_eq!(drop_start_collect(&mut queue, 10), vec![1, 2, 3]);
+/// assert!(queue.is_empty());
+/// ```
+pub fn drop_start_collect<T>(values: &mut Vec<T>, no_of_elements_to_drop: usize) -> Vec<T> {
+    if no_of_elements_to_drop == 0 {
+        return Vec::new();
+    }
+
+    if no_of_elements_to_drop >= values.len() {
+        std::mem::take(values)
+    } else {
+        values.drain(0..no_of_elements_to_drop).collect()
+    }
+}
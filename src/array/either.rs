@@ -0,0 +1,166 @@
+/// 🔀 A value that is one of two types: `Left(A)` or `Right(B)`.
+///
+/// A minimal stand-in for the `either` crate, used by APIs that split or route values two ways.
+/// By convention neither side means "error"; use [`Result`] for that.
+///
+/// With the `serde` feature enabled, `Either` implements `Serialize` and `Deserialize`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::either::Either;
+///
+/// let parsed: Vec<Either<i32, String>> = ["1", "two", "3"]
+///     .iter()
+///     .map(|s| match s.parse() {
+///         Ok(n) => Either::Left(n),
+///         Err(_) => Either::Right(s.to_string()),
+///     })
+///     .collect();
+///
+/// assert!(parsed[0].is_left());
+/// assert_eq!(parsed[1].as_ref().right(), Some(&"two".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Either<A, B> {
+    /// The left-hand value.
+    Left(A),
+    /// The right-hand value.
+    Right(B),
+}
+
+impl<A, B> Either<A, B> {
+    /// Returns `true` if this is a `Left`.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns `true` if this is a `Right`.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Converts into the left value, discarding a right one.
+    pub fn left(self) -> Option<A> {
+        match self {
+            Either::Left(value) => Some(value),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Converts into the right value, discarding a left one.
+    pub fn right(self) -> Option<B> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(value) => Some(value),
+        }
+    }
+
+    /// Borrows the contained value, keeping its side.
+    pub fn as_ref(&self) -> Either<&A, &B> {
+        match self {
+            Either::Left(value) => Either::Left(value),
+            Either::Right(value) => Either::Right(value),
+        }
+    }
+
+    /// Swaps the sides: `Left(a)` becomes `Right(a)` and vice versa.
+    pub fn flip(self) -> Either<B, A> {
+        match self {
+            Either::Left(value) => Either::Right(value),
+            Either::Right(value) => Either::Left(value),
+        }
+    }
+}
+
+/// ⬅️ Collects clones of every `Left` value in a slice, in order.
+///
+/// # Type Parameters
+/// - `A`: The left type. Must implement [`Clone`].
+/// - `B`: The right type.
+///
+/// # Arguments
+/// - `values`: The slice to read.
+///
+/// # Returns
+/// A `Vec<A>` with the left values; right values are skipped.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::either::{lefts, Either};
+///
+/// let values = [Either::Left(1), Either::Right('x'), Either::Left(2)];
+/// assert_eq!(lefts(&values), vec![1, 2]);
+/// ```
+pub fn lefts<A: Clone, B>(values: &[Either<A, B>]) -> Vec<A> {
+    values
+        .iter()
+        .filter_map(|value| value.as_ref().left().cloned())
+        .collect()
+}
+
+/// ➡️ Collects clones of every `Right` value in a slice, in order.
+///
+/// # Type Parameters
+/// - `A`: The left type.
+/// - `B`: The right type. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `values`: The slice to read.
+///
+/// # Returns
+/// A `Vec<B>` with the right values; left values are skipped.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::either::{rights, Either};
+///
+/// let values = [Either::Left(1), Either::Right('x'), Either::Left(2)];
+/// assert_eq!(rights(&values), vec!['x']);
+/// ```
+pub fn rights<A, B: Clone>(values: &[Either<A, B>]) -> Vec<B> {
+    values
+        .iter()
+        .filter_map(|value| value.as_ref().right().cloned())
+        .collect()
+}
+
+/// ↔️ Splits a sequence of `Either` values into its left and right values, without cloning.
+///
+/// # Type Parameters
+/// - `A`: The left type.
+/// - `B`: The right type.
+/// - `I`: Any iterable of `Either<A, B>`, such as a `Vec`.
+///
+/// # Arguments
+/// - `values`: The values to split. They are consumed.
+///
+/// # Returns
+/// A tuple `(lefts, rights)`, each in input order.
+///
+/// # Performance
+/// - **O(n)**; values are moved, not cloned.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::either::{partition_eithers, Either};
+///
+/// let routed = vec![Either::Left("ok"), Either::Right(404), Either::Left("fine")];
+/// let (bodies, codes) = partition_eithers(routed);
+/// assert_eq!(bodies, vec!["ok", "fine"]);
+/// assert_eq!(codes, vec![404]);
+/// ```
+pub fn partition_eithers<A, B, I>(values: I) -> (Vec<A>, Vec<B>)
+where
+    I: IntoIterator<Item = Either<A, B>>,
+{
+    let mut lefts = Vec::new();
+    let mut rights = Vec::new();
+    for value in values {
+        match value {
+            Either::Left(left) => lefts.push(left),
+            Either::Right(right) => rights.push(right),
+        }
+    }
+    (lefts, rights)
+}
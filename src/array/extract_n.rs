@@ -0,0 +1,57 @@
+/// 📤 Removes and returns up to `n` elements matching a predicate, preserving order.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector.
+/// - `P`: A predicate of the form `FnMut(&T) -> bool`.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to extract from.
+/// - `n`: The maximum number of elements to remove.
+/// - `predicate`: Returns `true` for elements that should be extracted.
+///
+/// # Returns
+/// A `Vec<T>` containing the extracted elements, in the order they appeared.
+///
+/// # Behavior
+/// - Scans from the front and moves the first `n` matching elements out of `values`.
+/// - Matching elements after the first `n` stay in place, as do all non-matching elements.
+/// - The relative order of the remaining elements is preserved.
+/// - The predicate is not called once `n` matches have been found; with `n == 0` it is never called.
+///
+/// # Performance
+/// - Built on [`Vec::extract_if`]: scanning stops after the `n`-th match, and the untouched
+///   tail is shifted down in one move.
+/// - Works in place: elements are moved, never cloned, and the capacity of `values` is kept.
+///
+/// # Examples
+///
+/// ### 📦 Drain a capped batch from a work queue
+/// ```
+/// use pencil_box::array::extract_n::extract_n;
+///
+/// let mut jobs = vec![("email", 1), ("sms", 2), ("email", 3), ("email", 4)];
+/// let batch = extract_n(&mut jobs, 2, |(kind, _)| *kind == "email");
+/// assert_eq!(batch, vec![("email", 1), ("email", 3)]);
+/// assert_eq!(jobs, vec![("sms", 2), ("email", 4)]);
+/// ```
+///
+/// ### 🔍 Fewer matches than the cap
+/// ```
+/// use pencil_box::array::extract_n::extract_n;
+///
+/// let mut data = vec![1, 2, 3, 4];
+/// assert_eq!(extract_n(&mut data, 10, |x| x % 2 == 0), vec![2, 4]);
+/// assert_eq!(data, vec![1, 3]);
+/// ```
+pub fn extract_n<T, P>(values: &mut Vec<T>, n: usize, mut predicate: P) -> Vec<T>
+where
+    P: FnMut(&T) -> bool,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+    values
+        .extract_if(.., |value| predicate(value))
+        .take(n)
+        .collect()
+}
@@ -0,0 +1,35 @@
+/// Creates a `Vec<T>` of a given size by repeating a pattern.
+///
+/// # Type Parameters
+/// - `T`: The element type of the vector. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `pattern`: The elements to cycle through, in order.
+/// - `size`: The number of elements in the resulting vector.
+///
+/// # Returns
+/// A new `Vec<T>` of length `size`, where element `i` is a clone of `pattern[i % pattern.len()]`.
+///
+/// # Behavior
+/// - The last repetition is cut short when `size` is not a multiple of the pattern length.
+/// - An empty `pattern` produces an empty vector, whatever `size` is.
+///
+/// # Performance
+/// - Allocates exactly once with capacity `size`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::fill_pattern::fill_pattern;
+///
+/// assert_eq!(fill_pattern(&[0, 1], 5), vec![0, 1, 0, 1, 0]);
+/// assert_eq!(fill_pattern(&["r", "g", "b"], 4), vec!["r", "g", "b", "r"]);
+/// ```
+pub fn fill_pattern<T: Clone>(pattern: &[T], size: usize) -> Vec<T> {
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+
+    let mut values = Vec::with_capacity(size);
+    values.extend(pattern.iter().cycle().take(size).cloned());
+    values
+}
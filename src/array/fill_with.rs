@@ -0,0 +1,77 @@
+/// Creates a `Vec<T>` of a given size, generating each element from its index.
+///
+/// # Type Parameters
+/// - `T`: The element type of the vector. No trait bounds are required.
+/// - `F`: A closure of the form `FnMut(usize) -> T`.
+///
+/// # Arguments
+/// - `size`: The number of elements in the resulting vector.
+/// - `f`: Called once per slot with the slot's index, in ascending order.
+///
+/// # Returns
+/// A new `Vec<T>` of length `size`, where element `i` is `f(i)`.
+///
+/// # Behavior
+/// - Complements [`fill_value`](crate::array::fill_value::fill_value) and
+///   [`fill_default`](crate::array::fill_default::fill_default) for values that differ per slot
+///   or that cannot be cloned.
+/// - Because `f` is `FnMut`, it may carry state between calls (counters, RNGs, iterators).
+///
+/// # Performance
+/// - Allocates exactly once with capacity `size`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::fill_with::fill_with;
+///
+/// assert_eq!(fill_with(5, |i| i * i), vec![0, 1, 4, 9, 16]);
+///
+/// let labels = fill_with(3, |i| format!("worker-{i}"));
+/// assert_eq!(labels, vec!["worker-0", "worker-1", "worker-2"]);
+/// ```
+pub fn fill_with<T, F: FnMut(usize) -> T>(size: usize, f: F) -> Vec<T> {
+    (0..size).map(f).collect()
+}
+
+/// Creates a `Vec<T>` of a given size from a fallible closure, stopping at the first error.
+///
+/// # Type Parameters
+/// - `T`: The element type of the vector. No trait bounds are required.
+/// - `E`: The error type returned by the closure.
+/// - `F`: A closure of the form `FnMut(usize) -> Result<T, E>`.
+///
+/// # Arguments
+/// - `size`: The number of elements in the resulting vector.
+/// - `f`: Called once per slot with the slot's index, in ascending order.
+///
+/// # Returns
+/// - `Ok(Vec<T>)` of length `size` if every call succeeded.
+/// - `Err(E)` with the first error; `f` is not called for later indices.
+///
+/// # Behavior
+/// - The fallible counterpart of [`fill_with`], avoiding an intermediate `Vec<Result<T, E>>`.
+///
+/// # Performance
+/// - Allocates once with capacity `size`; on error the partially built vector is dropped.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::fill_with::try_fill_with;
+///
+/// let raw = ["8080", "8081", "8082"];
+/// let ports: Result<Vec<u16>, _> = try_fill_with(raw.len(), |i| raw[i].parse());
+/// assert_eq!(ports, Ok(vec![8080, 8081, 8082]));
+///
+/// let bad = ["1", "two"];
+/// assert!(try_fill_with(bad.len(), |i| bad[i].parse::<u8>()).is_err());
+/// ```
+pub fn try_fill_with<T, E, F: FnMut(usize) -> Result<T, E>>(
+    size: usize,
+    mut f: F,
+) -> Result<Vec<T>, E> {
+    let mut values = Vec::with_capacity(size);
+    for index in 0..size {
+        values.push(f(index)?);
+    }
+    Ok(values)
+}
@@ -1,4 +1,3 @@
-
 /// 🔍 Returns the index of the **first** element in the slice that satisfies the predicate.
 ///
 /// # Type Parameters
@@ -75,3 +74,90 @@ pub fn find_index<T, M: Fn(&T) -> bool>(values: &[T], matcher: M) -> Option<usiz
     }
     None
 }
+
+/// 🔍 Returns the index of the first element at or after `start` that satisfies the predicate.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice.
+/// - `M`: A predicate function or closure that takes a reference to an element and returns `true` if it matches.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be scanned.
+/// - `start`: The index to start scanning from (inclusive).
+/// - `matcher`: A predicate function applied to each element.
+///
+/// # Returns
+/// - `Some(index)` of the first matching element, as an index into the **whole** slice, or
+/// - `None` if no element from `start` onwards satisfies the predicate.
+///
+/// # Behavior
+/// - Elements before `start` are never passed to `matcher`.
+/// - If `start >= values.len()`, returns `None`.
+/// - To resume after a previous match at `i`, pass `i + 1`.
+///
+/// # Performance
+/// - ✅ Worst-case: **O(n - start)**.
+/// - 🚫 No allocations or cloning.
+///
+/// # Examples
+/// ```rust
+/// use pencil_box::array::find_index::find_index_from;
+///
+/// let values = [1, 4, 2, 4, 5, 4];
+/// let first = find_index_from(&values, 0, |x| *x == 4);
+/// assert_eq!(first, Some(1));
+///
+/// let next = find_index_from(&values, first.unwrap() + 1, |x| *x == 4);
+/// assert_eq!(next, Some(3));
+///
+/// assert_eq!(find_index_from(&values, 10, |x| *x == 4), None);
+/// ```
+pub fn find_index_from<T, M: Fn(&T) -> bool>(
+    values: &[T],
+    start: usize,
+    matcher: M,
+) -> Option<usize> {
+    let position = values.get(start..)?.iter().position(matcher)?;
+    Some(start + position)
+}
+
+/// 🔍 Returns the index and mapped value of the first element for which `mapper` returns `Some`.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice.
+/// - `R`: The type produced by `mapper` for a matching element.
+/// - `F`: A function or closure that takes a reference to an element and returns `Option<R>`.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be scanned.
+/// - `mapper`: Returns `Some(value)` for a match, or `None` to keep scanning.
+///
+/// # Returns
+/// - `Some((index, value))` for the first element where `mapper` returns `Some`, or
+/// - `None` if `mapper` returns `None` for every element.
+///
+/// # Behavior
+/// - Combines [`find_index`] with a transform of the located element in a single traversal,
+///   e.g. finding and parsing the first numeric token.
+/// - Returns immediately on the first match.
+///
+/// # Performance
+/// - ✅ Worst-case: **O(n)**; `mapper` is called at most once per element.
+/// - 🚫 No allocations beyond what `mapper` itself performs.
+///
+/// # Examples
+/// ```rust
+/// use pencil_box::array::find_index::find_map_index;
+///
+/// let tokens = ["--verbose", "x", "42", "7"];
+/// let first_number = find_map_index(&tokens, |t| t.parse::<u32>().ok());
+/// assert_eq!(first_number, Some((2, 42)));
+///
+/// assert_eq!(find_map_index(&tokens, |t| t.parse::<f64>().ok().filter(|n| *n > 100.0)), None);
+/// ```
+pub fn find_map_index<T, R, F: Fn(&T) -> Option<R>>(values: &[T], mapper: F) -> Option<(usize, R)> {
+    values
+        .iter()
+        .enumerate()
+        .find_map(|(index, value)| mapper(value).map(|mapped| (index, mapped)))
+}
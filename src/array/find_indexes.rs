@@ -19,8 +19,10 @@
 ///
 /// # Performance
 /// - ✅ Linear time complexity **O(n)**, where *n* is the number of elements in the input slice.
-/// - ✅ Memory-efficient: pre-allocates capacity equal to the input length (worst-case).
-/// - 🚫 No heap allocations for non-matching elements.
+/// - ✅ Memory-efficient: the result grows on demand, so nothing is allocated when no element matches,
+///   and capacity stays within roughly twice the number of matches.
+/// - ⚠️ Trade-off: when most elements match, growth costs about `log2(matches)` reallocations
+///   compared to sizing the result for the whole input up front.
 ///
 /// # Examples
 /// 🔢 Basic numeric match:
@@ -81,7 +83,7 @@
 /// ✅ This function is panic-free for all valid inputs.
 ///
 pub fn find_indexes<T, M: Fn(&T) -> bool>(values: &[T], matcher: M) -> Vec<usize> {
-    let mut indexes = Vec::with_capacity(values.len());
+    let mut indexes = Vec::new();
     for (index, value) in values.iter().enumerate() {
         if matcher(value) {
             indexes.push(index);
@@ -89,3 +91,99 @@ pub fn find_indexes<T, M: Fn(&T) -> bool>(values: &[T], matcher: M) -> Vec<usize
     }
     indexes
 }
+
+/// 🔎 Returns the indices of at most `max` elements that satisfy the predicate, stopping early.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice.
+/// - `M`: A predicate function or closure that takes a reference to an element and returns `true` if it matches.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be scanned.
+/// - `matcher`: A predicate function applied to each element.
+/// - `max`: The maximum number of indices to return.
+///
+/// # Returns
+/// A `Vec<usize>` with the indices of the first `max` matching elements, in ascending order.
+///
+/// # Behavior
+/// - Same as [`find_indexes`], but the scan stops as soon as `max` matches have been found.
+/// - If `max` is `0`, returns an empty vector without calling `matcher`.
+///
+/// # Performance
+/// - ✅ Stops after the `max`-th match instead of scanning the whole slice.
+/// - ✅ The result grows on demand with no up-front reservation, so a large `max` such as
+///   `usize::MAX` ("no cap") costs nothing extra.
+///
+/// # Examples
+/// ```rust
+/// use pencil_box::array::find_indexes::find_indexes_limited;
+///
+/// let values = [1, 2, 3, 4, 5, 6, 7, 8];
+/// assert_eq!(find_indexes_limited(&values, |x| x % 2 == 0, 2), vec![1, 3]);
+/// assert_eq!(find_indexes_limited(&values, |x| x % 2 == 0, 10), vec![1, 3, 5, 7]);
+/// ```
+pub fn find_indexes_limited<T, M: Fn(&T) -> bool>(
+    values: &[T],
+    matcher: M,
+    max: usize,
+) -> Vec<usize> {
+    let mut indexes = Vec::new();
+    if max == 0 {
+        return indexes;
+    }
+    for (index, value) in values.iter().enumerate() {
+        if matcher(value) {
+            indexes.push(index);
+            if indexes.len() == max {
+                break;
+            }
+        }
+    }
+    indexes
+}
+
+/// 🔎 Lazily yields the indices where the predicate returns true, from the end toward the start.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the input slice.
+/// - `M`: A predicate function that takes a reference to an element and returns `true` if it matches.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be scanned.
+/// - `matcher`: A closure or function that determines whether an element should be included.
+///
+/// # Returns
+/// An iterator over the indices of matching elements, in **descending** order.
+///
+/// # Behavior
+/// - Walks the slice from the back, calling `matcher` only as items are requested.
+/// - Use `.take(n)` for the last `n` matches: the scan stops once `n` have been found, without
+///   collecting every match and reversing. `.collect()` gives all of them.
+/// - For just the last match, see [`find_last_index`](crate::array::find_last_index::find_last_index).
+///
+/// # Performance
+/// - ✅ **O(k)** where `k` is how far from the end the scan has to go; no allocation.
+///
+/// # Examples
+/// ```rust
+/// use pencil_box::array::find_indexes::find_indexes_rev;
+///
+/// let values = [1, 2, 3, 4, 5, 6];
+/// let last_two: Vec<usize> = find_indexes_rev(&values, |x| x % 2 == 0).take(2).collect();
+/// assert_eq!(last_two, vec![5, 3]);
+///
+/// let all: Vec<usize> = find_indexes_rev(&values, |x| x % 2 == 0).collect();
+/// assert_eq!(all, vec![5, 3, 1]);
+/// ```
+pub fn find_indexes_rev<'a, T, M: Fn(&T) -> bool + 'a>(
+    values: &'a [T],
+    matcher: M,
+) -> impl Iterator<Item = usize> + 'a {
+    values
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(move |(_, value)| matcher(value))
+        .map(|(index, _)| index)
+}
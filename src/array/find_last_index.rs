@@ -14,12 +14,12 @@
 /// - `None` if no element satisfies the predicate.
 ///
 /// # Behavior
-/// - Iterates through the entire slice from start to finish.
-/// - Keeps track of the most recent index where `matcher` returns `true`.
-/// - Returns that index after completion, or `None` if no match was found.
+/// - Iterates through the slice in reverse, from the last element to the first.
+/// - Returns the index of the first element (from the end) where `matcher` returns `true`.
+/// - Returns `None` if no match was found.
 ///
 /// # Performance
-/// - ✅ Time complexity: **O(n)** — scans all elements regardless of early match.
+/// - ✅ Time complexity: **O(n)** worst case — stops at the first match from the end.
 /// - ✅ Space complexity: **O(1)** — constant space, no allocations.
 /// - ✅ Zero heap allocations.
 ///
@@ -70,16 +70,84 @@
 /// ✅ Guaranteed panic-free for all valid input slices.
 ///
 pub fn find_last_index<T, M: Fn(&T) -> bool>(values: &[T], matcher: M) -> Option<usize> {
-    let mut last_found_at: usize = 0;
-    let mut is_found = false;
-    for (index, value) in values.iter().enumerate() {
-        if matcher(value) {
-            is_found = true;
-            last_found_at = index;
-        }
-    }
-    if is_found {
-        return Some(last_found_at);
-    }
-    None
+    values.iter().rposition(matcher)
+}
+
+/// 🔍 Returns a reference to the **last** element in the slice that satisfies the predicate.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice.
+/// - `M`: A predicate function or closure that takes a reference to an element and returns `true` if it matches.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be searched.
+/// - `matcher`: A predicate function applied to each element.
+///
+/// # Returns
+/// - `Some(&T)` pointing at the **last** matching element, or
+/// - `None` if no element satisfies the predicate.
+///
+/// # Behavior
+/// - Same scan as [`find_last_index`], returning the element instead of its index.
+///
+/// # Performance
+/// - ✅ Time complexity: **O(n)** worst case — stops at the first match from the end.
+/// - ✅ Zero heap allocations.
+///
+/// # Examples
+/// ```rust
+/// use pencil_box::array::find_last_index::find_last;
+///
+/// let values = ["alpha", "beta", "gamma", "bravo"];
+/// assert_eq!(find_last(&values, |s| s.starts_with('b')), Some(&"bravo"));
+/// assert_eq!(find_last(&values, |s| s.is_empty()), None);
+/// ```
+pub fn find_last<T, M: Fn(&T) -> bool>(values: &[T], matcher: M) -> Option<&T> {
+    values.iter().rev().find(|value| matcher(value))
+}
+
+/// 🔍 Returns the index of the last element before `end` that satisfies the predicate.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the slice.
+/// - `M`: A predicate function or closure that takes a reference to an element and returns `true` if it matches.
+///
+/// # Arguments
+/// - `values`: A reference to a slice of elements to be searched.
+/// - `end`: The index to stop at (exclusive). Only `values[..end]` is searched.
+/// - `matcher`: A predicate function applied to each element.
+///
+/// # Returns
+/// - `Some(index)` of the last matching element before `end`, or
+/// - `None` if no such element exists.
+///
+/// # Behavior
+/// - Scans in reverse starting at `end - 1`; elements at or after `end` are never passed to `matcher`.
+/// - If `end` exceeds the slice length, the whole slice is searched.
+/// - To resume before a previous match at `i`, pass `i`.
+///
+/// # Performance
+/// - ✅ Time complexity: **O(end)** worst case — stops at the first match from the end.
+/// - ✅ Zero heap allocations.
+///
+/// # Examples
+/// ```rust
+/// use pencil_box::array::find_last_index::find_last_index_from;
+///
+/// let values = [4, 1, 4, 2, 4];
+/// let last = find_last_index_from(&values, values.len(), |x| *x == 4);
+/// assert_eq!(last, Some(4));
+///
+/// let previous = find_last_index_from(&values, last.unwrap(), |x| *x == 4);
+/// assert_eq!(previous, Some(2));
+///
+/// assert_eq!(find_last_index_from(&values, 0, |x| *x == 4), None);
+/// ```
+pub fn find_last_index_from<T, M: Fn(&T) -> bool>(
+    values: &[T],
+    end: usize,
+    matcher: M,
+) -> Option<usize> {
+    let end = end.min(values.len());
+    values[..end].iter().rposition(matcher)
 }
@@ -36,7 +36,8 @@
 /// - 📈 Time complexity is **O(n)** where `n` is the total number of elements across all inner collections.
 /// - `.cloned()` operates **per-element**, not as a full slice clone — each `T` is cloned individually using `T::clone()`.
 /// - There is **no quadratic behavior**, because no full slice or repeated reallocation occurs during iteration.
-/// - The final vector is allocated with capacity and built efficiently via `.collect()`.
+/// - The total length is summed up front, so the output is allocated **once** with `Vec::with_capacity`
+///   and never reallocates while elements are appended.
 ///
 /// # Examples
 ///
@@ -110,16 +111,82 @@
 ///
 /// - [`flat_map`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.flat_map)
 /// - [`concat`](https://doc.rust-lang.org/std/slice/fn.concat.html) for `Vec<Vec<T>>` only
-
 pub fn flatten<T: Clone, Outer, Inner>(nested: Outer) -> Vec<T>
 where
     Outer: AsRef<[Inner]>,
     Inner: AsRef<[T]>,
 {
-    nested
-        .as_ref()
-        .iter()
-        .flat_map(|inner| inner.as_ref().iter().cloned())
-        .collect()
+    let nested = nested.as_ref();
+    let capacity = nested.iter().map(|inner| inner.as_ref().len()).sum();
+    let mut result = Vec::with_capacity(capacity);
+    for inner in nested {
+        result.extend_from_slice(inner.as_ref());
+    }
+    result
+}
 
-}
\ No newline at end of file
+/// 📦 Flattens a `Vec<Vec<T>>` into a single `Vec<T>` by **moving** every element, without cloning.
+///
+/// # Type Parameters
+///
+/// - `T`: The inner element type. No trait bounds are required.
+///
+/// # Arguments
+///
+/// - `nested`: The nested vector to consume. Ownership is taken, and the inner vectors are drained.
+///
+/// # Returns
+///
+/// A flattened `Vec<T>` containing all elements from `nested` in order.
+///
+/// # Behavior
+///
+/// - Consumes `nested` and moves each element into the output vector.
+/// - Preserves order of elements across all inner vectors.
+/// - Empty inner vectors are skipped; an empty outer vector yields an empty result.
+///
+/// # Performance
+///
+/// - 📈 Time complexity is **O(n)** where `n` is the total number of elements.
+/// - 🚫 No `Clone` is performed — heap-owning elements such as `String` are moved, not duplicated.
+/// - The output is allocated once with the summed capacity of all inner vectors.
+///
+/// # Examples
+///
+/// ### 🧵 Move strings without cloning
+/// ```rust
+/// use pencil_box::array::flatten::flatten_into;
+///
+/// let nested = vec![
+///     vec!["foo".to_string(), "bar".to_string()],
+///     vec!["baz".to_string()],
+/// ];
+/// assert_eq!(flatten_into(nested), vec!["foo", "bar", "baz"]);
+/// ```
+///
+/// ### 🚫 Works with non-`Clone` types
+/// ```rust
+/// use pencil_box::array::flatten::flatten_into;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Handle(u32);
+///
+/// let nested = vec![vec![Handle(1)], vec![], vec![Handle(2), Handle(3)]];
+/// assert_eq!(flatten_into(nested), vec![Handle(1), Handle(2), Handle(3)]);
+/// ```
+///
+/// # Panic Safety
+///
+/// ✅ This function is panic-free for valid inputs.
+///
+/// # See Also
+///
+/// - [`flatten`] for a borrowing variant that clones elements.
+pub fn flatten_into<T>(nested: Vec<Vec<T>>) -> Vec<T> {
+    let capacity = nested.iter().map(|inner| inner.len()).sum();
+    let mut result = Vec::with_capacity(capacity);
+    for mut inner in nested {
+        result.append(&mut inner);
+    }
+    result
+}
@@ -0,0 +1,155 @@
+use crate::array::stable_hash::{stable_hash, StableHasher};
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+/// 💍 A consistent-hashing ring that routes keys to nodes with minimal re-mapping on membership changes.
+///
+/// Each node is placed on the ring at `virtual_nodes` pseudo-random points. A key is routed to the
+/// first node point at or after the key's own hash, wrapping around the ring. Adding or removing a
+/// node only moves the keys that land on that node's points, unlike
+/// [`assign_by_hash`](crate::array::assign::assign_by_hash), where changing the bucket count moves most keys.
+///
+/// # Type Parameters
+/// - `N`: The node type (e.g. a hostname). Must implement [`Hash`], [`Eq`], and [`Clone`].
+///
+/// # Behavior
+/// - Hashing uses [`StableHasher`], so routing depends only on the nodes and the order they were
+///   added. It is the same across processes, platforms, and Rust releases.
+/// - More virtual nodes give a more even spread at the cost of memory and insert time.
+/// - If points of two different nodes hash to the same position, the node added first owns it
+///   and the other is remembered behind it. Removing the owner hands the point to the next node
+///   in insertion order, so routing always matches a ring freshly built from the remaining nodes.
+///
+/// # Performance
+/// - `route` is **O(log v)** where `v` is the total number of virtual nodes.
+/// - `add_node` and `remove_node` are **O(r log v)** where `r` is the virtual nodes per node:
+///   removal recomputes the node's own points instead of scanning the whole ring.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::hash_ring::HashRing;
+///
+/// let mut ring = HashRing::new(64);
+/// ring.add_node("cache-a");
+/// ring.add_node("cache-b");
+/// ring.add_node("cache-c");
+///
+/// let owner = *ring.route("user:42").unwrap();
+/// assert_eq!(ring.route("user:42"), Some(&owner));
+///
+/// ring.remove_node(&owner);
+/// assert_ne!(ring.route("user:42"), Some(&owner));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashRing<N: Hash + Eq + Clone> {
+    virtual_nodes: usize,
+    ring: BTreeMap<u64, N>,
+    /// Nodes whose point collided with an existing owner, in insertion order.
+    shadowed: BTreeMap<u64, Vec<N>>,
+    nodes: Vec<N>,
+}
+
+impl<N: Hash + Eq + Clone> HashRing<N> {
+    /// Creates an empty ring that places each node at `virtual_nodes` points.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub const fn new(virtual_nodes: usize) -> Self {
+        Self {
+            virtual_nodes: if virtual_nodes == 0 { 1 } else { virtual_nodes },
+            ring: BTreeMap::new(),
+            shadowed: BTreeMap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Adds a node to the ring. Returns `false` if the node was already present.
+    pub fn add_node(&mut self, node: N) -> bool {
+        if self.nodes.contains(&node) {
+            return false;
+        }
+        for replica in 0..self.virtual_nodes {
+            let point = point_hash(&node, replica);
+            match self.ring.get(&point) {
+                None => {
+                    self.ring.insert(point, node.clone());
+                }
+                Some(owner) if *owner == node => {}
+                Some(_) => {
+                    let shadows = self.shadowed.entry(point).or_default();
+                    if !shadows.contains(&node) {
+                        shadows.push(node.clone());
+                    }
+                }
+            }
+        }
+        self.nodes.push(node);
+        true
+    }
+
+    /// Removes a node and all of its points. Returns `false` if the node was not present.
+    pub fn remove_node(&mut self, node: &N) -> bool {
+        let Some(position) = self.nodes.iter().position(|n| n == node) else {
+            return false;
+        };
+        self.nodes.remove(position);
+        for replica in 0..self.virtual_nodes {
+            let point = point_hash(node, replica);
+            if self.ring.get(&point) == Some(node) {
+                self.ring.remove(&point);
+                if let Some(next) = self.take_shadow(point, 0) {
+                    self.ring.insert(point, next);
+                }
+            } else if let Some(index) = self
+                .shadowed
+                .get(&point)
+                .and_then(|shadows| shadows.iter().position(|n| n == node))
+            {
+                self.take_shadow(point, index);
+            }
+        }
+        true
+    }
+
+    /// Returns the node responsible for `key`, or `None` if the ring is empty.
+    pub fn route<K: Hash + ?Sized>(&self, key: &K) -> Option<&N> {
+        let hash = stable_hash(key);
+        self.ring
+            .range(hash..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, node)| node)
+    }
+
+    /// Returns the nodes currently on the ring, in insertion order.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes (not virtual nodes) on the ring.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the ring has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Removes and returns the `index`-th node shadowed at `point`, if any.
+    fn take_shadow(&mut self, point: u64, index: usize) -> Option<N> {
+        let shadows = self.shadowed.get_mut(&point)?;
+        let node = (index < shadows.len()).then(|| shadows.remove(index));
+        if shadows.is_empty() {
+            self.shadowed.remove(&point);
+        }
+        node
+    }
+}
+
+/// Hashes the `replica`-th virtual point of `node`.
+fn point_hash<N: Hash>(node: &N, replica: usize) -> u64 {
+    let mut hasher = StableHasher::new();
+    node.hash(&mut hasher);
+    replica.hash(&mut hasher);
+    hasher.finish()
+}
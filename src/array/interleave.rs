@@ -0,0 +1,56 @@
+/// 🔀 Merges several collections round-robin: the first element of each, then the second, and so on.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+/// - `U`: A slice-like container that implements `AsRef<[T]>`, such as `Vec<T>` or `&[T]`.
+///
+/// # Arguments
+/// - `values`: The collections to interleave, visited in order within each round.
+/// - `stop_at_shortest`: If `true`, only complete rounds are emitted, so the output stops once the
+///   shortest collection runs out. If `false`, exhausted collections are skipped and the rest
+///   continue until every element has been emitted.
+///
+/// # Returns
+/// A `Vec<T>` with the interleaved elements.
+///
+/// # Behavior
+/// - With `stop_at_shortest == true`, the result has `values.len() × min_len` elements, and is
+///   empty if any collection is empty.
+/// - With `stop_at_shortest == false`, every element of every collection appears exactly once.
+/// - An empty `values` gives an empty result.
+///
+/// # Performance
+/// - **O(total)**; the output is allocated once at its final size.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::interleave::interleave;
+///
+/// let queues = [vec!["a1", "a2", "a3"], vec!["b1"], vec!["c1", "c2"]];
+/// assert_eq!(
+///     interleave(&queues, false),
+///     vec!["a1", "b1", "c1", "a2", "c2", "a3"]
+/// );
+/// assert_eq!(interleave(&queues, true), vec!["a1", "b1", "c1"]);
+/// ```
+pub fn interleave<T: Clone, U: AsRef<[T]>>(values: &[U], stop_at_shortest: bool) -> Vec<T> {
+    let lengths = values.iter().map(|value| value.as_ref().len());
+    let (rounds, total) = if stop_at_shortest {
+        let rounds = lengths.min().unwrap_or(0);
+        (rounds, rounds * values.len())
+    } else {
+        lengths.fold((0, 0), |(rounds, total), len| {
+            (rounds.max(len), total + len)
+        })
+    };
+
+    let mut merged = Vec::with_capacity(total);
+    for round in 0..rounds {
+        for value in values {
+            if let Some(item) = value.as_ref().get(round) {
+                merged.push(item.clone());
+            }
+        }
+    }
+    merged
+}
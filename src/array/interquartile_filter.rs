@@ -0,0 +1,114 @@
+/// 📦 Removes values outside the Tukey fences `[Q1 - k·IQR, Q3 + k·IQR]`, in place.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector of samples to filter.
+/// - `k`: The fence multiplier. `1.5` is the conventional choice for outliers, `3.0` for
+///   "far out" values.
+///
+/// # Returns
+/// - `Ok(removed)` with the number of values dropped.
+/// - `Err(&'static str)` if `k` is negative or not finite; the vector is unchanged.
+///
+/// # Behavior
+/// - Quartiles are computed by linear interpolation between closest ranks (the same method as
+///   NumPy's default `percentile`).
+/// - `NaN` values are ignored when computing quartiles and are always removed.
+/// - Values exactly on a fence are kept.
+/// - The order of the remaining values is preserved.
+///
+/// # Performance
+/// - **O(n log n)**: a sorted copy of the finite values is used to find the quartiles.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::interquartile_filter::remove_outliers_iqr;
+///
+/// let mut latencies = vec![12.0, 15.0, 11.0, 14.0, 13.0, 250.0, 12.5];
+/// assert_eq!(remove_outliers_iqr(&mut latencies, 1.5), Ok(1));
+/// assert_eq!(latencies, vec![12.0, 15.0, 11.0, 14.0, 13.0, 12.5]);
+/// ```
+pub fn remove_outliers_iqr(values: &mut Vec<f64>, k: f64) -> Result<usize, &'static str> {
+    if !k.is_finite() || k < 0.0 {
+        return Err("k must be finite and non-negative");
+    }
+
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+    sorted.sort_unstable_by(f64::total_cmp);
+
+    let before = values.len();
+    if sorted.is_empty() {
+        values.clear();
+        return Ok(before);
+    }
+
+    let q1 = quantile(&sorted, 0.25);
+    let q3 = quantile(&sorted, 0.75);
+    let spread = k * (q3 - q1);
+    let (low, high) = (q1 - spread, q3 + spread);
+
+    values.retain(|v| *v >= low && *v <= high);
+    Ok(before - values.len())
+}
+
+/// 📏 Removes values more than `threshold` standard deviations from the mean, in place.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector of samples to filter.
+/// - `threshold`: The maximum allowed absolute z-score, e.g. `3.0`.
+///
+/// # Returns
+/// - `Ok(removed)` with the number of values dropped.
+/// - `Err(&'static str)` if `threshold` is negative or not finite; the vector is unchanged.
+///
+/// # Behavior
+/// - Uses the population standard deviation of the non-`NaN` values.
+/// - `NaN` values are always removed.
+/// - If every value is equal (standard deviation `0`), no finite value is removed.
+/// - The order of the remaining values is preserved.
+///
+/// # Performance
+/// - ✅ **O(n)**: two passes for mean and variance, one for filtering. No allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::interquartile_filter::remove_outliers_zscore;
+///
+/// let mut readings = vec![10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 40.0];
+/// assert_eq!(remove_outliers_zscore(&mut readings, 2.0), Ok(1));
+/// assert!(readings.iter().all(|r| *r == 10.0));
+/// ```
+pub fn remove_outliers_zscore(
+    values: &mut Vec<f64>,
+    threshold: f64,
+) -> Result<usize, &'static str> {
+    if !threshold.is_finite() || threshold < 0.0 {
+        return Err("threshold must be finite and non-negative");
+    }
+
+    let before = values.len();
+    let count = values.iter().filter(|v| !v.is_nan()).count();
+    if count == 0 {
+        values.clear();
+        return Ok(before);
+    }
+
+    let mean = values.iter().filter(|v| !v.is_nan()).sum::<f64>() / count as f64;
+    let variance = values
+        .iter()
+        .filter(|v| !v.is_nan())
+        .map(|v| (v - mean).powi(2))
+        .sum::<f64>()
+        / count as f64;
+    let limit = threshold * variance.sqrt();
+
+    values.retain(|v| (v - mean).abs() <= limit);
+    Ok(before - values.len())
+}
+
+/// Returns the `q`-quantile of `sorted` (non-empty, ascending), interpolating between ranks.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
@@ -1,5 +1,6 @@
+use std::collections::hash_map::RandomState;
 use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash};
 
 /// Computes the intersection of multiple collections, returning only elements common to **all** inputs.
 ///
@@ -44,6 +45,8 @@ use std::hash::Hash;
 ///
 /// - **Time Complexity**: O(n × m), where `n` is the number of input collections and `m` is the average length of each collection.
 /// - **Space Complexity**: O(u), where `u` is the number of unique elements across all collections.
+/// - By default, hashes with SipHash like [`intersection_secure`]; with the `fast-hash` feature,
+///   uses AHash like [`intersection_performant`].
 ///
 /// # Panic Safety
 ///
@@ -203,9 +206,71 @@ where
     U: AsRef<[T]>,
     T: Clone + Eq + Hash,
 {
-    let mut count: HashMap<T, usize> = HashMap::new();
+    if cfg!(feature = "fast-hash") {
+        intersection_performant(values)
+    } else {
+        intersection_secure(values)
+    }
+}
+
+/// 🔒 Computes the intersection using SipHash, regardless of the `fast-hash` feature.
+///
+/// # Behavior
+/// - Identical in output to [`intersection`]; the result order is unspecified.
+///
+/// # Performance
+/// - Uses [`HashMap`] and [`HashSet`] (SipHash): **secure and collision-resistant**, suitable
+///   for untrusted input.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::intersection::intersection_secure;
+///
+/// let result = intersection_secure(&[vec!["a", "b"], vec!["b", "c"]]);
+/// assert_eq!(result, vec!["b"]);
+/// ```
+pub fn intersection_secure<T, U>(values: &[U]) -> Vec<T>
+where
+    U: AsRef<[T]>,
+    T: Clone + Eq + Hash,
+{
+    intersection_with_hasher::<T, U, RandomState>(values)
+}
+
+/// ⚡ Computes the intersection using AHash for faster hashing.
+///
+/// # Behavior
+/// - Identical in output to [`intersection`]; the result order is unspecified.
+///
+/// # Performance
+/// - ⚡ Uses [`ahash`], a fast, non-cryptographic hash.
+/// - 🚀 Faster than SipHash for large data, but **not DoS-resistant** (not safe for untrusted input).
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::intersection::intersection_performant;
+///
+/// let a: Vec<u32> = (0..1_000).collect();
+/// let b: Vec<u32> = (500..1_500).collect();
+/// assert_eq!(intersection_performant(&[a, b]).len(), 500);
+/// ```
+pub fn intersection_performant<T, U>(values: &[U]) -> Vec<T>
+where
+    U: AsRef<[T]>,
+    T: Clone + Eq + Hash,
+{
+    intersection_with_hasher::<T, U, ahash::RandomState>(values)
+}
+
+fn intersection_with_hasher<T, U, S>(values: &[U]) -> Vec<T>
+where
+    U: AsRef<[T]>,
+    T: Clone + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    let mut count: HashMap<T, usize, S> = HashMap::default();
     for sub_array in values {
-        let mut seen = HashSet::new();
+        let mut seen: HashSet<&T, S> = HashSet::default();
         for item in sub_array.as_ref().iter() {
             if seen.insert(item) {
                 count
@@ -0,0 +1,183 @@
+use std::ops::{Add, Sub};
+
+/// 🧩 Sorts and merges overlapping or touching intervals into a minimal set.
+///
+/// Intervals are half-open `(start, end)` pairs covering `start..end`.
+///
+/// # Type Parameters
+/// - `T`: The bound type. Must implement [`Ord`] and [`Copy`].
+///
+/// # Arguments
+/// - `intervals`: The intervals to merge, in any order.
+///
+/// # Returns
+/// A new `Vec<(T, T)>` of disjoint intervals, sorted by start, where no two intervals touch.
+///
+/// # Behavior
+/// - Intervals that overlap or share an endpoint (`(1, 3)` and `(3, 5)`) are merged.
+/// - Empty or inverted intervals (`start >= end`) are ignored.
+/// - This normalized form is what the other functions in this module operate on.
+///
+/// # Performance
+/// - **O(n log n)** for the sort, then a single **O(n)** merge pass.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::intervals::merge_overlapping;
+///
+/// let merged = merge_overlapping(&[(5, 8), (1, 3), (2, 4), (8, 9), (12, 12)]);
+/// assert_eq!(merged, vec![(1, 4), (5, 9)]);
+/// ```
+pub fn merge_overlapping<T: Ord + Copy>(intervals: &[(T, T)]) -> Vec<(T, T)> {
+    let mut sorted: Vec<(T, T)> = intervals
+        .iter()
+        .copied()
+        .filter(|(start, end)| start < end)
+        .collect();
+    sorted.sort_unstable();
+
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// 🔗 Returns the ranges covered by both interval sets.
+///
+/// # Type Parameters
+/// - `T`: The bound type. Must implement [`Ord`] and [`Copy`].
+///
+/// # Arguments
+/// - `a`: The first set of half-open intervals, in any order.
+/// - `b`: The second set of half-open intervals, in any order.
+///
+/// # Returns
+/// A sorted `Vec<(T, T)>` of disjoint, non-empty intervals covered by both `a` and `b`.
+///
+/// # Behavior
+/// - Both inputs are normalized with [`merge_overlapping`] first.
+/// - Intervals that only touch at an endpoint do not intersect.
+///
+/// # Performance
+/// - **O(n log n + m log m)** for normalization, then a linear two-pointer sweep.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::intervals::intersect_sets;
+///
+/// let busy = [(9, 12), (13, 17)];
+/// let office = [(8, 10), (11, 14)];
+/// assert_eq!(intersect_sets(&busy, &office), vec![(9, 10), (11, 12), (13, 14)]);
+/// ```
+pub fn intersect_sets<T: Ord + Copy>(a: &[(T, T)], b: &[(T, T)]) -> Vec<(T, T)> {
+    let a = merge_overlapping(a);
+    let b = merge_overlapping(b);
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start < end {
+            result.push((start, end));
+        }
+
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+/// 🕳️ Returns the parts of `range` not covered by any interval.
+///
+/// # Type Parameters
+/// - `T`: The bound type. Must implement [`Ord`] and [`Copy`].
+///
+/// # Arguments
+/// - `range`: The half-open `(start, end)` range to search for gaps.
+/// - `intervals`: The covering intervals, in any order. Parts outside `range` are ignored.
+///
+/// # Returns
+/// A sorted `Vec<(T, T)>` of the uncovered sub-ranges of `range`.
+///
+/// # Behavior
+/// - If `range` is empty or inverted, returns an empty vector.
+/// - If no interval overlaps `range`, returns `vec![range]`.
+///
+/// # Performance
+/// - **O(n log n)** for normalization, then a linear sweep.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::intervals::find_gaps;
+///
+/// let downloaded = [(0, 100), (250, 300), (150, 200)];
+/// assert_eq!(find_gaps((0, 400), &downloaded), vec![(100, 150), (200, 250), (300, 400)]);
+/// ```
+pub fn find_gaps<T: Ord + Copy>(range: (T, T), intervals: &[(T, T)]) -> Vec<(T, T)> {
+    let (range_start, range_end) = range;
+    if range_start >= range_end {
+        return Vec::new();
+    }
+
+    let mut gaps = Vec::new();
+    let mut cursor = range_start;
+    for (start, end) in merge_overlapping(intervals) {
+        if end <= cursor {
+            continue;
+        }
+        if start >= range_end {
+            break;
+        }
+        if start > cursor {
+            gaps.push((cursor, start));
+        }
+        cursor = end;
+        if cursor >= range_end {
+            break;
+        }
+    }
+
+    if cursor < range_end {
+        gaps.push((cursor, range_end));
+    }
+    gaps
+}
+
+/// 📏 Returns the total length covered by a set of intervals, counting overlaps once.
+///
+/// # Type Parameters
+/// - `T`: The bound type. Must implement [`Ord`], [`Copy`], [`Default`] (used as zero),
+///   and support [`Add`] and [`Sub`].
+///
+/// # Arguments
+/// - `intervals`: The half-open intervals, in any order.
+///
+/// # Returns
+/// The sum of `end - start` over the merged intervals, or `T::default()` for empty input.
+///
+/// # Performance
+/// - **O(n log n)** via [`merge_overlapping`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::intervals::total_coverage;
+///
+/// assert_eq!(total_coverage(&[(0, 10), (5, 15), (20, 25)]), 20);
+/// assert_eq!(total_coverage::<u32>(&[]), 0);
+/// ```
+pub fn total_coverage<T>(intervals: &[(T, T)]) -> T
+where
+    T: Ord + Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    merge_overlapping(intervals)
+        .into_iter()
+        .fold(T::default(), |total, (start, end)| total + (end - start))
+}
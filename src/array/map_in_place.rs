@@ -0,0 +1,72 @@
+/// 🔁 Replaces every element with the result of `mapper`, reusing the vector's allocation.
+///
+/// # Type Parameters
+/// - `T`: The element type. No trait bounds are required.
+/// - `F`: A function of the form `FnMut(T) -> T`.
+///
+/// # Arguments
+/// - `values`: The vector to transform in place.
+/// - `mapper`: Called once per element, in order, with the element by value.
+///
+/// # Behavior
+/// - Length and order are preserved.
+/// - Elements are moved into `mapper`, so non-`Clone` types work and nothing is cloned.
+/// - If `mapper` panics, `values` is left empty; the remaining elements are dropped.
+///
+/// # Performance
+/// - ✅ **O(n)** with no new allocation: the mapped elements are written back into the
+///   existing buffer.
+/// - Prefer this over `values.iter().map(...).collect()` plus reassignment, which allocates
+///   a second vector.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::map_in_place::map_in_place;
+///
+/// let mut names = vec![String::from("ada"), String::from("linus")];
+/// map_in_place(&mut names, |name| name.to_uppercase());
+/// assert_eq!(names, vec!["ADA", "LINUS"]);
+/// ```
+pub fn map_in_place<T, F>(values: &mut Vec<T>, mapper: F)
+where
+    F: FnMut(T) -> T,
+{
+    *values = map_convert(std::mem::take(values), mapper);
+}
+
+/// 🔀 Converts a vector of `T` into a vector of `U`, reusing the allocation when possible.
+///
+/// # Type Parameters
+/// - `T`: The input element type.
+/// - `U`: The output element type.
+/// - `F`: A function of the form `FnMut(T) -> U`.
+///
+/// # Arguments
+/// - `values`: The vector to convert. It is consumed.
+/// - `mapper`: Called once per element, in order, with the element by value.
+///
+/// # Returns
+/// A `Vec<U>` with one converted element per input element, in order.
+///
+/// # Performance
+/// - ✅ **O(n)**. Built on `Vec::into_iter().map().collect()`, which the standard library
+///   collects in place when `T` and `U` have the same size and alignment (for example
+///   `u32` → `i32`, `String` → `Vec<u8>`, or a newtype wrapper), so no new buffer is allocated.
+/// - When the layouts differ, a new vector of exactly `values.len()` elements is allocated.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::map_in_place::map_convert;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct UserId(u64);
+///
+/// let ids = map_convert(vec![7u64, 42], UserId);
+/// assert_eq!(ids, vec![UserId(7), UserId(42)]);
+/// ```
+pub fn map_convert<T, U, F>(values: Vec<T>, mapper: F) -> Vec<U>
+where
+    F: FnMut(T) -> U,
+{
+    values.into_iter().map(mapper).collect()
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::fmt;
+
+/// ❗ Error returned by [`transpose`] when the rows have different lengths.
+///
+/// # Fields
+/// - `row`: Zero-based index of the first row whose length differs from the first row.
+/// - `expected`: The length of the first row.
+/// - `found`: The length of the offending row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransposeError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for TransposeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} has {} elements, expected {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+impl Error for TransposeError {}
+
+/// 🔃 Transposes a row-major matrix, turning rows into columns.
+///
+/// # Type Parameters
+/// - `T`: The cell type. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `matrix`: The rows, which must all have the same length.
+///
+/// # Returns
+/// - `Ok(Vec<Vec<T>>)` where `result[c][r] == matrix[r][c]`.
+/// - `Err(TransposeError)` for the first row whose length differs from the first row.
+///
+/// # Behavior
+/// - An empty matrix, or one whose rows are all empty, yields `Ok(vec![])`.
+/// - For ragged input that should be padded instead, see [`transpose_padded`].
+///
+/// # Performance
+/// - ✅ **O(rows × columns)**; each cell is cloned once and every column is allocated at its
+///   final size.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::matrix::transpose;
+///
+/// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(transpose(&rows).unwrap(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+///
+/// let ragged = vec![vec![1, 2], vec![3]];
+/// assert_eq!(transpose(&ragged).unwrap_err().row, 1);
+/// ```
+pub fn transpose<T: Clone>(matrix: &[Vec<T>]) -> Result<Vec<Vec<T>>, TransposeError> {
+    let expected = matrix.first().map_or(0, Vec::len);
+    if let Some((row, found)) = matrix
+        .iter()
+        .map(Vec::len)
+        .enumerate()
+        .find(|(_, len)| *len != expected)
+    {
+        return Err(TransposeError {
+            row,
+            expected,
+            found,
+        });
+    }
+
+    Ok((0..expected)
+        .map(|column| matrix.iter().map(|row| row[column].clone()).collect())
+        .collect())
+}
+
+/// 🔃 Transposes a possibly ragged matrix, padding missing cells with `T::default()`.
+///
+/// # Type Parameters
+/// - `T`: The cell type. Must implement [`Clone`] and [`Default`].
+///
+/// # Arguments
+/// - `matrix`: The rows, of any lengths.
+///
+/// # Returns
+/// A `Vec<Vec<T>>` with one column per cell of the longest row, each holding one value per row.
+///
+/// # Behavior
+/// - Rows shorter than the longest row contribute `T::default()` for their missing cells.
+/// - For rectangular input, the result equals that of [`transpose`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::matrix::transpose_padded;
+///
+/// let rows = vec![vec!["a", "b"], vec!["c"]];
+/// assert_eq!(transpose_padded(&rows), vec![vec!["a", "c"], vec!["b", ""]]);
+/// ```
+pub fn transpose_padded<T: Clone + Default>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
+    let columns = matrix.iter().map(Vec::len).max().unwrap_or(0);
+    (0..columns)
+        .map(|column| {
+            matrix
+                .iter()
+                .map(|row| row.get(column).cloned().unwrap_or_default())
+                .collect()
+        })
+        .collect()
+}
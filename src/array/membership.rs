@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// ➕ Pushes `value` onto the vector unless an equal element is already present.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. Must implement [`PartialEq`].
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector, treated as a small set.
+/// - `value`: The value that must be present afterwards.
+///
+/// # Returns
+/// `true` if `value` was pushed, `false` if an equal element was already present.
+///
+/// # Behavior
+/// - Existing elements are never reordered; a new value is appended at the end.
+/// - When the value is already present, it is dropped and the vector is left unchanged.
+///
+/// # Performance
+/// - **O(n)** linear scan. For many insertions at once, prefer [`ensure_contains_all`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::membership::ensure_contains;
+///
+/// let mut tags = vec!["rust", "cli"];
+/// assert!(ensure_contains(&mut tags, "wasm"));
+/// assert!(!ensure_contains(&mut tags, "rust"));
+/// assert_eq!(tags, vec!["rust", "cli", "wasm"]);
+/// ```
+pub fn ensure_contains<T: PartialEq>(values: &mut Vec<T>, value: T) -> bool {
+    if values.contains(&value) {
+        return false;
+    }
+    values.push(value);
+    true
+}
+
+/// 🔀 Removes `value` from the vector if present, otherwise pushes it.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. Must implement [`PartialEq`].
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector, treated as a small set.
+/// - `value`: The value whose membership is flipped.
+///
+/// # Returns
+/// `true` if `value` is present after the call (it was pushed), `false` if it was removed.
+///
+/// # Behavior
+/// - Removes **every** element equal to `value`, so vectors that already hold duplicates end up without it.
+/// - The relative order of the remaining elements is preserved.
+/// - A newly added value is appended at the end.
+///
+/// # Performance
+/// - **O(n)**: one scan to find a match, plus a `retain` pass when removing.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::membership::toggle;
+///
+/// let mut selected = vec![1, 3];
+/// assert!(toggle(&mut selected, 2));
+/// assert_eq!(selected, vec![1, 3, 2]);
+///
+/// assert!(!toggle(&mut selected, 3));
+/// assert_eq!(selected, vec![1, 2]);
+/// ```
+pub fn toggle<T: PartialEq>(values: &mut Vec<T>, value: T) -> bool {
+    if values.contains(&value) {
+        values.retain(|existing| existing != &value);
+        false
+    } else {
+        values.push(value);
+        true
+    }
+}
+
+/// ⚡ Pushes each item that is not already present, using a [`HashSet`] for lookups.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Eq`], [`Hash`], and [`Clone`].
+/// - `I`: Any iterable yielding `T`.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector, treated as a set.
+/// - `items`: The values that must be present afterwards.
+///
+/// # Returns
+/// The number of items that were pushed.
+///
+/// # Behavior
+/// - Equivalent to calling [`ensure_contains`] for each item in order.
+/// - Duplicates within `items` are only pushed once.
+/// - Existing duplicates inside `values` are left as they are.
+///
+/// # Performance
+/// - **O(n + m)** where `n = values.len()` and `m` is the number of items.
+/// - Clones each existing element once to seed the lookup set, and each pushed item once.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::membership::ensure_contains_all;
+///
+/// let mut ids = vec![1, 2, 3];
+/// assert_eq!(ensure_contains_all(&mut ids, [3, 4, 4, 5]), 2);
+/// assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn ensure_contains_all<T, I>(values: &mut Vec<T>, items: I) -> usize
+where
+    T: Eq + Hash + Clone,
+    I: IntoIterator<Item = T>,
+{
+    let mut seen: HashSet<T> = values.iter().cloned().collect();
+    let before = values.len();
+
+    for item in items {
+        if seen.insert(item.clone()) {
+            values.push(item);
+        }
+    }
+
+    values.len() - before
+}
@@ -0,0 +1,70 @@
+/// 🧲 Coalesces neighbouring elements in place whenever a merge closure combines them.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector.
+/// - `F`: A closure of the form `FnMut(&T, &T) -> Option<T>`.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to coalesce.
+/// - `merge`: Called with each pair of neighbours `(left, right)`. Returns `Some(combined)` to
+///   replace both with a single value, or `None` to keep them separate.
+///
+/// # Returns
+/// This function returns no value. It modifies the input vector in place.
+///
+/// # Behavior
+/// - Works left to right. After a successful merge, the combined value is immediately tried
+///   against the next element, so runs of any length collapse into one value.
+/// - Elements are never reordered; sort beforehand if merging depends on order (e.g. ranges).
+/// - Vectors with fewer than two elements are left unchanged.
+///
+/// # Performance
+/// - **O(n)** closure calls and a single pass over the vector.
+/// - Truly in place: a write cursor compacts the vector with swaps, so nothing is allocated,
+///   elements are never cloned, and the capacity of `values` is kept.
+///
+/// # Examples
+///
+/// ### 📐 Merge overlapping ranges
+/// ```
+/// use pencil_box::array::merge_adjacent::merge_adjacent;
+///
+/// let mut ranges = vec![(1, 3), (2, 5), (4, 6), (8, 9)];
+/// merge_adjacent(&mut ranges, |a, b| {
+///     (b.0 <= a.1).then(|| (a.0, a.1.max(b.1)))
+/// });
+/// assert_eq!(ranges, vec![(1, 6), (8, 9)]);
+/// ```
+///
+/// ### 🧵 Join consecutive words of the same case
+/// ```
+/// use pencil_box::array::merge_adjacent::merge_adjacent;
+///
+/// let mut words = vec!["ab".to_string(), "cd".to_string(), "EF".to_string()];
+/// merge_adjacent(&mut words, |a, b| {
+///     let lower = |s: &String| s.chars().all(char::is_lowercase);
+///     (lower(a) == lower(b)).then(|| format!("{a}{b}"))
+/// });
+/// assert_eq!(words, vec!["abcd", "EF"]);
+/// ```
+pub fn merge_adjacent<T, F>(values: &mut Vec<T>, mut merge: F)
+where
+    F: FnMut(&T, &T) -> Option<T>,
+{
+    if values.len() < 2 {
+        return;
+    }
+
+    // `values[..=write]` holds the merged output so far; `values[write]` is the open value.
+    let mut write = 0;
+    for read in 1..values.len() {
+        match merge(&values[write], &values[read]) {
+            Some(combined) => values[write] = combined,
+            None => {
+                write += 1;
+                values.swap(write, read);
+            }
+        }
+    }
+    values.truncate(write + 1);
+}
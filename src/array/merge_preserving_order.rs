@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// 📊 Counts reported by [`merge_preserving_order`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MergeSummary {
+    /// Number of incoming items appended to the end of the vector.
+    pub added: usize,
+    /// Number of existing items removed because they were absent from `incoming`.
+    pub removed: usize,
+}
+
+/// 🔄 Syncs a local list to an incoming one, keeping the existing order and appending new items.
+///
+/// This is the "sync my local list to the server's" operation: a single pass that replaces a
+/// `difference` + `uniq` + `extend` combination.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Eq`], [`Hash`], and [`Clone`].
+///
+/// # Arguments
+/// - `current`: The local list to update in place.
+/// - `incoming`: The authoritative list.
+/// - `remove_missing`: If `true`, items of `current` that do not appear in `incoming` are removed.
+///
+/// # Returns
+/// A [`MergeSummary`] with the number of items added and removed.
+///
+/// # Behavior
+/// - Items already in `current` keep their position; nothing is reordered.
+/// - Incoming items not yet in `current` are appended in their `incoming` order.
+/// - Duplicates within `incoming` are appended at most once.
+/// - Duplicates already in `current` are left alone (or all removed, if missing from `incoming`).
+///
+/// # Performance
+/// - Uses [`HashSet`] (SipHash), safe for untrusted input.
+/// - **O(n + m)**; only appended items are cloned.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::merge_preserving_order::{merge_preserving_order, MergeSummary};
+///
+/// let mut local = vec!["inbox", "drafts", "archive"];
+/// let server = ["archive", "inbox", "spam", "sent"];
+///
+/// let summary = merge_preserving_order(&mut local, &server, true);
+/// assert_eq!(local, vec!["inbox", "archive", "spam", "sent"]);
+/// assert_eq!(summary, MergeSummary { added: 2, removed: 1 });
+/// ```
+pub fn merge_preserving_order<T: Eq + Hash + Clone>(
+    current: &mut Vec<T>,
+    incoming: &[T],
+    remove_missing: bool,
+) -> MergeSummary {
+    let mut summary = MergeSummary::default();
+
+    if remove_missing {
+        let wanted: HashSet<&T> = incoming.iter().collect();
+        let before = current.len();
+        current.retain(|value| wanted.contains(value));
+        summary.removed = before - current.len();
+    }
+
+    let mut seen: HashSet<&T> = current.iter().collect();
+    let to_add: Vec<T> = incoming
+        .iter()
+        .filter(|value| seen.insert(value))
+        .cloned()
+        .collect();
+
+    summary.added = to_add.len();
+    current.extend(to_add);
+    summary
+}
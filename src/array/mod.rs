@@ -1,13 +1,81 @@
+pub mod access;
+pub mod arg_extremes;
+pub mod assign;
+pub mod batch_dedup_against;
+pub mod cartesian_product;
+pub mod change_points;
+pub mod checked_index;
 pub mod chunk;
+pub mod chunk_transpose;
+pub mod clamp_len;
 pub mod compact;
+pub mod connected_components;
+pub mod dedup_paths;
+pub mod delimited;
+pub mod diff_ops;
 pub mod difference;
+pub mod downsample_lttb;
 pub mod drop_end;
 pub mod drop_start;
+pub mod either;
+pub mod extract_n;
 pub mod fill_default;
+pub mod fill_pattern;
 pub mod fill_value;
+pub mod fill_with;
 pub mod find_index;
 pub mod find_indexes;
 pub mod find_last_index;
 pub mod flatten;
+pub mod hash_ring;
+pub mod interleave;
+pub mod interquartile_filter;
 pub mod intersection;
+pub mod intervals;
+pub mod map_in_place;
+pub mod matrix;
+pub mod membership;
+pub mod merge_adjacent;
+pub mod merge_preserving_order;
+pub mod monotonic_check;
+pub mod move_item;
+pub mod nth;
+pub mod order;
+pub mod order_by;
+pub mod permutations;
+pub mod pull;
+pub mod range;
+pub mod range_set;
+pub mod ranges;
+pub mod remove_at_indices;
+pub mod remove_matching;
+pub mod resample;
+#[cfg(feature = "rand")]
+pub mod reservoir_sample;
+pub mod retain_with_index;
+pub mod rotate;
+#[cfg(feature = "rand")]
+pub mod sample_stratified;
+#[cfg(feature = "rand")]
+pub mod sample_weighted;
+#[cfg(feature = "rand")]
+pub mod shuffle;
+#[cfg(feature = "rand")]
+pub mod shuffle_groups_preserving_members;
+pub mod slice;
+pub mod sort_by_frequency;
+pub mod sorted_index;
+pub mod sorted_insert;
+pub mod split_at_many;
+pub mod split_when;
+pub mod stable_hash;
+pub mod take_end;
+pub mod take_start;
+pub mod take_until_total;
+pub mod topo_sort;
+pub mod truncate_middle;
+pub mod try_map;
 pub mod uniq;
+pub mod uniq_by_distance;
+pub mod windowed_rate;
+pub mod without;
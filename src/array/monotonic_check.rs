@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+
+/// 📈 Checks whether a slice is **non-decreasing** (each element is `<=` the next).
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+///
+/// # Returns
+/// `true` if every adjacent pair satisfies `a <= b`, `false` otherwise.
+///
+/// # Behavior
+/// - Empty and single-element slices are increasing.
+/// - Repeated values are allowed; use [`is_strictly_increasing`] to reject them.
+/// - Incomparable pairs (e.g. involving `NaN`) make the result `false`.
+///
+/// # Performance
+/// - ✅ **O(n)** with early exit on the first violation; no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::is_increasing;
+///
+/// assert!(is_increasing(&[1, 2, 2, 5]));
+/// assert!(!is_increasing(&[1, 3, 2]));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn is_increasing<T: PartialOrd>(values: &[T]) -> bool {
+    values.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+/// 🔎 Checks whether a slice is sorted in ascending order.
+///
+/// The companion validator for APIs that require sorted input, suitable for
+/// `debug_assert!(is_sorted(values))`. Same result as [`is_increasing`].
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+///
+/// # Returns
+/// `true` if sorting `values` ascending would leave it unchanged, `false` otherwise.
+///
+/// # Behavior
+/// - Empty and single-element slices are sorted.
+/// - Incomparable pairs (e.g. involving `NaN`) make the result `false`.
+///
+/// # Performance
+/// - ✅ **O(n)** with early exit on the first violation; no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::is_sorted;
+///
+/// let ids = [3, 7, 7, 12];
+/// debug_assert!(is_sorted(&ids));
+/// assert!(!is_sorted(&["b", "a"]));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn is_sorted<T: PartialOrd>(values: &[T]) -> bool {
+    is_increasing(values)
+}
+
+/// 🔎 Checks whether a slice is sorted in ascending order of an extracted key.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`PartialOrd`].
+/// - `F`: A closure of the form `FnMut(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+/// - `key`: Extracts the sort key from an element.
+///
+/// # Returns
+/// `true` if every adjacent pair satisfies `key(a) <= key(b)`, `false` otherwise.
+///
+/// # Behavior
+/// - Empty and single-element slices are sorted, and `key` is not called.
+/// - Incomparable keys (e.g. `NaN`) make the result `false`.
+///
+/// # Performance
+/// - ✅ **O(n)** key extractions with early exit; each key is computed at most once.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::is_sorted_by_key;
+///
+/// let users = [("cy", 19), ("ana", 21), ("bo", 34)];
+/// assert!(is_sorted_by_key(&users, |user| user.1));
+/// assert!(!is_sorted_by_key(&users, |user| user.0));
+/// ```
+///
+/// # Panic Safety
+/// ✅ Panic-free as long as `key` does not panic.
+pub fn is_sorted_by_key<T, K, F>(values: &[T], mut key: F) -> bool
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    let mut iter = values.iter();
+    let Some(first) = iter.next() else {
+        return true;
+    };
+    let mut previous = key(first);
+    for value in iter {
+        let current = key(value);
+        if !matches!(
+            previous.partial_cmp(&current),
+            Some(Ordering::Less | Ordering::Equal)
+        ) {
+            return false;
+        }
+        previous = current;
+    }
+    true
+}
+
+/// 📈 Checks whether a slice is **strictly increasing** (each element is `<` the next).
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+///
+/// # Returns
+/// `true` if every adjacent pair satisfies `a < b`, `false` otherwise.
+///
+/// # Behavior
+/// - Empty and single-element slices are strictly increasing.
+/// - Any repeated neighbour, or an incomparable pair (e.g. `NaN`), makes the result `false`.
+///
+/// # Performance
+/// - ✅ **O(n)** with early exit on the first violation; no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::is_strictly_increasing;
+///
+/// assert!(is_strictly_increasing(&[1_000, 1_005, 1_010]));
+/// assert!(!is_strictly_increasing(&[1_000, 1_000, 1_010]));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn is_strictly_increasing<T: PartialOrd>(values: &[T]) -> bool {
+    values.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+/// 📉 Checks whether a slice is **non-increasing** (each element is `>=` the next).
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+///
+/// # Returns
+/// `true` if every adjacent pair satisfies `a >= b`, `false` otherwise.
+///
+/// # Behavior
+/// - Empty and single-element slices are decreasing.
+/// - Repeated values are allowed; use [`is_strictly_decreasing`] to reject them.
+/// - Incomparable pairs (e.g. involving `NaN`) make the result `false`.
+///
+/// # Performance
+/// - ✅ **O(n)** with early exit on the first violation; no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::is_decreasing;
+///
+/// assert!(is_decreasing(&[9.5, 9.5, 3.0]));
+/// assert!(!is_decreasing(&[9.5, f64::NAN]));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn is_decreasing<T: PartialOrd>(values: &[T]) -> bool {
+    values.windows(2).all(|pair| pair[0] >= pair[1])
+}
+
+/// 📉 Checks whether a slice is **strictly decreasing** (each element is `>` the next).
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+///
+/// # Returns
+/// `true` if every adjacent pair satisfies `a > b`, `false` otherwise.
+///
+/// # Behavior
+/// - Empty and single-element slices are strictly decreasing.
+/// - Any repeated neighbour, or an incomparable pair (e.g. `NaN`), makes the result `false`.
+///
+/// # Performance
+/// - ✅ **O(n)** with early exit on the first violation; no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::is_strictly_decreasing;
+///
+/// assert!(is_strictly_decreasing(&[3, 2, 1]));
+/// assert!(!is_strictly_decreasing(&[3, 3, 1]));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn is_strictly_decreasing<T: PartialOrd>(values: &[T]) -> bool {
+    values.windows(2).all(|pair| pair[0] > pair[1])
+}
+
+/// 🧭 Determines the monotonic direction of a slice, if it has one.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`PartialOrd`].
+///
+/// # Arguments
+/// - `values`: A reference to the slice to check.
+///
+/// # Returns
+/// The ordering shared by every adjacent pair `a.partial_cmp(b)`, ignoring equal neighbours:
+/// - `Some(Ordering::Less)` if the slice is non-decreasing and rises at least once (ascending).
+/// - `Some(Ordering::Greater)` if the slice is non-increasing and falls at least once (descending).
+/// - `Some(Ordering::Equal)` if all elements are equal, or the slice has fewer than two elements.
+/// - `None` if the slice both rises and falls, or contains an incomparable pair (e.g. `NaN`).
+///
+/// # Behavior
+/// - The orderings follow [`slice::is_sorted`] conventions: ascending data compares as `Less`
+///   pair by pair.
+///
+/// # Performance
+/// - ✅ **O(n)** with early exit as soon as the direction is contradicted; no allocations.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::monotonic_check::monotonic_direction;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(monotonic_direction(&[1, 1, 4, 9]), Some(Ordering::Less));
+/// assert_eq!(monotonic_direction(&[9, 4, 4]), Some(Ordering::Greater));
+/// assert_eq!(monotonic_direction(&[2, 2]), Some(Ordering::Equal));
+/// assert_eq!(monotonic_direction(&[1, 3, 2]), None);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn monotonic_direction<T: PartialOrd>(values: &[T]) -> Option<Ordering> {
+    let mut direction = Ordering::Equal;
+    for pair in values.windows(2) {
+        match pair[0].partial_cmp(&pair[1])? {
+            Ordering::Equal => {}
+            ordering if direction == Ordering::Equal => direction = ordering,
+            ordering if ordering != direction => return None,
+            _ => {}
+        }
+    }
+    Some(direction)
+}
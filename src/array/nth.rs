@@ -0,0 +1,48 @@
+/// 🎯 Returns a reference to the element at `index`, where negative indices count from the end.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice.
+///
+/// # Arguments
+/// - `values`: The slice to read from.
+/// - `index`: The position to look up. `0` is the first element, `-1` the last, `-2` the one before it, and so on.
+///
+/// # Returns
+/// - `Some(&T)` if the index resolves to a position inside the slice.
+/// - `None` if the index is out of bounds in either direction.
+///
+/// # Behavior
+/// - Mirrors lodash's `_.nth`.
+/// - Never panics, including for `isize::MIN`.
+/// - Usable in `const` contexts, e.g. to pick an entry from a constant lookup table.
+///
+/// # Performance
+/// - **O(1)**; no allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::nth::nth;
+///
+/// let data = vec!['a', 'b', 'c', 'd'];
+/// assert_eq!(nth(&data, 1), Some(&'b'));
+/// assert_eq!(nth(&data, -1), Some(&'d'));
+/// assert_eq!(nth(&data, -4), Some(&'a'));
+/// assert_eq!(nth(&data, -5), None);
+/// assert_eq!(nth(&data, 4), None);
+/// ```
+pub const fn nth<T>(values: &[T], index: isize) -> Option<&T> {
+    let position = if index < 0 {
+        match values.len().checked_sub(index.unsigned_abs()) {
+            Some(position) => position,
+            None => return None,
+        }
+    } else {
+        index as usize
+    };
+
+    if position < values.len() {
+        Some(&values[position])
+    } else {
+        None
+    }
+}
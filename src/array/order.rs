@@ -0,0 +1,32 @@
+/// ↕️ Sort direction shared by the sorting helpers.
+///
+/// Taken by [`sort_by_frequency`](crate::array::sort_by_frequency::sort_by_frequency),
+/// [`order_by`](crate::array::order_by::order_by), and
+/// [`SortKey`](crate::array::order_by::SortKey), so call sites read as `Order::Descending`
+/// rather than a bare `true`/`false`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::order::Order;
+///
+/// assert_eq!(Order::default(), Order::Ascending);
+/// assert_eq!(Order::Ascending.reverse(), Order::Descending);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// Smallest first.
+    #[default]
+    Ascending,
+    /// Largest first.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite direction.
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+
+pub use crate::array::order::Order;
+
+/// 🗂️ Sorts elements by an extracted key in the given direction, computing each key only once.
+///
+/// Uses a Schwartzian transform (decorate, sort, undecorate) via
+/// [`slice::sort_by_cached_key`], so expensive keys such as lowercased strings or parsed dates
+/// are computed once per element instead of once per comparison.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A closure of the form `FnMut(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: The elements to sort. Accepts a `&mut Vec<T>` or any mutable slice.
+/// - `key`: Extracts the sort key from an element.
+/// - `direction`: [`Order::Ascending`] or [`Order::Descending`].
+///
+/// # Behavior
+/// - The sort is stable in both directions: elements with equal keys keep their relative order.
+///
+/// # Performance
+/// - **O(n log n)** comparisons and exactly `n` key extractions.
+/// - Allocates a buffer of `n` keys; for cheap keys such as integer fields,
+///   [`slice::sort_by_key`] avoids that allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::order_by::{order_by, Order};
+///
+/// let mut names = vec!["bob", "Alice", "carol", "Bea"];
+/// order_by(&mut names, |name| name.to_lowercase(), Order::Ascending);
+/// assert_eq!(names, vec!["Alice", "Bea", "bob", "carol"]);
+///
+/// order_by(&mut names, |name| name.len(), Order::Descending);
+/// assert_eq!(names, vec!["Alice", "carol", "Bea", "bob"]);
+/// ```
+pub fn order_by<T, K, F>(values: &mut [T], mut key: F, direction: Order)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    match direction {
+        Order::Ascending => values.sort_by_cached_key(key),
+        Order::Descending => values.sort_by_cached_key(|value| Reverse(key(value))),
+    }
+}
+
+/// Type-erased comparison of two elements by one extracted key.
+type Comparator<'a, T> = Box<dyn Fn(&T, &T) -> Ordering + 'a>;
+
+/// 🔑 One column of a multi-key sort: a key extractor paired with a direction.
+///
+/// Used by [`order_by_many`]. Each `SortKey` can extract a different key type, so columns such
+/// as a name and an age can be combined freely.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::order_by::{Order, SortKey};
+///
+/// let by_len = SortKey::new(|s: &&str| s.len(), Order::Descending);
+/// assert_eq!(by_len.order(), Order::Descending);
+/// assert_eq!(SortKey::asc(|s: &&str| s.to_string()).order(), Order::Ascending);
+/// ```
+pub struct SortKey<'a, T> {
+    compare: Comparator<'a, T>,
+    order: Order,
+}
+
+impl<'a, T> SortKey<'a, T> {
+    /// Creates a sort key from a key extractor and a direction.
+    pub fn new<K, F>(key: F, order: Order) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'a,
+    {
+        SortKey {
+            compare: Box::new(move |a, b| key(a).cmp(&key(b))),
+            order,
+        }
+    }
+
+    /// Creates an ascending sort key.
+    pub fn asc<K, F>(key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'a,
+    {
+        Self::new(key, Order::Ascending)
+    }
+
+    /// Creates a descending sort key.
+    pub fn desc<K, F>(key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'a,
+    {
+        Self::new(key, Order::Descending)
+    }
+
+    /// Returns the direction of this key.
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        let ordering = (self.compare)(a, b);
+        match self.order {
+            Order::Ascending => ordering,
+            Order::Descending => ordering.reverse(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for SortKey<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortKey")
+            .field("order", &self.order)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 🗂️ Sorts elements by several keys in turn, each ascending or descending.
+///
+/// Mirrors lodash `_.orderBy`: the first key decides, later keys only break ties. This replaces
+/// hand-built tuple keys with [`Reverse`] wrappers for the descending columns.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+///
+/// # Arguments
+/// - `values`: The elements to sort. Accepts a `&mut Vec<T>` or any mutable slice.
+/// - `keys`: The columns to sort by, most significant first. See [`SortKey`].
+///
+/// # Behavior
+/// - The sort is stable: elements equal on every key keep their relative order.
+/// - With no keys, `values` is left unchanged.
+///
+/// # Performance
+/// - **O(n log n)** comparisons. Unlike [`order_by`], keys are extracted on every comparison, so
+///   keep extractors cheap (borrow fields rather than building strings where possible).
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::order_by::{order_by_many, SortKey};
+///
+/// let mut users = vec![("fred", 48), ("barney", 34), ("fred", 40), ("barney", 36)];
+/// order_by_many(
+///     &mut users,
+///     &[SortKey::asc(|u: &(&str, u32)| u.0), SortKey::desc(|u: &(&str, u32)| u.1)],
+/// );
+/// assert_eq!(
+///     users,
+///     vec![("barney", 36), ("barney", 34), ("fred", 48), ("fred", 40)]
+/// );
+/// ```
+pub fn order_by_many<T>(values: &mut [T], keys: &[SortKey<'_, T>]) {
+    if keys.is_empty() {
+        return;
+    }
+    values.sort_by(|a, b| {
+        keys.iter()
+            .map(|key| key.compare(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
@@ -0,0 +1,139 @@
+use std::iter::FusedIterator;
+
+/// 🔀 Lazy iterator over the `k`-length permutations of a slice.
+///
+/// Created by [`permutations_iter`]. Each item is a freshly allocated `Vec<T>`; see that
+/// function for ordering and edge cases.
+#[derive(Debug, Clone)]
+pub struct Permutations<'a, T> {
+    values: &'a [T],
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T: Clone> Iterator for Permutations<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(self.current());
+        }
+
+        // Advance to the next arrangement in lexicographic index order.
+        let n = self.values.len();
+        for slot in (0..self.cycles.len()).rev() {
+            self.cycles[slot] -= 1;
+            if self.cycles[slot] == 0 {
+                self.indices[slot..].rotate_left(1);
+                self.cycles[slot] = n - slot;
+            } else {
+                let swap_with = n - self.cycles[slot];
+                self.indices.swap(slot, swap_with);
+                return Some(self.current());
+            }
+        }
+        self.done = true;
+        None
+    }
+}
+
+impl<T: Clone> FusedIterator for Permutations<'_, T> {}
+
+impl<T: Clone> Permutations<'_, T> {
+    fn current(&self) -> Vec<T> {
+        self.indices[..self.cycles.len()]
+            .iter()
+            .map(|&index| self.values[index].clone())
+            .collect()
+    }
+}
+
+/// 🔀 Returns a lazy iterator over every ordered arrangement of `k` elements from `values`.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`] to build each permutation.
+///
+/// # Arguments
+/// - `values`: The elements to arrange.
+/// - `k`: The length of each permutation. Use `values.len()` for full permutations.
+///
+/// # Returns
+/// A [`Permutations`] iterator yielding `Vec<T>` of length `k`.
+///
+/// # Behavior
+/// - Permutations are produced in lexicographic order of element positions, so a sorted input
+///   yields sorted output.
+/// - Elements are distinguished by position, not value: duplicates in `values` produce repeated
+///   permutations.
+/// - If `k == 0`, a single empty permutation is yielded.
+/// - If `k > values.len()`, nothing is yielded.
+///
+/// # Performance
+/// - **O(k)** work and one allocation per permutation; nothing is precomputed.
+/// - There are `n! / (n - k)!` permutations, which grows very quickly.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::permutations::permutations_iter;
+///
+/// let mut orders = permutations_iter(&["a", "b", "c"], 2);
+/// assert_eq!(orders.next(), Some(vec!["a", "b"]));
+/// assert_eq!(orders.next(), Some(vec!["a", "c"]));
+/// assert_eq!(orders.next(), Some(vec!["b", "a"]));
+/// assert_eq!(orders.count(), 3);
+/// ```
+pub fn permutations_iter<T: Clone>(values: &[T], k: usize) -> Permutations<'_, T> {
+    let n = values.len();
+    Permutations {
+        values,
+        indices: (0..n).collect(),
+        cycles: (0..k.min(n)).map(|slot| n - slot).collect(),
+        started: false,
+        done: k > n,
+    }
+}
+
+/// 🔀 Returns every ordered arrangement of `k` elements from `values`.
+///
+/// Eager counterpart of [`permutations_iter`], with the same ordering and edge cases.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `values`: The elements to arrange.
+/// - `k`: The length of each permutation. Use `values.len()` for full permutations.
+///
+/// # Returns
+/// A `Vec<Vec<T>>` of `n! / (n - k)!` permutations, or an empty vector if `k > values.len()`.
+///
+/// # Performance
+/// - **O(k × n! / (n - k)!)**; prefer [`permutations_iter`] when the result may be large or
+///   only the first few are needed.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::permutations::permutations;
+///
+/// assert_eq!(
+///     permutations(&[1, 2, 3], 3),
+///     vec![
+///         vec![1, 2, 3],
+///         vec![1, 3, 2],
+///         vec![2, 1, 3],
+///         vec![2, 3, 1],
+///         vec![3, 1, 2],
+///         vec![3, 2, 1],
+///     ]
+/// );
+/// assert_eq!(permutations(&[1, 2], 3), Vec::<Vec<i32>>::new());
+/// ```
+pub fn permutations<T: Clone>(values: &[T], k: usize) -> Vec<Vec<T>> {
+    permutations_iter(values, k).collect()
+}
@@ -0,0 +1,82 @@
+use ahash::AHashSet;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// 🧲 Removes every occurrence of the listed values from a vector, in place, using [`HashSet`].
+///
+/// Mirrors lodash `_.pull` / `_.pullAll`: the vector itself is modified instead of a filtered
+/// copy being returned.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Eq`] and [`Hash`]. No [`Clone`] is required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to remove from.
+/// - `to_remove`: The values to remove. Duplicates in this list are harmless.
+///
+/// # Returns
+/// The number of elements removed from `values`.
+///
+/// # Behavior
+/// - Every element equal to any value in `to_remove` is removed, not just the first.
+/// - The relative order of the remaining elements is preserved.
+///
+/// # Performance
+/// - Uses [`HashSet`] (SipHash): **secure and collision-resistant**, suitable for untrusted input.
+/// - **O(n + m)**; the set borrows from `to_remove`, so nothing is cloned.
+/// - For large datasets where security is not a concern, see [`pull_performant`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::pull::pull;
+///
+/// let mut letters = vec!['a', 'b', 'c', 'a', 'b', 'c'];
+/// assert_eq!(pull(&mut letters, &['a', 'c']), 4);
+/// assert_eq!(letters, vec!['b', 'b']);
+/// ```
+pub fn pull<T: Eq + Hash>(values: &mut Vec<T>, to_remove: &[T]) -> usize {
+    if to_remove.is_empty() {
+        return 0;
+    }
+    let set: HashSet<&T> = to_remove.iter().collect();
+    let before = values.len();
+    values.retain(|value| !set.contains(value));
+    before - values.len()
+}
+
+/// 🧲 Removes every occurrence of the listed values from a vector, in place, using [`AHashSet`].
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Eq`] and [`Hash`]. No [`Clone`] is required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to remove from.
+/// - `to_remove`: The values to remove.
+///
+/// # Returns
+/// The number of elements removed from `values`.
+///
+/// # Behavior
+/// - Identical in output to [`pull`], but backed by [`ahash::AHashSet`].
+///
+/// # Performance
+/// - ⚡ Uses [`AHashSet`], a fast, non-cryptographic hash.
+/// - 🚀 Faster than `HashSet` for large data, but **not DoS-resistant** (not safe for untrusted input).
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::pull::pull_performant;
+///
+/// let mut ids: Vec<u32> = (0..10).collect();
+/// assert_eq!(pull_performant(&mut ids, &[1, 3, 5, 7, 9]), 5);
+/// assert_eq!(ids, vec![0, 2, 4, 6, 8]);
+/// ```
+pub fn pull_performant<T: Eq + Hash>(values: &mut Vec<T>, to_remove: &[T]) -> usize {
+    if to_remove.is_empty() {
+        return 0;
+    }
+    let set: AHashSet<&T> = to_remove.iter().collect();
+    let before = values.len();
+    values.retain(|value| !set.contains(value));
+    before - values.len()
+}
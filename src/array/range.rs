@@ -0,0 +1,153 @@
+/// 🔢 Numeric types usable with [`range`] and [`range_step`].
+///
+/// Implemented for all primitive integers up to 64 bits (plus `isize`/`usize`) and for `f32`/`f64`.
+pub trait RangeNumber: Copy + PartialOrd {
+    /// The value `0`.
+    const ZERO: Self;
+    /// The value `1`.
+    const ONE: Self;
+
+    /// Returns `self + step * n` (or `self - step * n` when `backwards`), or `None` if the
+    /// result is not representable.
+    fn offset(self, step: Self, n: usize, backwards: bool) -> Option<Self>;
+}
+
+macro_rules! impl_range_number_int {
+    ($($t:ty),*) => {
+        $(
+            impl RangeNumber for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn offset(self, step: Self, n: usize, backwards: bool) -> Option<Self> {
+                    let delta = (step as i128).checked_mul(n as i128)?;
+                    let value = if backwards {
+                        (self as i128).checked_sub(delta)?
+                    } else {
+                        (self as i128).checked_add(delta)?
+                    };
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_range_number_float {
+    ($($t:ty),*) => {
+        $(
+            impl RangeNumber for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn offset(self, step: Self, n: usize, backwards: bool) -> Option<Self> {
+                    let delta = step * n as $t;
+                    Some(if backwards { self - delta } else { self + delta })
+                }
+            }
+        )*
+    };
+}
+
+impl_range_number_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_range_number_float!(f32, f64);
+
+/// 📐 Creates a `Vec<T>` of numbers from `start` up to, but not including, `end`, in steps of one.
+///
+/// # Type Parameters
+/// - `T`: A [`RangeNumber`]: any primitive integer up to 64 bits, `f32`, or `f64`.
+///
+/// # Arguments
+/// - `start`: The first value.
+/// - `end`: The exclusive bound.
+///
+/// # Returns
+/// A new `Vec<T>` counting from `start` toward `end`.
+///
+/// # Behavior
+/// - Mirrors lodash's `_.range(start, end)`.
+/// - If `end < start`, counts **down** (`range(3, 0)` is `[3, 2, 1]`), also for unsigned types.
+/// - If `start == end`, returns an empty vector.
+/// - Float values are computed as `start ± i`, so no rounding error accumulates.
+///
+/// # Performance
+/// - **O(n)** in the number of values produced.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::range::range;
+///
+/// assert_eq!(range(0, 4), vec![0, 1, 2, 3]);
+/// assert_eq!(range(3u8, 0), vec![3, 2, 1]);
+/// assert_eq!(range(0.5, 3.0), vec![0.5, 1.5, 2.5]);
+/// ```
+pub fn range<T: RangeNumber>(start: T, end: T) -> Vec<T> {
+    let backwards = end < start;
+    collect_range(start, T::ONE, backwards, |value| {
+        if backwards {
+            value > end
+        } else {
+            value < end
+        }
+    })
+}
+
+/// 📐 Creates a `Vec<T>` of numbers from `start` toward `end` (exclusive), in increments of `step`.
+///
+/// # Type Parameters
+/// - `T`: A [`RangeNumber`]: any primitive integer up to 64 bits, `f32`, or `f64`.
+///
+/// # Arguments
+/// - `start`: The first value.
+/// - `end`: The exclusive bound.
+/// - `step`: The increment. Positive steps count up, negative steps count down.
+///
+/// # Returns
+/// A new `Vec<T>` with values `start + i * step` that lie before `end`.
+///
+/// # Behavior
+/// - Mirrors lodash's `_.range(start, end, step)`.
+/// - If `step` points away from `end` (e.g. a positive step with `end < start`), returns an empty vector.
+/// - If `step` is zero or `NaN`, returns an empty vector rather than repeating `start` forever.
+/// - Float values are computed as `start + i * step`, so no rounding error accumulates.
+///
+/// # Performance
+/// - **O(n)** in the number of values produced.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::range::range_step;
+///
+/// assert_eq!(range_step(0, 10, 3), vec![0, 3, 6, 9]);
+/// assert_eq!(range_step(10, 0, -4), vec![10, 6, 2]);
+/// assert_eq!(range_step(0.0, 1.0, 0.25), vec![0.0, 0.25, 0.5, 0.75]);
+/// assert!(range_step(0, 10, 0).is_empty());
+/// ```
+pub fn range_step<T: RangeNumber>(start: T, end: T, step: T) -> Vec<T> {
+    if step > T::ZERO {
+        collect_range(start, step, false, |value| value < end)
+    } else if step < T::ZERO {
+        collect_range(start, step, false, |value| value > end)
+    } else {
+        Vec::new()
+    }
+}
+
+/// Collects `start.offset(step, i, backwards)` for increasing `i` while `in_range` holds.
+fn collect_range<T: RangeNumber, C: Fn(T) -> bool>(
+    start: T,
+    step: T,
+    backwards: bool,
+    in_range: C,
+) -> Vec<T> {
+    let mut values = Vec::new();
+    let mut index = 0;
+    while let Some(value) = start.offset(step, index, backwards) {
+        if !in_range(value) {
+            break;
+        }
+        values.push(value);
+        index += 1;
+    }
+    values
+}
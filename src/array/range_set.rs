@@ -0,0 +1,169 @@
+use crate::array::intervals::merge_overlapping;
+
+/// 🗂️ A set of values stored as sorted, coalesced half-open ranges.
+///
+/// Useful for sparse membership where values arrive in runs: downloaded byte ranges,
+/// acknowledged sequence numbers, seen ID ranges, and so on.
+///
+/// # Type Parameters
+/// - `T`: The bound type. Must implement [`Ord`] and [`Copy`].
+///
+/// # Invariants
+/// - Ranges are half-open `(start, end)` pairs covering `start..end`, with `start < end`.
+/// - Ranges are sorted by start and never overlap or touch; adjacent inserts are coalesced.
+///
+/// With the `serde` feature enabled, a `RangeSet` serializes as a plain list of `(start, end)`
+/// pairs, and deserializing normalizes the list the same way as [`From<Vec<(T, T)>>`](From).
+///
+/// # Performance
+/// - `contains` is **O(log n)** in the number of stored ranges.
+/// - `insert` and `remove` are **O(log n)** to locate the affected ranges plus **O(n)** to shift the rest.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::range_set::RangeSet;
+///
+/// let mut downloaded = RangeSet::new();
+/// downloaded.insert((0, 100));
+/// downloaded.insert((200, 300));
+/// downloaded.insert((100, 150));
+/// assert_eq!(downloaded.iter().copied().collect::<Vec<_>>(), vec![(0, 150), (200, 300)]);
+///
+/// assert!(downloaded.contains(149));
+/// assert!(!downloaded.contains(150));
+///
+/// downloaded.remove((50, 250));
+/// assert_eq!(downloaded.iter().copied().collect::<Vec<_>>(), vec![(0, 50), (250, 300)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<(T, T)>", into = "Vec<(T, T)>")
+)]
+pub struct RangeSet<T: Ord + Copy> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    /// Creates an empty `RangeSet`. Usable in `const` contexts.
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Adds every value in `start..end`, merging with any overlapping or touching ranges.
+    ///
+    /// Empty or inverted ranges (`start >= end`) are ignored.
+    pub fn insert(&mut self, range: (T, T)) {
+        let (mut start, mut end) = range;
+        if start >= end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes every value in `start..end`, splitting ranges that straddle either bound.
+    ///
+    /// Empty or inverted ranges (`start >= end`) are ignored.
+    pub fn remove(&mut self, range: (T, T)) {
+        let (start, end) = range;
+        if start >= end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| e <= start);
+        let last = self.ranges.partition_point(|&(s, _)| s < end);
+        if first >= last {
+            return;
+        }
+
+        let mut remainder = Vec::with_capacity(2);
+        let head = self.ranges[first].0;
+        let tail = self.ranges[last - 1].1;
+        if head < start {
+            remainder.push((head, start));
+        }
+        if end < tail {
+            remainder.push((end, tail));
+        }
+        self.ranges.splice(first..last, remainder);
+    }
+
+    /// Returns `true` if `value` lies inside one of the stored ranges.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(_, e)| e <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Iterates over the stored ranges in ascending order.
+    pub fn iter(&self) -> std::slice::Iter<'_, (T, T)> {
+        self.ranges.iter()
+    }
+
+    /// Returns the stored ranges as a sorted slice.
+    pub fn as_slice(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    /// Returns the number of disjoint ranges (not the number of values covered).
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if the set holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Ord + Copy> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy> From<Vec<(T, T)>> for RangeSet<T> {
+    fn from(ranges: Vec<(T, T)>) -> Self {
+        Self {
+            ranges: merge_overlapping(&ranges),
+        }
+    }
+}
+
+impl<T: Ord + Copy> From<RangeSet<T>> for Vec<(T, T)> {
+    fn from(set: RangeSet<T>) -> Self {
+        set.ranges
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<(T, T)> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Ord + Copy> Extend<(T, T)> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<'a, T: Ord + Copy> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a (T, T);
+    type IntoIter = std::slice::Iter<'a, (T, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
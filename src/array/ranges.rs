@@ -0,0 +1,76 @@
+use std::ops::RangeInclusive;
+
+/// 🗜️ Collapses a list of IDs into the fewest inclusive ranges that cover them.
+///
+/// # Arguments
+/// - `values`: The IDs to collapse, in any order. Duplicates are allowed.
+///
+/// # Returns
+/// A `Vec<RangeInclusive<u64>>` of disjoint, non-adjacent ranges in ascending order.
+///
+/// # Behavior
+/// - Consecutive values (`n`, `n + 1`, …) are grouped into a single `start..=end` range.
+/// - Isolated values become single-element ranges (`n..=n`).
+/// - Input is sorted and deduplicated on a copy first, so order and repeats do not matter.
+/// - `u64::MAX` is handled without overflow.
+///
+/// # Performance
+/// - **O(n log n)** for the sort, then a single **O(n)** pass.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::ranges::collapse_to_ranges;
+///
+/// let ids = [7, 1, 2, 3, 8, 3, 10];
+/// assert_eq!(collapse_to_ranges(&ids), vec![1..=3, 7..=8, 10..=10]);
+/// ```
+pub fn collapse_to_ranges(values: &[u64]) -> Vec<RangeInclusive<u64>> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut ranges = Vec::new();
+    let mut iter = sorted.into_iter();
+    let Some(first) = iter.next() else {
+        return ranges;
+    };
+
+    let (mut start, mut end) = (first, first);
+    for value in iter {
+        if end.checked_add(1) == Some(value) {
+            end = value;
+        } else {
+            ranges.push(start..=end);
+            start = value;
+            end = value;
+        }
+    }
+    ranges.push(start..=end);
+    ranges
+}
+
+/// 💥 Expands inclusive ranges back into the individual IDs they cover.
+///
+/// # Arguments
+/// - `ranges`: The ranges to expand, in any order.
+///
+/// # Returns
+/// A `Vec<u64>` holding every value of every range, in the order the ranges were given.
+///
+/// # Behavior
+/// - The inverse of [`collapse_to_ranges`] for its output.
+/// - Empty ranges (`start > end`) contribute nothing.
+/// - Overlapping ranges are expanded as-is, so shared values appear more than once.
+///
+/// # Performance
+/// - **O(k)** where `k` is the total number of values produced. Beware of very wide ranges.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::ranges::explode_ranges;
+///
+/// assert_eq!(explode_ranges(&[1..=3, 7..=8]), vec![1, 2, 3, 7, 8]);
+/// ```
+pub fn explode_ranges(ranges: &[RangeInclusive<u64>]) -> Vec<u64> {
+    ranges.iter().flat_map(|range| range.clone()).collect()
+}
@@ -0,0 +1,44 @@
+use crate::array::retain_with_index::retain_with_index;
+
+/// 🗑️ Removes the elements at every listed position in one compaction pass.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to remove from.
+/// - `indices`: The positions to remove, referring to `values` **before** any removal. They may
+///   be unsorted and may contain duplicates.
+///
+/// # Returns
+/// The number of elements removed.
+///
+/// # Behavior
+/// - Duplicate indices remove their element once.
+/// - Indices `>= values.len()` are ignored.
+/// - The relative order of the remaining elements is preserved.
+///
+/// # Performance
+/// - ✅ **O(n + k)** for `n` elements and `k` indices, using a temporary `n`-bit mask.
+/// - Calling [`Vec::remove`] once per index would shift the tail each time, **O(n · k)**.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::remove_at_indices::remove_at_indices;
+///
+/// let mut rows = vec!["a", "b", "c", "d", "e"];
+/// assert_eq!(remove_at_indices(&mut rows, &[3, 0, 3, 99]), 2);
+/// assert_eq!(rows, vec!["b", "c", "e"]);
+/// ```
+pub fn remove_at_indices<T>(values: &mut Vec<T>, indices: &[usize]) -> usize {
+    let len = values.len();
+    let mut marked = vec![false; len];
+    for &index in indices {
+        if index < len {
+            marked[index] = true;
+        }
+    }
+
+    retain_with_index(values, |index, _| !marked[index]);
+    len - values.len()
+}
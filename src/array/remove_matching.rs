@@ -0,0 +1,42 @@
+/// 📤 Removes every element matching a predicate and returns the removed elements, in order.
+///
+/// Equivalent to `Vec::extract_if(.., predicate).collect()`, but the removal happens in full when
+/// the function returns, with no iterator that must be driven to completion.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector.
+/// - `P`: A predicate of the form `FnMut(&T) -> bool`.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to remove from.
+/// - `predicate`: Returns `true` for elements that should be removed.
+///
+/// # Returns
+/// A `Vec<T>` with the removed elements, in the order they appeared.
+///
+/// # Behavior
+/// - The predicate is called exactly once per element, front to back.
+/// - The relative order of the remaining elements is preserved.
+/// - For a capped number of removals, see [`extract_n`](crate::array::extract_n::extract_n).
+///
+/// # Performance
+/// - ✅ **O(n)** single pass; elements are moved, never cloned.
+/// - Works in place: kept elements are compacted within `values`, whose buffer and capacity are
+///   kept. Only the returned vector is allocated, and only if something matches.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::remove_matching::remove_matching;
+///
+/// let mut sessions = vec![("ana", true), ("bo", false), ("cy", true), ("di", false)];
+/// let expired = remove_matching(&mut sessions, |(_, active)| !active);
+///
+/// assert_eq!(expired, vec![("bo", false), ("di", false)]);
+/// assert_eq!(sessions, vec![("ana", true), ("cy", true)]);
+/// ```
+pub fn remove_matching<T, P>(values: &mut Vec<T>, mut predicate: P) -> Vec<T>
+where
+    P: FnMut(&T) -> bool,
+{
+    values.extract_if(.., |value| predicate(value)).collect()
+}
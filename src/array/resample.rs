@@ -0,0 +1,71 @@
+/// 🎚️ How [`resample`] computes values between the original samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResampleMode {
+    /// Take the value of the closest original sample (ties round up).
+    Nearest,
+    /// Interpolate linearly between the two surrounding samples.
+    Linear,
+}
+
+/// 📐 Up- or downsamples a series to exactly `target_len` points.
+///
+/// Useful for normalizing series of different lengths before comparing or plotting them.
+///
+/// # Arguments
+/// - `values`: The original, evenly spaced samples.
+/// - `target_len`: The number of points to produce.
+/// - `mode`: [`ResampleMode::Nearest`] or [`ResampleMode::Linear`].
+///
+/// # Returns
+/// A `Vec<f64>` of length `target_len`, or an empty vector if `values` is empty.
+///
+/// # Behavior
+/// - The first and last output points line up with the first and last input samples; the
+///   points in between are evenly spaced.
+/// - A `target_len` of `1` yields the first sample; a single-sample input is repeated.
+/// - If `target_len == values.len()`, the output equals the input.
+/// - `NaN` samples propagate to the output points that use them.
+///
+/// # Performance
+/// - ✅ **O(target_len)**, with a single allocation of the output.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::resample::{resample, ResampleMode};
+///
+/// let series = [0.0, 10.0, 20.0];
+/// assert_eq!(resample(&series, 5, ResampleMode::Linear), vec![0.0, 5.0, 10.0, 15.0, 20.0]);
+/// assert_eq!(resample(&series, 5, ResampleMode::Nearest), vec![0.0, 10.0, 10.0, 20.0, 20.0]);
+/// assert_eq!(resample(&series, 2, ResampleMode::Linear), vec![0.0, 20.0]);
+/// ```
+pub fn resample(values: &[f64], target_len: usize, mode: ResampleMode) -> Vec<f64> {
+    if values.is_empty() || target_len == 0 {
+        return Vec::new();
+    }
+
+    let last = values.len() - 1;
+    let step = if target_len > 1 {
+        last as f64 / (target_len - 1) as f64
+    } else {
+        0.0
+    };
+
+    (0..target_len)
+        .map(|i| {
+            let position = (i as f64 * step).min(last as f64);
+            match mode {
+                ResampleMode::Nearest => values[position.round() as usize],
+                ResampleMode::Linear => {
+                    let lower = position.floor() as usize;
+                    let upper = (lower + 1).min(last);
+                    let fraction = position - lower as f64;
+                    if fraction == 0.0 {
+                        values[lower]
+                    } else {
+                        values[lower] + (values[upper] - values[lower]) * fraction
+                    }
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+use rand::Rng;
+
+/// 🎲 Uniformly samples up to `k` items from an iterator of unknown length, in a single pass.
+///
+/// Requires the `rand` feature.
+///
+/// # Type Parameters
+/// - `T`: The item type. No trait bounds are required; items are moved, never cloned.
+/// - `I`: Any iterator of owned items.
+/// - `R`: Any random number generator implementing [`Rng`].
+///
+/// # Arguments
+/// - `iter`: The stream to sample from. It is consumed completely unless `k` is `0`.
+/// - `k`: The maximum number of items to return.
+/// - `rng`: The random number generator, e.g. `rand::thread_rng()` or a seeded `StdRng`.
+///
+/// # Returns
+/// A `Vec<T>` with `min(k, stream length)` items, in the order they appeared in the stream.
+///
+/// # Behavior
+/// - Every `k`-subset of the stream is equally likely to be chosen.
+/// - If the stream has at most `k` items, all of them are returned.
+/// - If `k` is `0`, returns an empty vector without pulling from `iter`.
+/// - With a seeded generator, results are deterministic.
+///
+/// # Performance
+/// - Memory is **O(k)**; the stream is never buffered.
+/// - Uses skip-ahead reservoir sampling (Algorithm L), so only **O(k log(n / k))** random draws
+///   are expected, and skipped items are passed over with [`Iterator::nth`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::reservoir_sample::reservoir_sample;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let sample = reservoir_sample((1..=1_000_000).filter(|n| n % 7 == 0), 5, &mut rng);
+///
+/// assert_eq!(sample.len(), 5);
+/// assert!(sample.iter().all(|n| n % 7 == 0));
+/// assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+pub fn reservoir_sample<T, I, R>(iter: I, k: usize, rng: &mut R) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    R: Rng + ?Sized,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut iter = iter.into_iter();
+    let mut reservoir: Vec<(usize, T)> = iter.by_ref().take(k).enumerate().collect();
+
+    if reservoir.len() == k {
+        let mut position = k - 1;
+        let mut weight = (unit_open(rng).ln() / k as f64).exp();
+        loop {
+            let skip = (unit_open(rng).ln() / (1.0 - weight).ln()).floor();
+            // Casting saturates, so an enormous skip simply exhausts the iterator.
+            let skip = skip as usize;
+            let Some(item) = iter.nth(skip) else {
+                break;
+            };
+            position = position.saturating_add(skip).saturating_add(1);
+            reservoir[rng.gen_range(0..k)] = (position, item);
+            weight *= (unit_open(rng).ln() / k as f64).exp();
+        }
+    }
+
+    reservoir.sort_unstable_by_key(|(position, _)| *position);
+    reservoir.into_iter().map(|(_, item)| item).collect()
+}
+
+/// A uniform draw from `(0, 1]`, so its logarithm is always finite.
+fn unit_open<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    1.0 - rng.gen::<f64>()
+}
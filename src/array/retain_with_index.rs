@@ -0,0 +1,50 @@
+/// 🧹 Retains only the elements for which `predicate(index, &element)` returns `true`.
+///
+/// Works like [`Vec::retain`], but also passes each element's original index.
+///
+/// # Type Parameters
+/// - `T`: The element type. No trait bounds are required.
+/// - `F`: A function of the form `FnMut(usize, &T) -> bool`.
+///
+/// # Arguments
+/// - `values`: The vector to filter in place.
+/// - `predicate`: Called exactly once per element, in order, with the element's index
+///   **before** any removal.
+///
+/// # Behavior
+/// - The relative order of retained elements is preserved.
+/// - Removed elements are dropped.
+///
+/// # Performance
+/// - ✅ **O(n)**, in place, with no allocation (no `enumerate` + `collect` round trip).
+///
+/// # Examples
+///
+/// ### 🔢 Keep every third element
+/// ```
+/// use pencil_box::array::retain_with_index::retain_with_index;
+///
+/// let mut frames: Vec<u32> = (0..10).collect();
+/// retain_with_index(&mut frames, |index, _| index % 3 == 0);
+/// assert_eq!(frames, vec![0, 3, 6, 9]);
+/// ```
+///
+/// ### 📍 Combine position and value
+/// ```
+/// use pencil_box::array::retain_with_index::retain_with_index;
+///
+/// let mut rows = vec!["header", "a", "", "b"];
+/// retain_with_index(&mut rows, |index, row| index > 0 && !row.is_empty());
+/// assert_eq!(rows, vec!["a", "b"]);
+/// ```
+pub fn retain_with_index<T, F>(values: &mut Vec<T>, mut predicate: F)
+where
+    F: FnMut(usize, &T) -> bool,
+{
+    let mut index = 0;
+    values.retain(|value| {
+        let keep = predicate(index, value);
+        index += 1;
+        keep
+    });
+}
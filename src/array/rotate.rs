@@ -0,0 +1,49 @@
+/// 🔄 Rotates elements in place by a signed offset.
+///
+/// A positive `offset` rotates **left** (the element at `offset` becomes the first), a negative
+/// `offset` rotates **right** (the last `|offset|` elements move to the front).
+///
+/// # Type Parameters
+/// - `T`: The element type. No trait bounds are required.
+///
+/// # Arguments
+/// - `values`: The elements to rotate. Accepts a `&mut Vec<T>` or any mutable slice.
+/// - `offset`: The signed rotation amount. Any value is allowed; it is reduced modulo the length.
+///
+/// # Behavior
+/// - Offsets larger than the length wrap around, so rotating by `len + 1` equals rotating by `1`.
+/// - Empty slices and offsets that are a multiple of the length leave `values` unchanged.
+///
+/// # Performance
+/// - ✅ **O(n)** in place via [`slice::rotate_left`] / [`slice::rotate_right`]; no allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::rotate::rotate;
+///
+/// let mut days = vec!["mon", "tue", "wed", "thu"];
+/// rotate(&mut days, 1);
+/// assert_eq!(days, vec!["tue", "wed", "thu", "mon"]);
+///
+/// rotate(&mut days, -2);
+/// assert_eq!(days, vec!["thu", "mon", "tue", "wed"]);
+///
+/// rotate(&mut days, 9); // same as 1
+/// assert_eq!(days, vec!["mon", "tue", "wed", "thu"]);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs, including `isize::MIN`.
+pub fn rotate<T>(values: &mut [T], offset: isize) {
+    let len = values.len();
+    if len == 0 {
+        return;
+    }
+
+    let steps = offset.unsigned_abs() % len;
+    if offset >= 0 {
+        values.rotate_left(steps);
+    } else {
+        values.rotate_right(steps);
+    }
+}
@@ -0,0 +1,89 @@
+use rand::Rng;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 🎲 Randomly samples up to `per_group_n` elements from each group, in a single pass.
+///
+/// Requires the `rand` feature.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`]; only sampled elements are cloned.
+/// - `K`: The group key type. Must implement [`Eq`] and [`Hash`].
+/// - `F`: A function of the form `Fn(&T) -> K` assigning each element to a group.
+/// - `R`: Any random number generator implementing [`Rng`].
+///
+/// # Arguments
+/// - `values`: The slice to sample from.
+/// - `key_fn`: Returns the group key for an element.
+/// - `per_group_n`: The maximum number of elements to keep from each group.
+/// - `rng`: The random number generator, e.g. `rand::thread_rng()` or a seeded `StdRng`.
+///
+/// # Returns
+/// A `Vec<T>` containing the sampled elements, in the order they appear in `values`.
+///
+/// # Behavior
+/// - Groups with at most `per_group_n` elements are kept in full.
+/// - Larger groups are sampled uniformly without replacement (reservoir sampling), so every
+///   element of a group is equally likely to be chosen.
+/// - If `per_group_n` is `0` or `values` is empty, returns an empty vector without calling `key_fn`.
+/// - With a seeded generator, results are deterministic.
+///
+/// # Performance
+/// - **O(n)** single pass, calling `key_fn` once per element.
+/// - Reservoirs store indices rather than values; memory is **O(groups × per_group_n)**.
+/// - Sorting the chosen indices to restore input order adds **O(k log k)** for `k` sampled elements.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sample_stratified::sample_stratified;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let events = [("eu", 1), ("us", 2), ("eu", 3), ("us", 4), ("eu", 5), ("ap", 6)];
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let sample = sample_stratified(&events, |(region, _)| *region, 2, &mut rng);
+///
+/// assert_eq!(sample.iter().filter(|(region, _)| *region == "eu").count(), 2);
+/// assert_eq!(sample.iter().filter(|(region, _)| *region == "us").count(), 2);
+/// assert!(sample.contains(&("ap", 6)));
+/// ```
+pub fn sample_stratified<T, K, F, R>(
+    values: &[T],
+    key_fn: F,
+    per_group_n: usize,
+    rng: &mut R,
+) -> Vec<T>
+where
+    T: Clone,
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+    R: Rng + ?Sized,
+{
+    if per_group_n == 0 || values.is_empty() {
+        return Vec::new();
+    }
+
+    let mut reservoirs: HashMap<K, (usize, Vec<usize>)> = HashMap::new();
+    for (index, value) in values.iter().enumerate() {
+        let (seen, reservoir) = reservoirs.entry(key_fn(value)).or_default();
+        *seen += 1;
+        if reservoir.len() < per_group_n {
+            reservoir.push(index);
+        } else {
+            let slot = rng.gen_range(0..*seen);
+            if slot < per_group_n {
+                reservoir[slot] = index;
+            }
+        }
+    }
+
+    let mut chosen: Vec<usize> = reservoirs
+        .into_values()
+        .flat_map(|(_, reservoir)| reservoir)
+        .collect();
+    chosen.sort_unstable();
+    chosen
+        .into_iter()
+        .map(|index| values[index].clone())
+        .collect()
+}
@@ -0,0 +1,260 @@
+use rand::Rng;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// 🎲 Samples up to `k` distinct elements, each chosen with probability proportional to its weight.
+///
+/// Requires the `rand` feature. Sampling is without replacement; see
+/// [`sample_weighted_with_replacement`] to allow repeats.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`]; only sampled elements are cloned.
+/// - `R`: Any random number generator implementing [`Rng`].
+///
+/// # Arguments
+/// - `values`: The slice to sample from.
+/// - `weights`: One non-negative weight per element of `values`.
+/// - `k`: The maximum number of elements to return.
+/// - `rng`: The random number generator, e.g. `rand::thread_rng()` or a seeded `StdRng`.
+///
+/// # Returns
+/// `Ok(Vec<T>)` with the sampled elements, in the order they appear in `values`.
+///
+/// # Errors
+/// Returns `Err(&'static str)` if `values` and `weights` differ in length, or if any weight is
+/// negative, `NaN`, or infinite.
+///
+/// # Behavior
+/// - Elements with weight `0` are never chosen.
+/// - If fewer than `k` elements have a positive weight, all of them are returned.
+/// - With a seeded generator, results are deterministic.
+///
+/// # Performance
+/// - Single pass using weighted reservoir sampling with exponential jumps (A-ExpJ), so only
+///   **O(k log(n / k))** random draws are expected instead of one per element.
+/// - Memory is **O(k)**; the reservoir stores indices rather than values.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sample_weighted::sample_weighted;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let endpoints = ["/health", "/search", "/checkout"];
+/// let weights = [0.0, 8.0, 2.0];
+/// let mut rng = StdRng::seed_from_u64(7);
+///
+/// let picked = sample_weighted(&endpoints, &weights, 2, &mut rng).unwrap();
+/// assert_eq!(picked, vec!["/search", "/checkout"]);
+/// ```
+pub fn sample_weighted<T, R>(
+    values: &[T],
+    weights: &[f64],
+    k: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, &'static str>
+where
+    T: Clone,
+    R: Rng + ?Sized,
+{
+    validate_weights(values, weights)?;
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Keys are kept in log space, `ln(u) / w`, so tiny weights do not underflow to zero.
+    let mut reservoir: BinaryHeap<Reverse<Keyed>> = BinaryHeap::with_capacity(k);
+    let mut positive = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight > 0.0);
+
+    for (index, &weight) in positive.by_ref() {
+        let key = unit_open(rng).ln() / weight;
+        reservoir.push(Reverse(Keyed { key, index }));
+        if reservoir.len() == k {
+            break;
+        }
+    }
+
+    if reservoir.len() == k {
+        let mut threshold = min_key(&reservoir);
+        let mut jump = unit_open(rng).ln() / threshold;
+        let mut skipped = 0.0;
+
+        for (index, &weight) in positive {
+            skipped += weight;
+            if skipped < jump {
+                continue;
+            }
+
+            let floor = (threshold * weight).exp();
+            let draw = floor + (1.0 - floor) * unit_open(rng);
+            reservoir.pop();
+            reservoir.push(Reverse(Keyed {
+                key: draw.ln() / weight,
+                index,
+            }));
+
+            threshold = min_key(&reservoir);
+            jump = unit_open(rng).ln() / threshold;
+            skipped = 0.0;
+        }
+    }
+
+    let mut chosen: Vec<usize> = reservoir
+        .into_iter()
+        .map(|Reverse(keyed)| keyed.index)
+        .collect();
+    chosen.sort_unstable();
+    Ok(chosen
+        .into_iter()
+        .map(|index| values[index].clone())
+        .collect())
+}
+
+/// 🎲 Draws `k` elements with replacement, each draw proportional to the element weights.
+///
+/// Requires the `rand` feature.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+/// - `R`: Any random number generator implementing [`Rng`].
+///
+/// # Arguments
+/// - `values`: The slice to sample from.
+/// - `weights`: One non-negative weight per element of `values`.
+/// - `k`: The number of draws.
+/// - `rng`: The random number generator.
+///
+/// # Returns
+/// `Ok(Vec<T>)` with exactly `k` elements, in draw order. The same element may appear repeatedly.
+///
+/// # Errors
+/// Returns `Err(&'static str)` if `values` and `weights` differ in length, if any weight is
+/// negative, `NaN`, or infinite, or if `k > 0` and no weight is positive.
+///
+/// # Performance
+/// - Builds an alias table (Vose's method) in **O(n)**, then each draw is **O(1)**.
+/// - Weights are normalized by the largest one, so very large finite weights (even ones whose
+///   sum overflows `f64`) keep their proportions.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sample_weighted::sample_weighted_with_replacement;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let requests = sample_weighted_with_replacement(&["GET", "POST"], &[9.0, 1.0], 1_000, &mut rng)
+///     .unwrap();
+///
+/// let posts = requests.iter().filter(|method| **method == "POST").count();
+/// assert!((50..150).contains(&posts));
+/// ```
+pub fn sample_weighted_with_replacement<T, R>(
+    values: &[T],
+    weights: &[f64],
+    k: usize,
+    rng: &mut R,
+) -> Result<Vec<T>, &'static str>
+where
+    T: Clone,
+    R: Rng + ?Sized,
+{
+    validate_weights(values, weights)?;
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Dividing by the largest weight first keeps the sum finite even when the weights
+    // themselves add up past `f64::MAX`.
+    let largest = weights.iter().copied().fold(0.0, f64::max);
+    if largest <= 0.0 {
+        return Err("at least one weight must be positive");
+    }
+    let total: f64 = weights.iter().map(|weight| weight / largest).sum();
+
+    let count = weights.len();
+    let mut scaled: Vec<f64> = weights
+        .iter()
+        .map(|weight| weight / largest * count as f64 / total)
+        .collect();
+    let mut probability = vec![1.0; count];
+    let mut alias: Vec<usize> = (0..count).collect();
+    let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+        (0..count).partition(|&index| scaled[index] < 1.0);
+
+    while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+        small.pop();
+        probability[less] = scaled[less];
+        alias[less] = more;
+        scaled[more] += scaled[less] - 1.0;
+        if scaled[more] < 1.0 {
+            large.pop();
+            small.push(more);
+        }
+    }
+
+    Ok((0..k)
+        .map(|_| {
+            let column = rng.gen_range(0..count);
+            let index = if rng.gen::<f64>() < probability[column] {
+                column
+            } else {
+                alias[column]
+            };
+            values[index].clone()
+        })
+        .collect())
+}
+
+fn validate_weights<T>(values: &[T], weights: &[f64]) -> Result<(), &'static str> {
+    if values.len() != weights.len() {
+        return Err("values and weights must have the same length");
+    }
+    if weights
+        .iter()
+        .any(|weight| !weight.is_finite() || *weight < 0.0)
+    {
+        return Err("weights must be finite and non-negative");
+    }
+    Ok(())
+}
+
+/// A uniform draw from `(0, 1]`, so its logarithm is always finite.
+fn unit_open<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    1.0 - rng.gen::<f64>()
+}
+
+fn min_key(reservoir: &BinaryHeap<Reverse<Keyed>>) -> f64 {
+    reservoir.peek().map_or(0.0, |Reverse(keyed)| keyed.key)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Keyed {
+    key: f64,
+    index: usize,
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .total_cmp(&other.key)
+            .then(self.index.cmp(&other.index))
+    }
+}
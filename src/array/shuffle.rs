@@ -0,0 +1,57 @@
+use rand::Rng;
+
+/// 🔀 Shuffles elements in place with the Fisher–Yates algorithm, using the given RNG.
+///
+/// Requires the `rand` feature.
+///
+/// # Type Parameters
+/// - `T`: The element type. No trait bounds are required; elements are swapped, never cloned.
+/// - `R`: Any random number generator implementing [`Rng`].
+///
+/// # Arguments
+/// - `values`: The elements to shuffle. Accepts a `&mut Vec<T>` or any mutable slice.
+/// - `rng`: The random number generator, e.g. a seeded `StdRng` for reproducible tests.
+///
+/// # Behavior
+/// - Every permutation is equally likely, given a uniform RNG.
+/// - Slices with fewer than two elements are left unchanged and the RNG is not used.
+///
+/// # Performance
+/// - **O(n)** swaps and `n - 1` random draws; no allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::shuffle::shuffle;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut deck: Vec<u8> = (1..=10).collect();
+/// shuffle(&mut deck, &mut StdRng::seed_from_u64(3));
+///
+/// let mut sorted = deck.clone();
+/// sorted.sort();
+/// assert_eq!(sorted, (1..=10).collect::<Vec<_>>());
+/// ```
+pub fn shuffle<T, R: Rng + ?Sized>(values: &mut [T], rng: &mut R) {
+    for upper in (1..values.len()).rev() {
+        let other = rng.gen_range(0..=upper);
+        values.swap(upper, other);
+    }
+}
+
+/// 🔀 Shuffles elements in place using the thread-local RNG.
+///
+/// Requires the `rand` feature. A convenience wrapper around [`shuffle`] with
+/// [`rand::thread_rng`]; use [`shuffle`] directly when results must be reproducible.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::shuffle::shuffle_thread_rng;
+///
+/// let mut players = vec!["ana", "bo", "cy"];
+/// shuffle_thread_rng(&mut players);
+/// assert_eq!(players.len(), 3);
+/// ```
+pub fn shuffle_thread_rng<T>(values: &mut [T]) {
+    shuffle(values, &mut rand::thread_rng());
+}
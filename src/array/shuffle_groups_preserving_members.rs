@@ -0,0 +1,77 @@
+use crate::array::shuffle::shuffle;
+use rand::Rng;
+
+/// 🔀 Shuffles the order of groups of adjacent elements while keeping each group intact.
+///
+/// Requires the `rand` feature. Groups are delimited the same way as in
+/// [`split_when`](crate::array::split_when::split_when): a new group starts wherever
+/// `is_boundary(previous, next)` returns `true`.
+///
+/// # Type Parameters
+/// - `T`: The element type. No trait bounds are required; elements are moved, never cloned.
+/// - `F`: A closure of the form `FnMut(&T, &T) -> bool`.
+/// - `R`: Any random number generator implementing [`Rng`].
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to reorder.
+/// - `is_boundary`: Called with each pair of neighbours. Returns `true` to start a new group.
+/// - `rng`: The random number generator, e.g. a seeded `StdRng` for reproducible tests.
+///
+/// # Behavior
+/// - Every ordering of the groups is equally likely, given a uniform RNG.
+/// - Elements within a group keep their relative order and stay contiguous.
+/// - With fewer than two groups, `values` is left unchanged and the RNG is not used.
+///
+/// # Performance
+/// - **O(n)** boundary checks, then every element is moved once into its group and once back.
+/// - `values` keeps its buffer; only the per-group vectors are allocated.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::shuffle_groups_preserving_members::shuffle_groups_preserving_members;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// // Articles tagged by section; shuffle the sections, not the articles.
+/// let mut feed = vec![("news", 1), ("news", 2), ("sport", 3), ("tech", 4), ("tech", 5)];
+/// shuffle_groups_preserving_members(
+///     &mut feed,
+///     |a, b| a.0 != b.0,
+///     &mut StdRng::seed_from_u64(7),
+/// );
+///
+/// let news: Vec<_> = feed.iter().filter(|item| item.0 == "news").map(|item| item.1).collect();
+/// assert_eq!(news, vec![1, 2]);
+/// assert_eq!(feed.len(), 5);
+/// ```
+pub fn shuffle_groups_preserving_members<T, F, R>(
+    values: &mut Vec<T>,
+    mut is_boundary: F,
+    rng: &mut R,
+) where
+    F: FnMut(&T, &T) -> bool,
+    R: Rng + ?Sized,
+{
+    let mut lengths = Vec::new();
+    let mut start = 0;
+    for index in 1..values.len() {
+        if is_boundary(&values[index - 1], &values[index]) {
+            lengths.push(index - start);
+            start = index;
+        }
+    }
+    if lengths.is_empty() {
+        return;
+    }
+    lengths.push(values.len() - start);
+
+    let mut groups: Vec<Vec<T>> = {
+        let mut drained = values.drain(..);
+        lengths
+            .into_iter()
+            .map(|length| drained.by_ref().take(length).collect())
+            .collect()
+    };
+    shuffle(&mut groups, rng);
+    values.extend(groups.into_iter().flatten());
+}
@@ -0,0 +1,61 @@
+/// 🔪 Returns a cloned sub-range of a slice, accepting negative indices counted from the end.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the slice. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `values`: The slice to take elements from.
+/// - `start`: The inclusive start index. Negative values count back from the end (`-1` is the last element).
+/// - `end`: The exclusive end index. Negative values count back from the end.
+///
+/// # Returns
+/// A new `Vec<T>` containing clones of the elements in the resolved range.
+///
+/// # Behavior
+/// - Mirrors Python's `values[start:end]` and lodash's `_.slice`.
+/// - A negative index `i` resolves to `len + i`, saturating at `0`.
+/// - Indices past the end are clamped to `len`, so the function never panics.
+/// - If the resolved `start` is not before the resolved `end`, an empty vector is returned.
+///
+/// # Performance
+/// - Index resolution is **O(1)**; cloning the range is **O(k)** where `k` is its length.
+/// - The result is allocated exactly once via `to_vec`.
+///
+/// # Examples
+///
+/// ### ➖ Negative indices
+/// ```
+/// use pencil_box::array::slice::slice;
+///
+/// let data = vec![1, 2, 3, 4, 5];
+/// assert_eq!(slice(&data, -3, -1), vec![3, 4]);
+/// assert_eq!(slice(&data, 1, -1), vec![2, 3, 4]);
+/// ```
+///
+/// ### 📏 Out-of-range indices are clamped
+/// ```
+/// use pencil_box::array::slice::slice;
+///
+/// let data = vec!["a", "b", "c"];
+/// assert_eq!(slice(&data, -10, 10), vec!["a", "b", "c"]);
+/// assert!(slice(&data, 2, 1).is_empty());
+/// ```
+pub fn slice<T: Clone>(values: &[T], start: isize, end: isize) -> Vec<T> {
+    let start = resolve_index(start, values.len());
+    let end = resolve_index(end, values.len());
+
+    if start >= end {
+        return Vec::new();
+    }
+
+    values[start..end].to_vec()
+}
+
+/// Resolves a possibly negative index against `len`, clamping it into `0..=len`.
+fn resolve_index(index: isize, len: usize) -> usize {
+    if index < 0 {
+        len.saturating_sub(index.unsigned_abs())
+    } else {
+        (index as usize).min(len)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::array::order::Order;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 📶 Sorts a vector in place by how often each value occurs, grouping equal values together.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Eq`] and [`Hash`]. No [`Clone`] is required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to sort.
+/// - `order`: [`Order::Descending`] puts the most common values first; [`Order::Ascending`] puts
+///   the least common first.
+///
+/// # Behavior
+/// - All occurrences of a value end up next to each other.
+/// - Values with the same count are ordered by their first appearance in the input, in both
+///   directions, so the result is deterministic.
+/// - Empty and single-element vectors are left unchanged.
+///
+/// # Performance
+/// - **O(n log n)**: one counting pass with a [`HashMap`], then a stable sort.
+/// - Elements are moved, never cloned.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::order::Order;
+/// use pencil_box::array::sort_by_frequency::sort_by_frequency;
+///
+/// let mut tags = vec!["rust", "go", "rust", "zig", "go", "rust"];
+/// sort_by_frequency(&mut tags, Order::Descending);
+/// assert_eq!(tags, vec!["rust", "rust", "rust", "go", "go", "zig"]);
+///
+/// sort_by_frequency(&mut tags, Order::Ascending);
+/// assert_eq!(tags, vec!["zig", "go", "go", "rust", "rust", "rust"]);
+/// ```
+pub fn sort_by_frequency<T: Eq + Hash>(values: &mut Vec<T>, order: Order) {
+    if values.len() < 2 {
+        return;
+    }
+
+    // For each distinct value: (occurrences, index of first appearance).
+    let mut stats: HashMap<&T, (usize, usize)> = HashMap::with_capacity(values.len());
+    for (index, value) in values.iter().enumerate() {
+        stats.entry(value).or_insert((0, index)).0 += 1;
+    }
+    let keys: Vec<(usize, usize)> = values.iter().map(|value| stats[value]).collect();
+    drop(stats);
+
+    let mut keyed: Vec<((usize, usize), T)> = keys.into_iter().zip(values.drain(..)).collect();
+    match order {
+        Order::Ascending => keyed.sort_by_key(|&((count, first), _)| (count, first)),
+        Order::Descending => keyed.sort_by_key(|&((count, first), _)| (Reverse(count), first)),
+    }
+    values.extend(keyed.into_iter().map(|(_, value)| value));
+}
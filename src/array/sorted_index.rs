@@ -0,0 +1,104 @@
+/// 🎯 Returns the lowest index at which `item` can be inserted to keep `values` sorted.
+///
+/// Mirrors lodash `_.sortedIndex`. Unlike [`slice::binary_search`], the result is always a
+/// usable insertion point, and with duplicates it is always the first one.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Ord`].
+///
+/// # Arguments
+/// - `values`: A slice sorted in ascending order.
+/// - `item`: The value to locate.
+///
+/// # Returns
+/// The index of the first element that is not less than `item`, or `values.len()` if there is
+/// none.
+///
+/// # Behavior
+/// - If `values` is not sorted, the result is unspecified (but the call does not panic).
+///
+/// # Performance
+/// - **O(log n)** comparisons via [`slice::partition_point`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sorted_index::sorted_index;
+///
+/// let mut scores = vec![10, 20, 20, 30];
+/// assert_eq!(sorted_index(&scores, &20), 1);
+/// assert_eq!(sorted_index(&scores, &25), 3);
+///
+/// let at = sorted_index(&scores, &5);
+/// scores.insert(at, 5);
+/// assert_eq!(scores, vec![5, 10, 20, 20, 30]);
+/// ```
+pub fn sorted_index<T: Ord>(values: &[T], item: &T) -> usize {
+    values.partition_point(|value| value < item)
+}
+
+/// 🎯 Returns the highest index at which `item` can be inserted to keep `values` sorted.
+///
+/// Mirrors lodash `_.sortedLastIndex`: with duplicates, the insertion point is after the last
+/// equal element, which keeps insertion stable.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Ord`].
+///
+/// # Arguments
+/// - `values`: A slice sorted in ascending order.
+/// - `item`: The value to locate.
+///
+/// # Returns
+/// The index of the first element that is greater than `item`, or `values.len()` if there is
+/// none.
+///
+/// # Performance
+/// - **O(log n)** comparisons via [`slice::partition_point`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sorted_index::sorted_last_index;
+///
+/// let values = [10, 20, 20, 30];
+/// assert_eq!(sorted_last_index(&values, &20), 3);
+/// assert_eq!(sorted_last_index(&values, &40), 4);
+/// ```
+pub fn sorted_last_index<T: Ord>(values: &[T], item: &T) -> usize {
+    values.partition_point(|value| value <= item)
+}
+
+/// 🎯 Returns the lowest insertion point for `item` in a slice sorted by a key.
+///
+/// Mirrors lodash `_.sortedIndexBy`: `key` is applied to both `item` and the elements.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A closure of the form `FnMut(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: A slice sorted in ascending order of `key`.
+/// - `item`: The value to locate.
+/// - `key`: Extracts the sort key.
+///
+/// # Returns
+/// The index of the first element whose key is not less than `key(item)`, or `values.len()`.
+///
+/// # Performance
+/// - **O(log n)** key extractions, plus one for `item`.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sorted_index::sorted_index_by;
+///
+/// let users = [("ana", 21), ("bo", 34), ("cy", 40)];
+/// assert_eq!(sorted_index_by(&users, &("new", 34), |user| user.1), 1);
+/// ```
+pub fn sorted_index_by<T, K, F>(values: &[T], item: &T, mut key: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let target = key(item);
+    values.partition_point(|value| key(value) < target)
+}
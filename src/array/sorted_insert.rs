@@ -0,0 +1,77 @@
+use crate::array::sorted_index::sorted_last_index;
+
+/// 📥 Inserts an element into a sorted vector at the position that keeps it sorted.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Ord`].
+///
+/// # Arguments
+/// - `values`: A vector sorted in ascending order.
+/// - `item`: The element to insert.
+///
+/// # Returns
+/// The index at which `item` was inserted.
+///
+/// # Behavior
+/// - `item` is placed after any elements equal to it, so equal elements keep insertion order.
+/// - If `values` is not sorted, `item` is still inserted, at an unspecified position.
+///
+/// # Performance
+/// - **O(log n)** comparisons to find the position, then **O(n)** to shift the tail.
+/// - Suits small to medium always-sorted lists; for many insertions at once, push and sort.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sorted_insert::sorted_insert;
+///
+/// let mut deadlines = vec![3, 8, 12];
+/// assert_eq!(sorted_insert(&mut deadlines, 10), 2);
+/// assert_eq!(sorted_insert(&mut deadlines, 1), 0);
+/// assert_eq!(deadlines, vec![1, 3, 8, 10, 12]);
+/// ```
+pub fn sorted_insert<T: Ord>(values: &mut Vec<T>, item: T) -> usize {
+    let index = sorted_last_index(values, &item);
+    values.insert(index, item);
+    index
+}
+
+/// 📥 Inserts an element into a vector sorted by a key, at the position that keeps it sorted.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `K`: The key type. Must implement [`Ord`].
+/// - `F`: A closure of the form `FnMut(&T) -> K`.
+///
+/// # Arguments
+/// - `values`: A vector sorted in ascending order of `key`.
+/// - `item`: The element to insert.
+/// - `key`: Extracts the sort key; applied to `item` and to the existing elements.
+///
+/// # Returns
+/// The index at which `item` was inserted.
+///
+/// # Behavior
+/// - `item` is placed after any elements with an equal key, so ties keep insertion order.
+///
+/// # Performance
+/// - **O(log n)** key extractions, then **O(n)** to shift the tail.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::sorted_insert::sorted_insert_by_key;
+///
+/// let mut tasks = vec![("backup", 1), ("deploy", 5)];
+/// sorted_insert_by_key(&mut tasks, ("report", 3), |task| task.1);
+/// sorted_insert_by_key(&mut tasks, ("lint", 1), |task| task.1);
+/// assert_eq!(tasks, vec![("backup", 1), ("lint", 1), ("report", 3), ("deploy", 5)]);
+/// ```
+pub fn sorted_insert_by_key<T, K, F>(values: &mut Vec<T>, item: T, mut key: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let target = key(&item);
+    let index = values.partition_point(|value| key(value) <= target);
+    values.insert(index, item);
+    index
+}
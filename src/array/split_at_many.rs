@@ -0,0 +1,60 @@
+/// ✂️ Splits a slice at several positions in one call.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+///
+/// # Arguments
+/// - `values`: The slice to split.
+/// - `indices`: The split positions, in non-decreasing order. Each must be `<= values.len()`.
+///
+/// # Returns
+/// - `Ok(Vec<Vec<T>>)` with `indices.len() + 1` parts: part `i` holds the elements from
+///   `indices[i - 1]` (or 0) up to `indices[i]` (or the end).
+/// - `Err(&'static str)` if the indices are invalid.
+///
+/// # Errors
+/// - `"split indices must be in non-decreasing order"` if an index is smaller than the previous one.
+/// - `"split index out of bounds"` if an index is greater than `values.len()`.
+///
+/// # Behavior
+/// - Indices are absolute positions in `values`, not offsets from the previous split.
+/// - Repeated indices, and indices `0` or `values.len()`, produce empty parts.
+/// - No indices returns the whole slice as a single part.
+///
+/// # Performance
+/// - **O(n + k)**; every element is cloned once.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::split_at_many::split_at_many;
+///
+/// let header_body_footer = split_at_many(&[1, 2, 3, 4, 5, 6], &[1, 5]).unwrap();
+/// assert_eq!(header_body_footer, vec![vec![1], vec![2, 3, 4, 5], vec![6]]);
+///
+/// assert!(split_at_many(&[1, 2, 3], &[2, 1]).is_err());
+/// assert!(split_at_many(&[1, 2, 3], &[4]).is_err());
+/// ```
+pub fn split_at_many<T: Clone>(
+    values: &[T],
+    indices: &[usize],
+) -> Result<Vec<Vec<T>>, &'static str> {
+    let mut previous = 0;
+    for &index in indices {
+        if index < previous {
+            return Err("split indices must be in non-decreasing order");
+        }
+        if index > values.len() {
+            return Err("split index out of bounds");
+        }
+        previous = index;
+    }
+
+    let mut parts = Vec::with_capacity(indices.len() + 1);
+    let mut start = 0;
+    for &index in indices {
+        parts.push(values[start..index].to_vec());
+        start = index;
+    }
+    parts.push(values[start..].to_vec());
+    Ok(parts)
+}
@@ -0,0 +1,49 @@
+/// ✂️ Splits a slice into groups, starting a new group wherever a predicate over neighbours holds.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`].
+/// - `F`: A closure of the form `FnMut(&T, &T) -> bool`.
+///
+/// # Arguments
+/// - `values`: The slice to split.
+/// - `is_boundary`: Called with each pair of neighbours `(previous, next)`. Returns `true` to
+///   start a new group at `next`.
+///
+/// # Returns
+/// A `Vec<Vec<T>>` of consecutive, non-empty groups that together hold every element in order.
+///
+/// # Behavior
+/// - The predicate is only ever called on adjacent elements, so it suits sessionization
+///   (e.g. "gap greater than 30 seconds") and run detection (e.g. "value changed").
+/// - An empty slice produces no groups; a single element produces one group.
+///
+/// # Performance
+/// - **O(n)** closure calls and one clone per element.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::split_when::split_when;
+///
+/// // Event timestamps in seconds; a gap of more than 30s starts a new session.
+/// let events = [0, 10, 25, 90, 100, 200];
+/// let sessions = split_when(&events, |prev, next| next - prev > 30);
+/// assert_eq!(sessions, vec![vec![0, 10, 25], vec![90, 100], vec![200]]);
+/// ```
+pub fn split_when<T, F>(values: &[T], mut is_boundary: F) -> Vec<Vec<T>>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for index in 1..values.len() {
+        if is_boundary(&values[index - 1], &values[index]) {
+            groups.push(values[start..index].to_vec());
+            start = index;
+        }
+    }
+    if start < values.len() {
+        groups.push(values[start..].to_vec());
+    }
+    groups
+}
@@ -0,0 +1,107 @@
+use std::hash::{Hash, Hasher};
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 🧬 A fixed 64-bit hasher whose output does not change between Rust releases or platforms.
+///
+/// [`DefaultHasher`](std::collections::hash_map::DefaultHasher) is only deterministic within one
+/// Rust release, so anything persisted by hash (shard numbers, ring positions) may move after a
+/// toolchain upgrade. `StableHasher` runs FNV-1a over the written bytes and finishes with the
+/// MurmurHash3 `fmix64` step, so nearby inputs still spread evenly.
+///
+/// Used by [`HashRing`](crate::array::hash_ring::HashRing) and
+/// [`assign_by_hash`](crate::array::assign::assign_by_hash).
+///
+/// # Behavior
+/// - Integers are written little-endian at a fixed width; `usize` and `isize` are widened to 64
+///   bits, so 32-bit and 64-bit targets agree.
+/// - The result depends only on the bytes a value's [`Hash`] impl writes.
+/// - Not resistant to hash flooding; do not use it for maps keyed by untrusted input.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::stable_hash::{stable_hash, StableHasher};
+/// use std::hash::{Hash, Hasher};
+///
+/// let mut hasher = StableHasher::new();
+/// "tenant-a".hash(&mut hasher);
+/// assert_eq!(hasher.finish(), stable_hash("tenant-a"));
+/// assert_eq!(stable_hash(&7usize), stable_hash(&7u64));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StableHasher {
+    state: u64,
+}
+
+impl StableHasher {
+    /// Creates a hasher in its fixed initial state.
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET }
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        let mut hash = self.state;
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        hash ^ (hash >> 33)
+    }
+}
+
+/// 🧬 Hashes a single value with [`StableHasher`].
+///
+/// # Arguments
+/// - `value`: The value to hash. Unsized values such as `str` are accepted.
+///
+/// # Returns
+/// A `u64` that is the same for equal inputs across processes, platforms, and Rust releases.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::stable_hash::stable_hash;
+///
+/// assert_eq!(stable_hash("user:42"), stable_hash(&String::from("user:42")));
+/// assert_ne!(stable_hash("user:42"), stable_hash("user:43"));
+/// ```
+pub fn stable_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = StableHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,58 @@
+/// Keeps only the last `no_of_elements_to_take` elements of a vector, dropping the rest in place.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to shorten.
+/// - `no_of_elements_to_take`: The number of elements to keep from the end of the vector.
+///
+/// # Returns
+/// This function returns no value. It modifies the input vector in place.
+///
+/// # Behavior
+/// - Keeps the last `no_of_elements_to_take` elements and removes everything before them.
+/// - If `no_of_elements_to_take` is `0`, the vector is cleared.
+/// - If `no_of_elements_to_take` is greater than or equal to the vector’s length, the vector is left unchanged.
+/// - The counterpart of [`drop_start`](crate::array::drop_start::drop_start), expressed as a count to keep.
+///
+/// # Performance
+/// - Time complexity is **O(k)** where `k` is the number of elements kept, since they must be shifted left.
+/// - Performs in-place mutation using `drain` without reallocating or cloning.
+/// - For frequent removal from the start, consider using [`VecDeque`](std::collections::VecDeque) for O(1) behavior.
+///
+/// # Examples
+///
+/// ### ✂️ Keep the last few elements
+/// ```
+/// use pencil_box::array::take_end::take_end;
+///
+/// let mut data = vec![10, 20, 30, 40];
+/// take_end(&mut data, 2);
+/// assert_eq!(data, vec![30, 40]);
+/// ```
+///
+/// ### 💤 Take more than the vector contains (no change)
+/// ```
+/// use pencil_box::array::take_end::take_end;
+///
+/// let mut data = vec![1, 2];
+/// take_end(&mut data, 10);
+/// assert_eq!(data, vec![1, 2]);
+/// ```
+///
+/// ### 🛑 Take zero elements (clears the vector)
+/// ```
+/// use pencil_box::array::take_end::take_end;
+///
+/// let mut data = vec!["a".to_string(), "b".to_string()];
+/// take_end(&mut data, 0);
+/// assert!(data.is_empty());
+/// ```
+pub fn take_end<T>(values: &mut Vec<T>, no_of_elements_to_take: usize) {
+    let no_of_elements_to_drop = values.len().saturating_sub(no_of_elements_to_take);
+    if no_of_elements_to_drop == 0 {
+        return;
+    }
+    values.drain(..no_of_elements_to_drop);
+}
@@ -0,0 +1,54 @@
+/// Keeps only the first `no_of_elements_to_take` elements of a vector, dropping the rest in place.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to shorten.
+/// - `no_of_elements_to_take`: The number of elements to keep from the start of the vector.
+///
+/// # Returns
+/// This function returns no value. It modifies the input vector in place.
+///
+/// # Behavior
+/// - Keeps the first `no_of_elements_to_take` elements and removes everything after them.
+/// - If `no_of_elements_to_take` is `0`, the vector is cleared.
+/// - If `no_of_elements_to_take` is greater than or equal to the vector’s length, the vector is left unchanged.
+/// - The counterpart of [`drop_end`](crate::array::drop_end::drop_end), expressed as a count to keep.
+///
+/// # Performance
+/// - ✅ In-place operation with no element shifting.
+/// - 🚫 No reallocation or element cloning occurs.
+/// - ⚡ Uses `.truncate()` internally; only the removed elements are dropped.
+///
+/// # Examples
+///
+/// ### ✂️ Keep the first few elements
+/// ```
+/// use pencil_box::array::take_start::take_start;
+///
+/// let mut data = vec![10, 20, 30, 40];
+/// take_start(&mut data, 2);
+/// assert_eq!(data, vec![10, 20]);
+/// ```
+///
+/// ### 💤 Take more than the vector contains (no change)
+/// ```
+/// use pencil_box::array::take_start::take_start;
+///
+/// let mut data = vec![1, 2];
+/// take_start(&mut data, 10);
+/// assert_eq!(data, vec![1, 2]);
+/// ```
+///
+/// ### 🛑 Take zero elements (clears the vector)
+/// ```
+/// use pencil_box::array::take_start::take_start;
+///
+/// let mut data = vec!["a".to_string(), "b".to_string()];
+/// take_start(&mut data, 0);
+/// assert!(data.is_empty());
+/// ```
+pub fn take_start<T>(values: &mut Vec<T>, no_of_elements_to_take: usize) {
+    values.truncate(no_of_elements_to_take);
+}
@@ -0,0 +1,87 @@
+/// 📦 Returns the longest prefix whose summed weight stays within a budget.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: A closure of the form `FnMut(&T) -> u64` giving each element's weight.
+///
+/// # Arguments
+/// - `values`: The slice to take from.
+/// - `weight`: Returns the weight (e.g. size in bytes) of an element.
+/// - `budget`: The maximum total weight of the prefix.
+///
+/// # Returns
+/// A sub-slice `&values[..n]` where `n` is as large as possible with a total weight `<= budget`.
+///
+/// # Behavior
+/// - Stops at the first element that would exceed the budget; later, lighter elements are not
+///   considered, so the result is always a prefix.
+/// - Zero-weight elements are always taken until a heavier element no longer fits.
+/// - A total that would overflow `u64` counts as over budget, so sums never wrap around.
+///
+/// # Performance
+/// - **O(n)** at most, stopping as soon as the budget is exceeded. No allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::take_until_total::take_until_total;
+///
+/// let messages = ["hello", "world", "this one is long", "hi"];
+/// let page = take_until_total(&messages, |m| m.len() as u64, 12);
+/// assert_eq!(page, &["hello", "world"]);
+/// ```
+pub fn take_until_total<T, F>(values: &[T], weight: F, budget: u64) -> &[T]
+where
+    F: FnMut(&T) -> u64,
+{
+    split_at_total(values, weight, budget).0
+}
+
+/// 📦 Splits a slice after the longest prefix whose summed weight stays within a budget.
+///
+/// # Type Parameters
+/// - `T`: The element type.
+/// - `F`: A closure of the form `FnMut(&T) -> u64` giving each element's weight.
+///
+/// # Arguments
+/// - `values`: The slice to split.
+/// - `weight`: Returns the weight of an element.
+/// - `budget`: The maximum total weight of the first part.
+///
+/// # Returns
+/// A tuple `(taken, rest)` where `taken` is what [`take_until_total`] returns and `rest` is
+/// everything after it.
+///
+/// # Behavior
+/// - Same prefix rules as [`take_until_total`].
+/// - Calling it repeatedly on `rest` fills consecutive pages; an element heavier than the budget
+///   on its own yields an empty `taken`, so check for that to avoid looping forever.
+///
+/// # Performance
+/// - **O(n)** at most. No allocation.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::take_until_total::split_at_total;
+///
+/// let sizes = [400u64, 300, 500, 200, 100];
+/// let (packet, rest) = split_at_total(&sizes, |&size| size, 1000);
+/// assert_eq!(packet, &[400, 300]);
+/// assert_eq!(rest, &[500, 200, 100]);
+/// ```
+pub fn split_at_total<T, F>(values: &[T], mut weight: F, budget: u64) -> (&[T], &[T])
+where
+    F: FnMut(&T) -> u64,
+{
+    let mut total = 0u64;
+    let end = values
+        .iter()
+        .position(|value| match total.checked_add(weight(value)) {
+            Some(next) if next <= budget => {
+                total = next;
+                false
+            }
+            _ => true,
+        })
+        .unwrap_or(values.len());
+    values.split_at(end)
+}
@@ -0,0 +1,146 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// ❗ Error returned by [`topo_sort`] when the dependencies contain a cycle.
+///
+/// # Fields
+/// - `cycle`: The nodes of one cycle, in dependency order: each node depends on the next,
+///   and the last depends on the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<T> {
+    pub cycle: Vec<T>,
+}
+
+impl<T: Debug> fmt::Display for CycleError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dependency cycle: ")?;
+        for node in &self.cycle {
+            write!(f, "{node:?} -> ")?;
+        }
+        match self.cycle.first() {
+            Some(first) => write!(f, "{first:?}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Debug> Error for CycleError<T> {}
+
+/// 🧭 Orders nodes so that every node comes after its dependencies, keeping input order
+/// wherever the dependencies allow.
+///
+/// # Type Parameters
+/// - `T`: The node type. Must implement [`Clone`], [`Eq`], and [`Hash`].
+/// - `F`: A function of the form `FnMut(&T) -> I` returning a node's dependencies.
+/// - `I`: Any iterable of nodes.
+///
+/// # Arguments
+/// - `nodes`: The nodes to sort.
+/// - `dependencies`: Returns the nodes that must come **before** the given node. Called once
+///   per node.
+///
+/// # Returns
+/// - `Ok(Vec<T>)` with every node once, dependencies first.
+/// - `Err(CycleError)` naming one cycle if no such order exists.
+///
+/// # Behavior
+/// - **Stable**: whenever several nodes are ready, the one listed first in `nodes` is placed
+///   first, so already-valid input comes back unchanged.
+/// - Dependencies that are not in `nodes` are ignored, as if already satisfied.
+/// - Duplicate nodes are kept once, at their first position; self-dependencies are cycles.
+///
+/// # Performance
+/// - **O((V + E) log V)** for `V` nodes and `E` dependency edges.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::topo_sort::topo_sort;
+///
+/// let deps = |task: &&str| match *task {
+///     "deploy" => vec!["test", "build"],
+///     "test" => vec!["build"],
+///     _ => vec![],
+/// };
+///
+/// let order = topo_sort(&["deploy", "lint", "test", "build"], deps).unwrap();
+/// assert_eq!(order, vec!["lint", "build", "test", "deploy"]);
+///
+/// let cyclic = topo_sort(&["a", "b"], |n: &&str| if *n == "a" { vec!["b"] } else { vec!["a"] });
+/// assert_eq!(cyclic.unwrap_err().cycle, vec!["a", "b"]);
+/// ```
+pub fn topo_sort<T, F, I>(nodes: &[T], mut dependencies: F) -> Result<Vec<T>, CycleError<T>>
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut positions: HashMap<&T, usize> = HashMap::with_capacity(nodes.len());
+    let mut unique: Vec<&T> = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        positions.entry(node).or_insert_with(|| {
+            unique.push(node);
+            unique.len() - 1
+        });
+    }
+
+    // `requires[i]` lists the dependencies of node `i`; `unlocks[d]` the nodes waiting on `d`.
+    let mut requires: Vec<Vec<usize>> = vec![Vec::new(); unique.len()];
+    let mut unlocks: Vec<Vec<usize>> = vec![Vec::new(); unique.len()];
+    for (index, node) in unique.iter().enumerate() {
+        for dependency in dependencies(node) {
+            if let Some(&dependency) = positions.get(&dependency) {
+                requires[index].push(dependency);
+                unlocks[dependency].push(index);
+            }
+        }
+    }
+
+    let mut pending: Vec<usize> = requires.iter().map(Vec::len).collect();
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..unique.len())
+        .filter(|&index| pending[index] == 0)
+        .map(Reverse)
+        .collect();
+
+    let mut sorted = Vec::with_capacity(unique.len());
+    while let Some(Reverse(index)) = ready.pop() {
+        sorted.push(unique[index].clone());
+        for &next in &unlocks[index] {
+            pending[next] -= 1;
+            if pending[next] == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+    }
+
+    if sorted.len() == unique.len() {
+        return Ok(sorted);
+    }
+
+    // Every unsorted node still waits on an unsorted dependency, so following those edges
+    // from any unsorted node must eventually revisit a node.
+    let mut visited_at: HashMap<usize, usize> = HashMap::new();
+    let mut path = Vec::new();
+    let mut current = (0..unique.len())
+        .find(|&index| pending[index] > 0)
+        .unwrap_or_default();
+    while !visited_at.contains_key(&current) {
+        visited_at.insert(current, path.len());
+        path.push(current);
+        current = requires[current]
+            .iter()
+            .copied()
+            .find(|&dependency| pending[dependency] > 0)
+            .unwrap_or(current);
+    }
+
+    Err(CycleError {
+        cycle: path[visited_at[&current]..]
+            .iter()
+            .map(|&index| unique[index].clone())
+            .collect(),
+    })
+}
@@ -0,0 +1,96 @@
+/// ✂️ Keeps the first `keep_start` and last `keep_end` elements, dropping everything between.
+///
+/// Complements [`drop_start`](crate::array::drop_start::drop_start) and
+/// [`drop_end`](crate::array::drop_end::drop_end) when both ends matter, e.g. trimming long
+/// logs to their first and last lines.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to trim in place.
+/// - `keep_start`: The number of leading elements to keep.
+/// - `keep_end`: The number of trailing elements to keep.
+///
+/// # Returns
+/// The number of elements dropped from the middle.
+///
+/// # Behavior
+/// - If the vector has at most `keep_start + keep_end` elements, it is left unchanged and `0`
+///   is returned.
+/// - The kept elements stay in their original order.
+///
+/// # Performance
+/// - ✅ In-place, **O(n)**; only the tail is shifted. No reallocation or cloning.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::truncate_middle::truncate_middle;
+///
+/// let mut lines: Vec<u32> = (1..=10).collect();
+/// assert_eq!(truncate_middle(&mut lines, 2, 3), 5);
+/// assert_eq!(lines, vec![1, 2, 8, 9, 10]);
+/// ```
+pub fn truncate_middle<T>(values: &mut Vec<T>, keep_start: usize, keep_end: usize) -> usize {
+    match middle_range(values.len(), keep_start, keep_end) {
+        Some((start, end)) => {
+            values.drain(start..end);
+            end - start
+        }
+        None => 0,
+    }
+}
+
+/// ✂️ Like [`truncate_middle`], but puts a marker element where the middle was dropped.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+/// - `F`: A function of the form `FnOnce(usize) -> T` building the marker.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to trim in place.
+/// - `keep_start`: The number of leading elements to keep.
+/// - `keep_end`: The number of trailing elements to keep.
+/// - `marker`: Called with the number of dropped elements to build the marker.
+///
+/// # Returns
+/// The number of elements dropped from the middle, not counting the marker.
+///
+/// # Behavior
+/// - If nothing needs dropping, the vector is left unchanged and `marker` is not called.
+/// - Otherwise the result has exactly `keep_start + 1 + keep_end` elements.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::truncate_middle::truncate_middle_with_marker;
+///
+/// let mut log: Vec<String> = (1..=6).map(|n| format!("line {n}")).collect();
+/// truncate_middle_with_marker(&mut log, 1, 1, |dropped| format!("… {dropped} lines omitted"));
+/// assert_eq!(log, vec!["line 1", "… 4 lines omitted", "line 6"]);
+/// ```
+pub fn truncate_middle_with_marker<T, F>(
+    values: &mut Vec<T>,
+    keep_start: usize,
+    keep_end: usize,
+    marker: F,
+) -> usize
+where
+    F: FnOnce(usize) -> T,
+{
+    match middle_range(values.len(), keep_start, keep_end) {
+        Some((start, end)) => {
+            let dropped = end - start;
+            values.splice(start..end, std::iter::once(marker(dropped)));
+            dropped
+        }
+        None => 0,
+    }
+}
+
+/// Returns the `start..end` range to drop, or `None` if the vector is short enough already.
+fn middle_range(len: usize, keep_start: usize, keep_end: usize) -> Option<(usize, usize)> {
+    if len <= keep_start.saturating_add(keep_end) {
+        return None;
+    }
+    Some((keep_start, len - keep_end))
+}
@@ -0,0 +1,92 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Debug, Display};
+
+/// ❗ Error returned by [`try_map`] when the mapping function fails for an element.
+///
+/// # Type Parameters
+/// - `T`: The input element type.
+/// - `E`: The error type returned by the mapping function.
+///
+/// # Fields
+/// - `index`: Zero-based position of the failing element.
+/// - `input`: A clone of the failing element.
+/// - `source`: The error returned by the mapping function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapError<T, E> {
+    pub index: usize,
+    pub input: T,
+    pub source: E,
+}
+
+impl<T: Debug, E: Display> Display for MapError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to map element {} ({:?}): {}",
+            self.index, self.input, self.source
+        )
+    }
+}
+
+impl<T: Debug, E: Error + 'static> Error for MapError<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// 🔁 Maps every element with a fallible function, reporting which element failed.
+///
+/// # Type Parameters
+/// - `T`: The input element type. Must implement [`Clone`]; only the failing element is cloned.
+/// - `R`: The output element type.
+/// - `E`: The error type returned by `mapper`.
+/// - `F`: A function of the form `FnMut(&T) -> Result<R, E>`.
+///
+/// # Arguments
+/// - `values`: The elements to convert.
+/// - `mapper`: The conversion, called once per element in order.
+///
+/// # Returns
+/// - `Ok(Vec<R>)` with every converted element, in order.
+/// - `Err(MapError)` for the **first** failing element, carrying its index, a clone of it, and
+///   the error returned by `mapper`.
+///
+/// # Behavior
+/// - Stops at the first error; later elements are not passed to `mapper`.
+/// - An empty slice yields `Ok(vec![])`.
+///
+/// # Performance
+/// - ✅ Single pass, **O(n)**; the output is allocated once with the input length.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::try_map::try_map;
+///
+/// let ports = ["80", "443", "http"];
+/// let error = try_map(&ports, |port| port.parse::<u16>()).unwrap_err();
+///
+/// assert_eq!(error.index, 2);
+/// assert_eq!(error.input, "http");
+/// assert!(error.to_string().starts_with("failed to map element 2 (\"http\")"));
+/// ```
+pub fn try_map<T, R, E, F>(values: &[T], mut mapper: F) -> Result<Vec<R>, MapError<T, E>>
+where
+    T: Clone,
+    F: FnMut(&T) -> Result<R, E>,
+{
+    let mut mapped = Vec::with_capacity(values.len());
+    for (index, value) in values.iter().enumerate() {
+        match mapper(value) {
+            Ok(result) => mapped.push(result),
+            Err(source) => {
+                return Err(MapError {
+                    index,
+                    input: value.clone(),
+                    source,
+                })
+            }
+        }
+    }
+    Ok(mapped)
+}
@@ -2,7 +2,7 @@ use ahash::AHashSet;
 use std::collections::HashSet;
 use std::hash::Hash;
 
-/// 🔁 Removes duplicate elements from a mutable vector using the standard [`HashSet`] (SipHash).
+/// 🔁 Removes duplicate elements from a mutable vector, keeping the first occurrence of each.
 ///
 /// # Type Parameters
 /// - `T`: The type of elements in the vector. Must implement [`Eq`], [`Hash`], and [`Clone`].
@@ -22,8 +22,10 @@ use std::hash::Hash;
 /// - Works with primitives, strings, enums, and any type that implements `Eq`, `Hash`, and `Clone`.
 ///
 /// # Performance
-/// - Uses [`std::collections::HashSet`] (SipHash), secure and collision-resistant.
-/// - Slower than [`AHashSet`] on large datasets, but safer for untrusted input.
+/// - By default, uses [`std::collections::HashSet`] (SipHash), secure and collision-resistant,
+///   exactly like [`uniq_secure`].
+/// - With the `fast-hash` feature, uses [`AHashSet`] instead, exactly like [`uniq_performant`].
+///   Call [`uniq_secure`] where untrusted input must stay DoS-resistant regardless of features.
 ///
 /// # Examples
 ///
@@ -53,6 +55,31 @@ use std::hash::Hash;
 /// assert_eq!(fruits.len(), 2);
 /// ```
 pub fn uniq<T: Eq + Hash + Clone>(values: &mut Vec<T>) {
+    if cfg!(feature = "fast-hash") {
+        uniq_performant(values)
+    } else {
+        uniq_secure(values)
+    }
+}
+
+/// 🔒 Removes duplicate elements using [`HashSet`] (SipHash), regardless of the `fast-hash` feature.
+///
+/// # Behavior
+/// - Identical in output to [`uniq`]: the first occurrence of each item is kept, in order.
+///
+/// # Performance
+/// - Uses [`std::collections::HashSet`] (SipHash), secure and collision-resistant.
+/// - Slower than [`AHashSet`] on large datasets, but safe for untrusted input.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::uniq::uniq_secure;
+///
+/// let mut user_ids = vec!["u1", "u2", "u1"];
+/// uniq_secure(&mut user_ids);
+/// assert_eq!(user_ids, vec!["u1", "u2"]);
+/// ```
+pub fn uniq_secure<T: Eq + Hash + Clone>(values: &mut Vec<T>) {
     let mut seen = HashSet::with_capacity(values.len());
     values.retain(|item| seen.insert(item.clone()));
 }
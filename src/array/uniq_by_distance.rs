@@ -0,0 +1,58 @@
+/// 🧲 Removes elements that lie within `threshold` of an element already kept (greedy, in place).
+///
+/// Approximate deduplication for values that are "close enough" rather than equal, such as
+/// near-identical strings under an edit distance, or embeddings under cosine distance.
+///
+/// # Type Parameters
+/// - `T`: The element type contained in the vector. No specific traits are required.
+/// - `F`: A distance function of the form `FnMut(&T, &T) -> f64`.
+///
+/// # Arguments
+/// - `values`: A mutable reference to the vector to deduplicate.
+/// - `distance`: Called as `distance(kept, candidate)`; smaller means more similar.
+/// - `threshold`: Candidates at a distance `<= threshold` from any kept element are removed.
+///
+/// # Returns
+/// The number of elements removed.
+///
+/// # Behavior
+/// - Elements are visited front to back; the first element of every cluster is the one kept.
+/// - Because the pass is greedy, the result depends on input order, and two kept elements
+///   are always more than `threshold` apart.
+/// - A `NaN` distance never counts as a match.
+/// - The relative order of the kept elements is preserved.
+///
+/// # Performance
+/// - **O(n · k)** distance calls for `n` elements and `k` kept elements.
+/// - ✅ In place: no allocation and no cloning.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::uniq_by_distance::uniq_by_distance;
+///
+/// let mut readings = vec![10.0, 10.2, 15.0, 9.9, 15.3, 20.0];
+/// let removed = uniq_by_distance(&mut readings, |a: &f64, b: &f64| (a - b).abs(), 0.5);
+///
+/// assert_eq!(removed, 3);
+/// assert_eq!(readings, vec![10.0, 15.0, 20.0]);
+/// ```
+pub fn uniq_by_distance<T, F>(values: &mut Vec<T>, mut distance: F, threshold: f64) -> usize
+where
+    F: FnMut(&T, &T) -> f64,
+{
+    let len = values.len();
+    let mut kept = 0;
+    for index in 0..len {
+        let (retained, rest) = values.split_at(index);
+        let candidate = &rest[0];
+        let is_duplicate = retained[..kept]
+            .iter()
+            .any(|existing| distance(existing, candidate) <= threshold);
+        if !is_duplicate {
+            values.swap(kept, index);
+            kept += 1;
+        }
+    }
+    values.truncate(kept);
+    len - kept
+}
@@ -0,0 +1,99 @@
+/// 🧱 The largest number of windows [`windowed_counts`] and [`windowed_rate`] will allocate.
+///
+/// Because empty windows are returned too, the output size depends on the time span rather than
+/// the number of events. Spans that would need more windows than this are rejected with an error
+/// instead of attempting a huge allocation.
+pub const MAX_WINDOWS: usize = 1 << 20;
+
+/// 🪟 Counts events per fixed-size time window.
+///
+/// # Arguments
+/// - `timestamps`: Event times in any unit (seconds, milliseconds, …), in any order.
+///   For [`Instant`](std::time::Instant)s, convert with `instant.duration_since(start).as_millis() as u64`.
+/// - `window`: The window size, in the same unit as `timestamps`. Must be greater than 0.
+///
+/// # Returns
+/// Returns a [`Result`]:
+/// - `Ok(Vec<(u64, usize)>)` of `(window_start, count)` pairs in ascending order.
+/// - `Err(&'static str)` if `window` is `0`, or if the span from the earliest to the latest
+///   event covers more than [`MAX_WINDOWS`] windows.
+///
+/// # Behavior
+/// - Windows are aligned to multiples of `window`: an event at `t` falls in the window starting at
+///   `t - t % window`.
+/// - Every window from the earliest to the latest event is returned, including empty ones, so gaps
+///   show up as zero counts.
+/// - Empty input returns an empty vector.
+///
+/// # Performance
+/// - **O(n + w)** where `w` is the number of windows spanned; the output is allocated once.
+/// - `w` depends on the time span, not the number of events, so it is capped at [`MAX_WINDOWS`].
+///   A stray timestamp (e.g. a default `0` among epoch milliseconds) produces an error rather than
+///   billions of empty windows.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::windowed_rate::windowed_counts;
+///
+/// let request_times_ms = [1_000, 1_200, 1_900, 4_100, 4_500];
+/// assert_eq!(
+///     windowed_counts(&request_times_ms, 1_000).unwrap(),
+///     vec![(1_000, 3), (2_000, 0), (3_000, 0), (4_000, 2)]
+/// );
+/// ```
+pub fn windowed_counts(timestamps: &[u64], window: u64) -> Result<Vec<(u64, usize)>, &'static str> {
+    if window == 0 {
+        return Err("window must be greater than 0");
+    }
+
+    let (Some(&min), Some(&max)) = (timestamps.iter().min(), timestamps.iter().max()) else {
+        return Ok(Vec::new());
+    };
+
+    let first_window = min / window;
+    let window_count = (max / window - first_window)
+        .checked_add(1)
+        .and_then(|count| usize::try_from(count).ok())
+        .filter(|&count| count <= MAX_WINDOWS)
+        .ok_or("time span covers too many windows")?;
+    let mut counts = vec![0usize; window_count];
+    for &timestamp in timestamps {
+        counts[(timestamp / window - first_window) as usize] += 1;
+    }
+
+    Ok(counts
+        .into_iter()
+        .enumerate()
+        .map(|(offset, count)| ((first_window + offset as u64) * window, count))
+        .collect())
+}
+
+/// 📈 Computes the event rate per fixed-size time window.
+///
+/// # Arguments
+/// - `timestamps`: Event times in any unit, in any order.
+/// - `window`: The window size, in the same unit as `timestamps`. Must be greater than 0.
+///
+/// # Returns
+/// Returns a [`Result`]:
+/// - `Ok(Vec<(u64, f64)>)` of `(window_start, events_per_unit)` pairs in ascending order.
+/// - `Err(&'static str)` if `window` is `0` or the span covers more than [`MAX_WINDOWS`] windows.
+///
+/// # Behavior
+/// - Same windows as [`windowed_counts`], with each count divided by `window`.
+/// - With millisecond timestamps, multiply by `1000.0` for events per second.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::windowed_rate::windowed_rate;
+///
+/// let event_seconds = [0, 1, 2, 3, 10, 11];
+/// let rates = windowed_rate(&event_seconds, 5).unwrap();
+/// assert_eq!(rates, vec![(0, 0.8), (5, 0.0), (10, 0.4)]);
+/// ```
+pub fn windowed_rate(timestamps: &[u64], window: u64) -> Result<Vec<(u64, f64)>, &'static str> {
+    Ok(windowed_counts(timestamps, window)?
+        .into_iter()
+        .map(|(start, count)| (start, count as f64 / window as f64))
+        .collect())
+}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// 🚫 Returns a new vector with every occurrence of the excluded values left out.
+///
+/// The non-mutating counterpart to [`pull`](crate::array::pull::pull), mirroring lodash
+/// `_.without`. Reads more naturally than
+/// [`difference`](crate::array::difference::difference) when there is a single exclusion list.
+///
+/// # Type Parameters
+/// - `T`: The element type. Must implement [`Clone`], [`Eq`], and [`Hash`].
+///
+/// # Arguments
+/// - `values`: The input elements. Left untouched.
+/// - `excluded`: The values to leave out.
+///
+/// # Returns
+/// A new `Vec<T>` with the elements of `values` that are not in `excluded`, in their original order.
+///
+/// # Behavior
+/// - Duplicates in `values` that are not excluded are kept.
+/// - An empty `excluded` returns a copy of `values`.
+///
+/// # Performance
+/// - Uses [`HashSet`] (SipHash), safe for untrusted input.
+/// - **O(n + m)**; the set borrows from `excluded`, and only kept elements are cloned.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::without::without;
+///
+/// let tags = ["rust", "draft", "cli", "draft", "wip"];
+/// assert_eq!(without(&tags, &["draft", "wip"]), vec!["rust", "cli"]);
+/// assert_eq!(tags.len(), 5);
+/// ```
+pub fn without<T: Eq + Hash + Clone>(values: &[T], excluded: &[T]) -> Vec<T> {
+    if excluded.is_empty() {
+        return values.to_vec();
+    }
+    let set: HashSet<&T> = excluded.iter().collect();
+    values
+        .iter()
+        .filter(|value| !set.contains(value))
+        .cloned()
+        .collect()
+}
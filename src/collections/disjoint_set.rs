@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 🔗 A union-find structure that tracks which values belong to the same group.
+///
+/// Values are added on first use; [`union`](Self::union) merges the groups of two values and
+/// [`find`](Self::find) returns a group's representative. Path compression and union by size
+/// keep both operations close to **O(1)** amortized.
+///
+/// # Type Parameters
+/// - `T`: The value type. Must implement [`Clone`], [`Eq`], and [`Hash`].
+///
+/// # Examples
+/// ```
+/// use pencil_box::collections::disjoint_set::DisjointSet;
+///
+/// let mut accounts = DisjointSet::new();
+/// accounts.union("alice@work", "alice@home");
+/// accounts.union("alice@home", "a.smith");
+/// accounts.insert("bob");
+///
+/// assert!(accounts.connected(&"alice@work", &"a.smith"));
+/// assert!(!accounts.connected(&"alice@work", &"bob"));
+/// assert_eq!(accounts.group_count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet<T: Eq + Hash + Clone> {
+    values: Vec<T>,
+    indices: HashMap<T, usize>,
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    groups: usize,
+}
+
+impl<T: Eq + Hash + Clone> DisjointSet<T> {
+    /// Creates an empty `DisjointSet`.
+    pub fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            indices: HashMap::new(),
+            parents: Vec::new(),
+            sizes: Vec::new(),
+            groups: 0,
+        }
+    }
+
+    /// Adds `value` as a group of its own. Returns `false` if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.indices.contains_key(&value) {
+            return false;
+        }
+        self.index_of(value);
+        true
+    }
+
+    /// Merges the groups of `a` and `b`, adding either value if missing.
+    ///
+    /// Returns `true` if two different groups were merged, `false` if they were already one.
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.index_of(a);
+        let b = self.index_of(b);
+        let (mut root_a, mut root_b) = (self.root(a), self.root(b));
+        if root_a == root_b {
+            return false;
+        }
+        if self.sizes[root_a] < self.sizes[root_b] {
+            std::mem::swap(&mut root_a, &mut root_b);
+        }
+        self.parents[root_b] = root_a;
+        self.sizes[root_a] += self.sizes[root_b];
+        self.groups -= 1;
+        true
+    }
+
+    /// Returns the representative of `value`'s group, or `None` if `value` was never added.
+    ///
+    /// Two values are in the same group exactly when they have the same representative.
+    pub fn find(&mut self, value: &T) -> Option<&T> {
+        let index = *self.indices.get(value)?;
+        let root = self.root(index);
+        Some(&self.values[root])
+    }
+
+    /// Returns `true` if both values are present and in the same group.
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.indices.get(a).copied(), self.indices.get(b).copied()) {
+            (Some(a), Some(b)) => self.root(a) == self.root(b),
+            _ => false,
+        }
+    }
+
+    /// Returns every group, in order of each group's first-added value.
+    ///
+    /// Values within a group are listed in the order they were added.
+    pub fn groups(&mut self) -> Vec<Vec<T>> {
+        let mut slots: HashMap<usize, usize> = HashMap::with_capacity(self.groups);
+        let mut groups: Vec<Vec<T>> = Vec::with_capacity(self.groups);
+        for index in 0..self.values.len() {
+            let root = self.root(index);
+            let slot = *slots.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[slot].push(self.values[index].clone());
+        }
+        groups
+    }
+
+    /// Returns the number of distinct groups.
+    pub fn group_count(&self) -> usize {
+        self.groups
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values have been added.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn index_of(&mut self, value: T) -> usize {
+        if let Some(&index) = self.indices.get(&value) {
+            return index;
+        }
+        let index = self.values.len();
+        self.values.push(value.clone());
+        self.indices.insert(value, index);
+        self.parents.push(index);
+        self.sizes.push(1);
+        self.groups += 1;
+        index
+    }
+
+    fn root(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = index;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+}
+
+impl<T: Eq + Hash + Clone> Default for DisjointSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod disjoint_set;
+pub mod slot_vec;
+pub mod small_ordered_map;
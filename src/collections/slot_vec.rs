@@ -0,0 +1,184 @@
+/// 🔑 A stable handle to a value stored in a [`SlotVec`].
+///
+/// Keys combine a slot index with a generation counter, so a key to a removed value never
+/// accidentally refers to a newer value that reused the same slot.
+///
+/// With the `serde` feature enabled, `SlotKey` implements `Serialize` and `Deserialize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlotKey {
+    index: usize,
+    generation: u64,
+}
+
+impl SlotKey {
+    /// Returns the slot index this key points at.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the slot when this key was issued.
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    generation: u64,
+    value: Option<T>,
+}
+
+/// 🗄️ A vector with stable, generation-checked keys: removing a value never shifts the others.
+///
+/// Unlike removing from a `Vec`, which moves every later element and invalidates their indices,
+/// `SlotVec::remove` leaves a hole that a later insert reuses. Keys to removed values stop
+/// resolving, even after their slot is reused.
+///
+/// # Type Parameters
+/// - `T`: The stored value type. No trait bounds are required.
+///
+/// # Performance
+/// - `insert`, `remove`, `get`, and `get_mut` are **O(1)**.
+/// - Iteration is **O(capacity)**, skipping empty slots.
+/// - Slots are never released, so memory follows the peak number of live values.
+///
+/// # Examples
+/// ```
+/// use pencil_box::collections::slot_vec::SlotVec;
+///
+/// let mut sessions = SlotVec::new();
+/// let alice = sessions.insert("alice");
+/// let bob = sessions.insert("bob");
+///
+/// assert_eq!(sessions.remove(alice), Some("alice"));
+/// assert_eq!(sessions.get(bob), Some(&"bob"));
+///
+/// let carol = sessions.insert("carol"); // reuses alice's slot
+/// assert_eq!(sessions.get(alice), None); // the old key stays invalid
+/// assert_eq!(sessions.get(carol), Some(&"carol"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlotVec<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T> SlotVec<T> {
+    /// Creates an empty `SlotVec`. Usable in `const` contexts.
+    pub const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty `SlotVec` with room for `capacity` values before reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Stores `value` and returns a key that stays valid until the value is removed.
+    pub fn insert(&mut self, value: T) -> SlotKey {
+        self.len += 1;
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index];
+            slot.value = Some(value);
+            return SlotKey {
+                index,
+                generation: slot.generation,
+            };
+        }
+
+        self.slots.push(Slot {
+            generation: 0,
+            value: Some(value),
+        });
+        SlotKey {
+            index: self.slots.len() - 1,
+            generation: 0,
+        }
+    }
+
+    /// Removes and returns the value for `key`, or `None` if the key is stale or unknown.
+    pub fn remove(&mut self, key: SlotKey) -> Option<T> {
+        let slot = self.slots.get_mut(key.index)?;
+        if slot.generation != key.generation {
+            return None;
+        }
+        let value = slot.value.take()?;
+        slot.generation += 1;
+        self.free.push(key.index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if the key is stale or unknown.
+    pub fn get(&self, key: SlotKey) -> Option<&T> {
+        self.slots
+            .get(key.index)
+            .filter(|slot| slot.generation == key.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if the key is stale or unknown.
+    pub fn get_mut(&mut self, key: SlotKey) -> Option<&mut T> {
+        self.slots
+            .get_mut(key.index)
+            .filter(|slot| slot.generation == key.generation)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    /// Returns `true` if `key` currently refers to a value.
+    pub fn contains_key(&self, key: SlotKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Iterates over live values with their keys, in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (SlotKey, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            slot.value.as_ref().map(|value| {
+                (
+                    SlotKey {
+                        index,
+                        generation: slot.generation,
+                    },
+                    value,
+                )
+            })
+        })
+    }
+
+    /// Removes every value. All previously issued keys become invalid.
+    pub fn clear(&mut self) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.value.take().is_some() {
+                slot.generation += 1;
+                self.free.push(index);
+            }
+        }
+        self.len = 0;
+    }
+
+    /// Returns the number of live values.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no live values.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Default for SlotVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,216 @@
+use std::borrow::Borrow;
+
+/// 📒 A small, vec-backed map that remembers insertion order.
+///
+/// Lookups are linear scans, which beat hashing for a handful of entries and need only [`Eq`]
+/// on the keys. Iteration always follows insertion order, so output built from it is deterministic.
+///
+/// # Type Parameters
+/// - `K`: The key type. Must implement [`Eq`].
+/// - `V`: The value type.
+///
+/// # Behavior
+/// - Inserting an existing key replaces its value but keeps its original position.
+/// - Removing a key keeps the relative order of the remaining entries.
+///
+/// With the `serde` feature enabled, a `SmallOrderedMap` serializes as a list of `(key, value)`
+/// pairs; when deserializing, later duplicates replace earlier values.
+///
+/// # Performance
+/// - `get`, `insert`, and `remove` are **O(n)**; prefer a `HashMap` beyond a few dozen entries.
+/// - Memory is a single `Vec<(K, V)>` with no hashing overhead.
+///
+/// # Examples
+/// ```
+/// use pencil_box::collections::small_ordered_map::SmallOrderedMap;
+///
+/// let mut counts = SmallOrderedMap::new();
+/// for word in ["b", "a", "b", "c", "a", "b"] {
+///     *counts.get_or_insert_with(word, || 0) += 1;
+/// }
+///
+/// let pairs: Vec<_> = counts.iter().map(|(k, v)| (*k, *v)).collect();
+/// assert_eq!(pairs, vec![("b", 3), ("a", 2), ("c", 1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(from = "Vec<(K, V)>")
+)]
+pub struct SmallOrderedMap<K: Eq, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K: Eq, V> SmallOrderedMap<K, V> {
+    /// Creates an empty map.
+    ///
+    /// This is a `const fn`, so an empty map can initialize a `static` table:
+    ///
+    /// ```
+    /// use pencil_box::collections::small_ordered_map::SmallOrderedMap;
+    /// use std::sync::Mutex;
+    ///
+    /// static ROUTES: Mutex<SmallOrderedMap<&str, u16>> = Mutex::new(SmallOrderedMap::new());
+    ///
+    /// ROUTES.lock().unwrap().insert("/health", 200);
+    /// assert_eq!(ROUTES.lock().unwrap().get("/health"), Some(&200));
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Creates an empty map with room for `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts a value, returning the previous value if the key was already present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value for `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).map(|index| &self.entries[index].1)
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).map(|index| &mut self.entries[index].1)
+    }
+
+    /// Returns the value for `key`, inserting the result of `default` at the end if it is missing.
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        let index = match self.position(&key) {
+            Some(index) => index,
+            None => {
+                self.entries.push((key, default()));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index].1
+    }
+
+    /// Removes a key, returning its value. Remaining entries keep their order.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).map(|index| self.entries.remove(index).1)
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Iterates over entries in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Iterates over keys in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Iterates over values in insertion order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the index of `key` in the entry list.
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries
+            .iter()
+            .position(|(existing, _)| existing.borrow() == key)
+    }
+}
+
+impl<K: Eq, V> Default for SmallOrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V> FromIterator<(K, V)> for SmallOrderedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq, V> Extend<(K, V)> for SmallOrderedMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq, V> From<Vec<(K, V)>> for SmallOrderedMap<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<K: Eq, V> From<SmallOrderedMap<K, V>> for Vec<(K, V)> {
+    fn from(map: SmallOrderedMap<K, V>) -> Self {
+        map.entries
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Eq + serde::Serialize, V: serde::Serialize> serde::Serialize for SmallOrderedMap<K, V> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.entries)
+    }
+}
+
+impl<K: Eq, V> IntoIterator for SmallOrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
@@ -0,0 +1,49 @@
+/// 🚩 Reads an environment variable as a boolean flag.
+///
+/// # Arguments
+/// - `key`: The name of the environment variable to read.
+///
+/// # Returns
+/// `true` if the variable is set to a truthy string, `false` otherwise.
+///
+/// # Behavior
+/// - Truthy values are `"1"`, `"true"`, and `"yes"`, compared case-insensitively.
+/// - Leading and trailing whitespace is trimmed before comparison.
+/// - Any other value — including an unset variable or non-Unicode data — is `false`.
+///
+/// # Performance
+/// - One lookup in the process environment.
+/// - Comparison is done in place with `eq_ignore_ascii_case`; no lowercase copy is allocated.
+///
+/// # Examples
+///
+/// ### 🚩 Truthy strings
+/// ```
+/// use pencil_box::env::get_bool::get_bool;
+///
+/// std::env::set_var("PB_DOC_GET_BOOL_VERBOSE", "Yes");
+/// assert!(get_bool("PB_DOC_GET_BOOL_VERBOSE"));
+/// ```
+///
+/// ### 📭 Unset or other values are `false`
+/// ```
+/// use pencil_box::env::get_bool::get_bool;
+///
+/// std::env::set_var("PB_DOC_GET_BOOL_OFF", "off");
+/// assert!(!get_bool("PB_DOC_GET_BOOL_OFF"));
+/// assert!(!get_bool("PB_DOC_GET_BOOL_MISSING"));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn get_bool(key: &str) -> bool {
+    match std::env::var(key) {
+        Ok(value) => {
+            let value = value.trim();
+            ["1", "true", "yes"]
+                .iter()
+                .any(|truthy| value.eq_ignore_ascii_case(truthy))
+        }
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use crate::env::get_parsed::get_parsed;
+
+/// 🔧 Reads and parses an environment variable, falling back to a default value.
+///
+/// # Type Parameters
+/// - `T`: The target type. Must implement [`FromStr`].
+///
+/// # Arguments
+/// - `key`: The name of the environment variable to read.
+/// - `default`: The value returned when the variable is unset or cannot be parsed.
+///
+/// # Returns
+/// The parsed value, or `default` if the variable is unset, not valid Unicode, or fails to parse.
+///
+/// # Behavior
+/// - Identical to [`get_parsed`], with `None` replaced by `default`.
+/// - Leading and trailing whitespace is trimmed before parsing.
+///
+/// # Performance
+/// - One lookup in the process environment and a single parse.
+/// - `default` is moved, never cloned.
+///
+/// # Examples
+///
+/// ### 🔢 Use a default when the variable is missing
+/// ```
+/// use pencil_box::env::get_or::get_or;
+///
+/// let workers: usize = get_or("PB_DOC_GET_OR_MISSING", 4);
+/// assert_eq!(workers, 4);
+/// ```
+///
+/// ### ✅ Prefer the environment when present
+/// ```
+/// use pencil_box::env::get_or::get_or;
+///
+/// std::env::set_var("PB_DOC_GET_OR_WORKERS", "16");
+/// assert_eq!(get_or("PB_DOC_GET_OR_WORKERS", 4usize), 16);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn get_or<T: FromStr>(key: &str, default: T) -> T {
+    get_parsed(key).unwrap_or(default)
+}
@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+/// 🔧 Reads an environment variable and parses it into the requested type.
+///
+/// # Type Parameters
+/// - `T`: The target type. Must implement [`FromStr`].
+///
+/// # Arguments
+/// - `key`: The name of the environment variable to read.
+///
+/// # Returns
+/// - `Some(T)` if the variable is set and its value parses successfully.
+/// - `None` if the variable is unset, is not valid Unicode, or fails to parse.
+///
+/// # Behavior
+/// - Leading and trailing whitespace is trimmed before parsing.
+/// - Parsing is delegated to [`str::parse`], so the accepted format is defined by `T::from_str`.
+///
+/// # Performance
+/// - One lookup in the process environment and a single parse.
+/// - Allocates only the `String` returned by [`std::env::var`].
+///
+/// # Examples
+///
+/// ### 🔢 Parse a numeric setting
+/// ```
+/// use pencil_box::env::get_parsed::get_parsed;
+///
+/// std::env::set_var("PB_DOC_GET_PARSED_PORT", "8080");
+/// let port: Option<u16> = get_parsed("PB_DOC_GET_PARSED_PORT");
+/// assert_eq!(port, Some(8080));
+/// ```
+///
+/// ### ⚠️ Unset or invalid values yield `None`
+/// ```
+/// use pencil_box::env::get_parsed::get_parsed;
+///
+/// std::env::set_var("PB_DOC_GET_PARSED_BAD", "not-a-number");
+/// assert_eq!(get_parsed::<u32>("PB_DOC_GET_PARSED_BAD"), None);
+/// assert_eq!(get_parsed::<u32>("PB_DOC_GET_PARSED_MISSING"), None);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn get_parsed<T: FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok()?.trim().parse().ok()
+}
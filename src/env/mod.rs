@@ -0,0 +1,3 @@
+pub mod get_bool;
+pub mod get_or;
+pub mod get_parsed;
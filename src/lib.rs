@@ -1 +1,9 @@
-pub mod array;
\ No newline at end of file
+pub mod array;
+pub mod collections;
+pub mod env;
+mod macros;
+pub mod path;
+pub mod prob;
+pub mod queue;
+pub mod text;
+pub mod url;
@@ -0,0 +1,112 @@
+/// 🧱 Builds a `Vec` with one of the crate's fill or range helpers, chosen by the macro form.
+///
+/// | Form                      | Expands to                                                 |
+/// | ------------------------- | ---------------------------------------------------------- |
+/// | `seq![value; n]`          | [`fill_value`](crate::array::fill_value::fill_value)`(&value, n)` |
+/// | `seq![default; n]`        | [`fill_default`](crate::array::fill_default::fill_default)`(n)`   |
+/// | `seq![with f; n]`         | [`fill_with`](crate::array::fill_with::fill_with)`(n, f)`         |
+/// | `seq![range start, end]`  | [`range`](crate::array::range::range)`(start, end)`               |
+/// | `seq![range start, end, step]` | [`range_step`](crate::array::range::range_step)`(start, end, step)` |
+///
+/// Any other shape is a compile error, as are arguments of the wrong type for the helper.
+///
+/// # Behavior
+/// - `default` and `with` are matched as keywords; to fill with a variable of that name, use
+///   the helper function directly.
+/// - Arguments are evaluated exactly once.
+///
+/// # Examples
+/// ```
+/// use pencil_box::seq;
+///
+/// let blanks: Vec<String> = seq![default; 2];
+/// assert_eq!(blanks, vec!["", ""]);
+///
+/// assert_eq!(seq!["ab".to_string(); 2], vec!["ab", "ab"]);
+/// assert_eq!(seq![with |i| i * i; 4], vec![0, 1, 4, 9]);
+/// assert_eq!(seq![range 3, 0], vec![3, 2, 1]);
+/// assert_eq!(seq![range 0.0, 1.0, 0.25], vec![0.0, 0.25, 0.5, 0.75]);
+/// ```
+///
+/// ```compile_fail
+/// use pencil_box::seq;
+///
+/// let oops: Vec<u8> = seq![range 1, 2, 3, 4];
+/// ```
+#[macro_export]
+macro_rules! seq {
+    (default; $size:expr $(,)?) => {
+        $crate::array::fill_default::fill_default($size)
+    };
+    (with $f:expr; $size:expr $(,)?) => {
+        $crate::array::fill_with::fill_with($size, $f)
+    };
+    (range $start:expr, $end:expr $(,)?) => {
+        $crate::array::range::range($start, $end)
+    };
+    (range $start:expr, $end:expr, $step:expr $(,)?) => {
+        $crate::array::range::range_step($start, $end, $step)
+    };
+    ($value:expr; $size:expr $(,)?) => {
+        $crate::array::fill_value::fill_value(&$value, $size)
+    };
+}
+
+/// 🗺️ Builds a [`HashMap`](std::collections::HashMap) from `key => value` pairs.
+///
+/// # Behavior
+/// - The map is allocated once, sized for the number of pairs.
+/// - If a key appears more than once, the last value wins.
+/// - `map_of! {}` builds an empty map; its types come from context.
+///
+/// # Examples
+/// ```
+/// use pencil_box::map_of;
+///
+/// let ports = map_of! {
+///     "http" => 80,
+///     "https" => 443,
+/// };
+/// assert_eq!(ports["https"], 443);
+/// assert_eq!(ports.len(), 2);
+/// ```
+///
+/// ```compile_fail
+/// use pencil_box::map_of;
+///
+/// let missing_value = map_of! { "http" => 80, "https" };
+/// ```
+#[macro_export]
+macro_rules! map_of {
+    () => {
+        ::std::collections::HashMap::new()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {
+        ::std::collections::HashMap::from([$(($key, $value)),+])
+    };
+}
+
+/// 🧺 Builds a [`HashSet`](std::collections::HashSet) from a list of values.
+///
+/// # Behavior
+/// - The set is allocated once, sized for the number of values.
+/// - Duplicate values are collapsed.
+/// - `set_of![]` builds an empty set; its type comes from context.
+///
+/// # Examples
+/// ```
+/// use pencil_box::set_of;
+///
+/// let admins = set_of!["ana", "bo", "ana"];
+/// assert_eq!(admins.len(), 2);
+/// assert!(admins.contains("bo"));
+/// ```
+#[macro_export]
+macro_rules! set_of {
+    () => {
+        ::std::collections::HashSet::new()
+    };
+    ($($value:expr),+ $(,)?) => {
+        ::std::collections::HashSet::from([$($value),+])
+    };
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+/// 🏷️ Ensures a path ends with the given extension, appending it when missing.
+///
+/// # Arguments
+/// - `path`: The path to check. Accepts anything that implements `AsRef<Path>`.
+/// - `extension`: The required extension, with or without a leading `.` (e.g. `"json"` or `".json"`).
+///
+/// # Returns
+/// A [`PathBuf`] whose final extension is `extension`.
+///
+/// # Behavior
+/// - If the path's extension already equals `extension`, the path is returned unchanged.
+/// - Otherwise `.extension` is **appended** to the file name, so an existing different
+///   extension is kept (e.g. `archive.tar` → `archive.tar.gz`).
+/// - Comparison is case-sensitive, matching how most filesystems treat names.
+/// - If `extension` is empty (or just `"."`), the path is returned unchanged.
+/// - A path with no file name (e.g. `/` or `..`) is returned unchanged.
+///
+/// # Performance
+/// - ✅ **O(n)** in the path length.
+/// - Allocates exactly one [`PathBuf`], reserving room for the appended extension up front.
+/// - 🚫 No filesystem access.
+///
+/// # Examples
+///
+/// ### ➕ Append a missing extension
+/// ```
+/// use pencil_box::path::ensure_extension::ensure_extension;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(ensure_extension("out/report", "csv"), PathBuf::from("out/report.csv"));
+/// assert_eq!(ensure_extension("archive.tar", ".gz"), PathBuf::from("archive.tar.gz"));
+/// ```
+///
+/// ### ✅ Already present
+/// ```
+/// use pencil_box::path::ensure_extension::ensure_extension;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(ensure_extension("config.json", "json"), PathBuf::from("config.json"));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn ensure_extension<P: AsRef<Path>>(path: P, extension: &str) -> PathBuf {
+    let path = path.as_ref();
+    let extension = extension.strip_prefix('.').unwrap_or(extension);
+
+    if extension.is_empty() || path.file_name().is_none() {
+        return path.to_path_buf();
+    }
+    if path.extension().is_some_and(|current| current == extension) {
+        return path.to_path_buf();
+    }
+
+    let mut os_string =
+        std::ffi::OsString::with_capacity(path.as_os_str().len() + 1 + extension.len());
+    os_string.push(path.as_os_str());
+    os_string.push(".");
+    os_string.push(extension);
+    PathBuf::from(os_string)
+}
@@ -0,0 +1,3 @@
+pub mod ensure_extension;
+pub mod normalize;
+pub mod relative_to;
@@ -0,0 +1,81 @@
+use std::path::{Component, Path, PathBuf};
+
+/// 🧭 Lexically normalizes a path by resolving `.` and `..` components, without touching the filesystem.
+///
+/// # Arguments
+/// - `path`: The path to normalize. Accepts anything that implements `AsRef<Path>`.
+///
+/// # Returns
+/// A new [`PathBuf`] with redundant separators, `.` components, and resolvable `..` components removed.
+///
+/// # Behavior
+/// - `.` components are dropped.
+/// - `..` removes the preceding normal component.
+/// - `..` directly after the root (e.g. `/..`) is dropped, since nothing exists above the root.
+/// - Leading `..` components of a **relative** path are preserved, as they cannot be resolved lexically.
+/// - A path that normalizes to nothing (e.g. `""` or `a/..`) becomes `"."`.
+/// - Symlinks are **not** resolved — use [`std::fs::canonicalize`] when the real location matters.
+///
+/// # Performance
+/// - ✅ Single pass over the path components: **O(n)** in the path length.
+/// - 🚫 No filesystem access or system calls.
+/// - Allocates the output once, sized to the input length.
+///
+/// # Examples
+///
+/// ### 🧹 Resolve `.` and `..`
+/// ```
+/// use pencil_box::path::normalize::normalize;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(normalize("a/./b/../c"), PathBuf::from("a/c"));
+/// assert_eq!(normalize("/var/log/../lib"), PathBuf::from("/var/lib"));
+/// ```
+///
+/// ### 🔝 Cannot escape the root
+/// ```
+/// use pencil_box::path::normalize::normalize;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(normalize("/../etc"), PathBuf::from("/etc"));
+/// ```
+///
+/// ### ↩️ Unresolvable parents of relative paths are kept
+/// ```
+/// use pencil_box::path::normalize::normalize;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(normalize("../a/../../b"), PathBuf::from("../../b"));
+/// assert_eq!(normalize("a/.."), PathBuf::from("."));
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn normalize<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    let mut components: Vec<Component> = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => components.push(component),
+            },
+            _ => components.push(component),
+        }
+    }
+
+    if components.is_empty() {
+        return PathBuf::from(".");
+    }
+
+    let mut normalized = PathBuf::with_capacity(path.as_os_str().len());
+    for component in components {
+        normalized.push(component);
+    }
+    normalized
+}
@@ -0,0 +1,92 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::path::normalize::normalize;
+
+/// 🧭 Computes the relative path that leads from `base` to `target`, without touching the filesystem.
+///
+/// # Arguments
+/// - `base`: The directory the result should be relative to.
+/// - `target`: The path to reach from `base`.
+///
+/// # Returns
+/// - `Some(PathBuf)` holding the relative path, or `"."` when both paths are the same.
+/// - `None` if no lexical answer exists:
+///   - one path is absolute and the other relative,
+///   - the paths have different prefixes (e.g. different Windows drives), or
+///   - `base` climbs above its starting point with `..` past the shared prefix, so the
+///     directory names needed to climb back down are unknown.
+///
+/// # Behavior
+/// - Both paths are [`normalize`]d first, so `.` and resolvable `..` components are handled.
+/// - `base` is always treated as a directory.
+/// - Symlinks are **not** resolved.
+///
+/// # Performance
+/// - ✅ **O(n + m)** in the lengths of the two paths.
+/// - 🚫 No filesystem access or system calls.
+///
+/// # Examples
+///
+/// ### 🧭 Sibling and nested paths
+/// ```
+/// use pencil_box::path::relative_to::relative_to;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(relative_to("/srv/app", "/srv/app/static/logo.png"), Some(PathBuf::from("static/logo.png")));
+/// assert_eq!(relative_to("/srv/app/bin", "/srv/data"), Some(PathBuf::from("../../data")));
+/// assert_eq!(relative_to("a/b", "a/b"), Some(PathBuf::from(".")));
+/// ```
+///
+/// ### ⚠️ Mixed absolute and relative paths
+/// ```
+/// use pencil_box::path::relative_to::relative_to;
+///
+/// assert_eq!(relative_to("/srv", "data"), None);
+/// ```
+///
+/// # Panic Safety
+/// ✅ This function is panic-free for all inputs.
+pub fn relative_to<B: AsRef<Path>, T: AsRef<Path>>(base: B, target: T) -> Option<PathBuf> {
+    let base = normalize(base);
+    let target = normalize(target);
+
+    if base.is_absolute() != target.is_absolute() || base.has_root() != target.has_root() {
+        return None;
+    }
+
+    let mut base_components = base
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .peekable();
+    let mut target_components = target
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .peekable();
+
+    while let (Some(b), Some(t)) = (base_components.peek(), target_components.peek()) {
+        if b != t {
+            break;
+        }
+        base_components.next();
+        target_components.next();
+    }
+
+    let mut relative = PathBuf::new();
+    for component in base_components {
+        match component {
+            Component::Normal(_) => relative.push(".."),
+            _ => return None,
+        }
+    }
+    for component in target_components {
+        match component {
+            Component::Prefix(_) | Component::RootDir => return None,
+            _ => relative.push(component),
+        }
+    }
+
+    if relative.as_os_str().is_empty() {
+        relative.push(".");
+    }
+    Some(relative)
+}
@@ -0,0 +1,137 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// 🌸 A Bloom filter for fast, memory-light "definitely not present" checks.
+///
+/// A Bloom filter answers membership queries with no false negatives: if [`contains`](Self::contains)
+/// returns `false`, the value was never inserted. A `true` answer may be a false positive, at roughly
+/// the rate chosen when the filter was created. Use it as a pre-check in front of an expensive lookup
+/// (a large `HashSet`, a database, a network call).
+///
+/// # Type Parameters
+/// - `T`: The value type. Must implement [`Hash`]; unsized types such as `str` are supported.
+///
+/// # Behavior
+/// - Sized for an expected number of items and a target false-positive rate.
+/// - Inserting more items than expected still works but raises the false-positive rate.
+/// - Values cannot be removed.
+/// - Hashing uses [`DefaultHasher::new`] with fixed keys, so results are deterministic.
+///
+/// # Performance
+/// - `insert` and `contains` are **O(k)** where `k` is the number of hash probes (typically 5–10).
+/// - Memory is about `-n · ln(p) / ln(2)²` bits: roughly 1.2 bytes per item at a 1% rate.
+///
+/// # Examples
+/// ```
+/// use pencil_box::prob::bloom_filter::BloomFilter;
+///
+/// let blocked = ["spam.example", "ads.example"];
+/// let filter: BloomFilter<str> = BloomFilter::from_slice(&blocked, 0.01).unwrap();
+///
+/// assert!(filter.contains("spam.example"));
+/// if !filter.contains("docs.rs") {
+///     // Definitely not blocked — skip the expensive lookup.
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter<T: Hash + ?Sized> {
+    bits: Vec<u64>,
+    bit_count: u64,
+    hash_count: u32,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Hash + ?Sized> BloomFilter<T> {
+    /// Creates an empty filter sized for `expected_items` at the given false-positive rate.
+    ///
+    /// `expected_items` of `0` is treated as `1`.
+    ///
+    /// # Errors
+    /// Returns `Err(&'static str)` if `false_positive_rate` is not strictly between `0.0` and `1.0`.
+    pub fn with_rate(
+        expected_items: usize,
+        false_positive_rate: f64,
+    ) -> Result<Self, &'static str> {
+        if !(false_positive_rate > 0.0 && false_positive_rate < 1.0) {
+            return Err("false_positive_rate must be between 0 and 1 (exclusive)");
+        }
+
+        let items = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let bit_count = (-items * false_positive_rate.ln() / (ln2 * ln2))
+            .ceil()
+            .max(64.0) as u64;
+        let hash_count = ((bit_count as f64 / items) * ln2).round().clamp(1.0, 32.0) as u32;
+
+        Ok(Self {
+            bits: vec![0; bit_count.div_ceil(64) as usize],
+            bit_count,
+            hash_count,
+            marker: PhantomData,
+        })
+    }
+
+    /// Builds a filter sized for `values` and inserts all of them.
+    ///
+    /// Accepts any slice whose elements borrow as `T`, e.g. `&[String]` or `&[&str]` for `BloomFilter<str>`.
+    ///
+    /// # Errors
+    /// Returns `Err(&'static str)` if `false_positive_rate` is not strictly between `0.0` and `1.0`.
+    pub fn from_slice<V: Borrow<T>>(
+        values: &[V],
+        false_positive_rate: f64,
+    ) -> Result<Self, &'static str> {
+        let mut filter = Self::with_rate(values.len(), false_positive_rate)?;
+        for value in values {
+            filter.insert(value.borrow());
+        }
+        Ok(filter)
+    }
+
+    /// Adds a value to the filter.
+    pub fn insert(&mut self, value: &T) {
+        for bit in self.probes(value) {
+            self.bits[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns `false` if `value` was definitely never inserted, or `true` if it probably was.
+    pub fn contains(&self, value: &T) -> bool {
+        self.probes(value)
+            .all(|bit| self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Removes every value, keeping the filter's size and rate.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Returns the number of bits in the filter.
+    pub fn bit_count(&self) -> u64 {
+        self.bit_count
+    }
+
+    /// Returns the number of hash probes per value.
+    pub fn hash_count(&self) -> u32 {
+        self.hash_count
+    }
+
+    /// Yields the bit positions for `value` using double hashing (`h1 + i · h2`).
+    fn probes(&self, value: &T) -> impl Iterator<Item = u64> {
+        let h1 = seeded_hash(value, 0);
+        let h2 = seeded_hash(value, 1) | 1;
+        let bit_count = self.bit_count;
+        (0..u64::from(self.hash_count))
+            .map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % bit_count)
+    }
+}
+
+/// Hashes `value` together with `seed` using the fixed-key default hasher.
+fn seeded_hash<T: Hash + ?Sized>(value: &T, seed: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
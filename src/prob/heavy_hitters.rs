@@ -0,0 +1,193 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 🔥 Tracks the approximately most frequent values of a stream in bounded memory (Space-Saving).
+///
+/// Keeps at most `capacity` counters. When a new value arrives and every counter is taken, the
+/// value with the smallest count is evicted and the newcomer inherits that count as its error bound.
+///
+/// # Type Parameters
+/// - `T`: The value type. Must implement [`Clone`], [`Eq`], and [`Hash`].
+///
+/// # Guarantees
+/// - Any value occurring more than `total / capacity` times is always tracked.
+/// - For a tracked value, [`bounds`](Self::bounds) returns `(lower, upper)` with
+///   `lower <= true count <= upper`.
+///
+/// # Behavior
+/// - Among counters tied for the smallest count, the one tracked longest is evicted first.
+///
+/// # Performance
+/// - Memory is **O(capacity)**, regardless of stream length.
+/// - `insert` is **O(log capacity)**: counters live in a min-heap keyed by count, so finding and
+///   evicting the smallest one never scans the others.
+///
+/// # Examples
+/// ```
+/// use pencil_box::prob::heavy_hitters::HeavyHitters;
+///
+/// let mut hitters = HeavyHitters::new(3).unwrap();
+/// for path in ["/", "/a", "/", "/b", "/", "/c", "/a", "/"] {
+///     hitters.insert(path);
+/// }
+///
+/// let top = hitters.top(1);
+/// assert_eq!(top[0].0, &"/");
+/// assert!(top[0].1 >= 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HeavyHitters<T: Eq + Hash + Clone> {
+    capacity: usize,
+    total: u64,
+    next_order: u64,
+    /// Min-heap ordered by `(count, order)`.
+    counters: Vec<Counter<T>>,
+    /// Position of each tracked value in `counters`.
+    indices: HashMap<T, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Counter<T> {
+    value: T,
+    count: u64,
+    error: u64,
+    /// When the value started being tracked; breaks ties deterministically.
+    order: u64,
+}
+
+impl<T> Counter<T> {
+    fn key(&self) -> (u64, u64) {
+        (self.count, self.order)
+    }
+}
+
+impl<T: Eq + Hash + Clone> HeavyHitters<T> {
+    /// Creates a tracker with room for `capacity` counters.
+    ///
+    /// # Errors
+    /// Returns `Err(&'static str)` if `capacity` is `0`.
+    pub fn new(capacity: usize) -> Result<Self, &'static str> {
+        if capacity == 0 {
+            return Err("capacity must be greater than 0");
+        }
+        Ok(Self {
+            capacity,
+            total: 0,
+            next_order: 0,
+            counters: Vec::with_capacity(capacity),
+            indices: HashMap::with_capacity(capacity),
+        })
+    }
+
+    /// Records one occurrence of `value`.
+    pub fn insert(&mut self, value: T) {
+        self.total += 1;
+
+        if let Some(&index) = self.indices.get(&value) {
+            self.counters[index].count += 1;
+            self.sift_down(index);
+            return;
+        }
+
+        let order = self.next_order;
+        self.next_order += 1;
+
+        if self.counters.len() < self.capacity {
+            let index = self.counters.len();
+            self.counters.push(Counter {
+                value: value.clone(),
+                count: 1,
+                error: 0,
+                order,
+            });
+            self.indices.insert(value, index);
+            self.sift_up(index);
+            return;
+        }
+
+        let min_count = self.counters[0].count;
+        self.indices.remove(&self.counters[0].value);
+        self.counters[0] = Counter {
+            value: value.clone(),
+            count: min_count + 1,
+            error: min_count,
+            order,
+        };
+        self.indices.insert(value, 0);
+        self.sift_down(0);
+    }
+
+    /// Returns up to `n` tracked values with their estimated counts, most frequent first.
+    ///
+    /// Estimated counts are upper bounds; see [`bounds`](Self::bounds) for the guaranteed range.
+    /// Equal counts are ordered by smaller error bound first, then by how long the value has
+    /// been tracked, so the result does not depend on hash order.
+    pub fn top(&self, n: usize) -> Vec<(&T, u64)> {
+        let mut entries: Vec<&Counter<T>> = self.counters.iter().collect();
+        entries
+            .sort_unstable_by_key(|counter| (Reverse(counter.count), counter.error, counter.order));
+        entries
+            .into_iter()
+            .take(n)
+            .map(|counter| (&counter.value, counter.count))
+            .collect()
+    }
+
+    /// Returns `(lower, upper)` bounds on the true count of `value`, or `None` if it is not tracked.
+    pub fn bounds(&self, value: &T) -> Option<(u64, u64)> {
+        self.indices.get(value).map(|&index| {
+            let counter = &self.counters[index];
+            (counter.count - counter.error, counter.count)
+        })
+    }
+
+    /// Returns the total number of values inserted.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the maximum number of counters kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.counters[parent].key() <= self.counters[index].key() {
+                break;
+            }
+            self.swap(parent, index);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let mut smallest = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.counters.len()
+                    && self.counters[child].key() < self.counters[smallest].key()
+                {
+                    smallest = child;
+                }
+            }
+            if smallest == index {
+                break;
+            }
+            self.swap(smallest, index);
+            index = smallest;
+        }
+    }
+
+    /// Swaps two heap slots and keeps `indices` pointing at them.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.counters.swap(a, b);
+        for index in [a, b] {
+            if let Some(slot) = self.indices.get_mut(&self.counters[index].value) {
+                *slot = index;
+            }
+        }
+    }
+}
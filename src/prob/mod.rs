@@ -0,0 +1,3 @@
+pub mod approx_distinct;
+pub mod bloom_filter;
+pub mod heavy_hitters;
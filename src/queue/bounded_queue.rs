@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+/// 🚦 What a [`BoundedQueue`] does when [`push`](BoundedQueue::push) is called while it is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Refuse the new item and hand it back to the caller.
+    Reject,
+    /// Evict the item at the front (the oldest) to make room for the new one.
+    DropOldest,
+    /// Evict the item at the back (the most recently queued) to make room for the new one.
+    DropNewest,
+}
+
+/// 📥 The result of a [`BoundedQueue::push`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub enum PushOutcome<T> {
+    /// The item was queued without evicting anything.
+    Queued,
+    /// The item was queued and the contained item was evicted to make room.
+    Evicted(T),
+    /// The queue was full under [`OverflowPolicy::Reject`]; the item is handed back.
+    Rejected(T),
+}
+
+impl<T> PushOutcome<T> {
+    /// Returns `true` if the pushed item ended up in the queue.
+    pub fn is_queued(&self) -> bool {
+        !matches!(self, PushOutcome::Rejected(_))
+    }
+}
+
+/// 🧺 A FIFO queue with a fixed capacity and an explicit policy for overflow.
+///
+/// Wraps a [`VecDeque`] that never grows past `capacity`. When full, [`push`](Self::push)
+/// applies the queue's [`OverflowPolicy`] instead of growing, which makes it suitable for
+/// buffering between pipeline stages that run at different rates.
+///
+/// # Type Parameters
+/// - `T`: The queued item type. No trait bounds are required.
+///
+/// # Performance
+/// - `push` and `pop` are **O(1)**; the buffer is allocated once, up front.
+/// - `drain_batch(n)` is **O(n)**.
+///
+/// # Examples
+/// ```
+/// use pencil_box::queue::bounded_queue::{BoundedQueue, OverflowPolicy, PushOutcome};
+///
+/// let mut events = BoundedQueue::new(2, OverflowPolicy::DropOldest).unwrap();
+/// let _ = events.push("a");
+/// let _ = events.push("b");
+/// assert_eq!(events.push("c"), PushOutcome::Evicted("a"));
+///
+/// assert_eq!(events.drain_batch(10), vec!["b", "c"]);
+/// assert_eq!(events.dropped(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedQueue<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: u64,
+}
+
+impl<T> BoundedQueue<T> {
+    /// Creates an empty queue holding at most `capacity` items.
+    ///
+    /// # Errors
+    /// Returns `Err(&'static str)` if `capacity` is `0`.
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Result<Self, &'static str> {
+        if capacity == 0 {
+            return Err("capacity must be greater than 0");
+        }
+        Ok(Self {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+            policy,
+            dropped: 0,
+        })
+    }
+
+    /// Adds `item` to the back of the queue, applying the overflow policy if the queue is full.
+    ///
+    /// Every evicted or rejected item is counted by [`dropped`](Self::dropped).
+    pub fn push(&mut self, item: T) -> PushOutcome<T> {
+        if self.items.len() < self.capacity {
+            self.items.push_back(item);
+            return PushOutcome::Queued;
+        }
+
+        self.dropped += 1;
+        let evicted = match self.policy {
+            OverflowPolicy::Reject => return PushOutcome::Rejected(item),
+            OverflowPolicy::DropOldest => self.items.pop_front(),
+            OverflowPolicy::DropNewest => self.items.pop_back(),
+        };
+        self.items.push_back(item);
+        match evicted {
+            Some(evicted) => PushOutcome::Evicted(evicted),
+            None => PushOutcome::Queued,
+        }
+    }
+
+    /// Removes and returns the item at the front of the queue.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Returns a reference to the item at the front of the queue without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Removes up to `max` items from the front of the queue, in FIFO order.
+    pub fn drain_batch(&mut self, max: usize) -> Vec<T> {
+        let count = max.min(self.items.len());
+        self.items.drain(..count).collect()
+    }
+
+    /// Removes every queued item, in FIFO order.
+    pub fn drain_all(&mut self) -> Vec<T> {
+        self.items.drain(..).collect()
+    }
+
+    /// Returns an iterator over the queued items, front to back.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    /// Removes every queued item. The [`dropped`](Self::dropped) counter is kept.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Returns the number of queued items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns `true` if the next `push` will trigger the overflow policy.
+    pub fn is_full(&self) -> bool {
+        self.items.len() == self.capacity
+    }
+
+    /// Returns the maximum number of queued items.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the overflow policy.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns how many items have been evicted or rejected since the queue was created.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
@@ -0,0 +1,147 @@
+use crate::array::diff_ops::{diff_ops, DiffOp};
+use std::fmt;
+
+/// 📄 One line of a line-based diff, borrowing from the compared texts.
+///
+/// An alias for [`DiffOp`] over `&str`, so each line prints as `" line"`, `"-line"`, or `"+line"`.
+pub type LineDiff<'a> = DiffOp<&'a str>;
+
+/// 📝 Compares two texts line by line.
+///
+/// # Arguments
+/// - `old`: The original text.
+/// - `new`: The updated text.
+///
+/// # Returns
+/// A `Vec<LineDiff>` edit script, as produced by [`diff_ops`] over the lines of both texts.
+///
+/// # Behavior
+/// - Lines are split with [`str::lines`], so `\n` and `\r\n` endings are both accepted and a
+///   trailing newline does not produce an extra empty line.
+/// - Lines are compared exactly; whitespace differences count as changes.
+/// - Pass the result to [`UnifiedDiff`] to render it as a unified diff.
+///
+/// # Performance
+/// - Inherits the cost of [`diff_ops`]: linear for shared prefixes/suffixes, **O(n × m)** for
+///   the changed region in between.
+///
+/// # Examples
+/// ```
+/// use pencil_box::array::diff_ops::DiffOp;
+/// use pencil_box::text::diff_lines::diff_lines;
+///
+/// let diff = diff_lines("port = 80\nhost = a\n", "port = 8080\nhost = a\n");
+/// assert_eq!(
+///     diff,
+///     vec![
+///         DiffOp::Delete("port = 80"),
+///         DiffOp::Insert("port = 8080"),
+///         DiffOp::Equal("host = a"),
+///     ]
+/// );
+/// ```
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<LineDiff<'a>> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    diff_ops(&old_lines, &new_lines)
+}
+
+/// 🖨️ Renders a line diff in unified diff format when displayed.
+///
+/// # Fields
+/// - `lines`: The edit script to render, usually from [`diff_lines`].
+/// - `context`: The number of unchanged lines shown around each change. `3` matches `diff -u`.
+///
+/// # Behavior
+/// - Changes closer than `2 * context` lines apart share a hunk.
+/// - Each hunk starts with a `@@ -start,count +start,count @@` header, using 1-based line numbers.
+/// - No `---`/`+++` file headers are written, so callers can add their own names.
+/// - Displays as an empty string when there are no changes.
+///
+/// # Examples
+/// ```
+/// use pencil_box::text::diff_lines::{diff_lines, UnifiedDiff};
+///
+/// let diff = diff_lines("a\nb\nc\n", "a\nB\nc\n");
+/// let unified = UnifiedDiff { lines: &diff, context: 1 };
+/// assert_eq!(unified.to_string(), "@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnifiedDiff<'a> {
+    pub lines: &'a [LineDiff<'a>],
+    pub context: usize,
+}
+
+impl fmt::Display for UnifiedDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let changes: Vec<usize> = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, op)| op.is_change())
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut group_start = 0;
+        while group_start < changes.len() {
+            let mut group_end = group_start;
+            while group_end + 1 < changes.len()
+                && changes[group_end + 1] - changes[group_end] <= 2 * self.context + 1
+            {
+                group_end += 1;
+            }
+
+            let from = changes[group_start].saturating_sub(self.context);
+            let to = (changes[group_end] + self.context + 1).min(self.lines.len());
+            self.write_hunk(f, from, to)?;
+
+            group_start = group_end + 1;
+        }
+        Ok(())
+    }
+}
+
+impl UnifiedDiff<'_> {
+    /// Writes the header and lines of the hunk covering `self.lines[from..to]`.
+    fn write_hunk(&self, f: &mut fmt::Formatter<'_>, from: usize, to: usize) -> fmt::Result {
+        let before = &self.lines[..from];
+        let hunk = &self.lines[from..to];
+
+        let old_before = before
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Insert(_)))
+            .count();
+        let new_before = before
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Delete(_)))
+            .count();
+        let old_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Insert(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Delete(_)))
+            .count();
+
+        writeln!(
+            f,
+            "@@ -{} +{} @@",
+            hunk_range(old_before, old_count),
+            hunk_range(new_before, new_count)
+        )?;
+        for op in hunk {
+            writeln!(f, "{op}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a unified diff range. Empty ranges point at the line before them, as `diff -u` does.
+fn hunk_range(lines_before: usize, count: usize) -> String {
+    if count == 0 {
+        format!("{lines_before},0")
+    } else {
+        format!("{},{count}", lines_before + 1)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod csv;
+pub mod diff_lines;
+pub mod format_table;
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::access::{head, initial, last, last_n, tail};
+
+    /// Tests `head` and `last` on a non-empty slice.
+    ///
+    /// # Expected
+    /// Returns the first and last elements respectively.
+    #[test]
+    fn test_head_and_last() {
+        let data = vec!["a", "b", "c"];
+        assert_eq!(head(&data), Some(&"a"));
+        assert_eq!(last(&data), Some(&"c"));
+    }
+
+    /// Tests `tail` and `initial` on a non-empty slice.
+    ///
+    /// # Expected
+    /// Drops the first or last element respectively.
+    #[test]
+    fn test_tail_and_initial() {
+        let data = vec![1, 2, 3, 4];
+        assert_eq!(tail(&data), &[2, 3, 4]);
+        assert_eq!(initial(&data), &[1, 2, 3]);
+    }
+
+    /// Tests every accessor on a single-element slice.
+    ///
+    /// # Expected
+    /// `head` and `last` return the element; `tail` and `initial` are empty.
+    #[test]
+    fn test_single_element() {
+        let data = [42];
+        assert_eq!(head(&data), Some(&42));
+        assert_eq!(last(&data), Some(&42));
+        assert!(tail(&data).is_empty());
+        assert!(initial(&data).is_empty());
+        assert_eq!(last_n(&data, 1), &[42]);
+    }
+
+    /// Tests every accessor on an empty slice.
+    ///
+    /// # Expected
+    /// Returns `None` or empty slices without panicking.
+    #[test]
+    fn test_empty_slice() {
+        let data: Vec<String> = vec![];
+        assert_eq!(head(&data), None);
+        assert_eq!(last(&data), None);
+        assert!(tail(&data).is_empty());
+        assert!(initial(&data).is_empty());
+        assert!(last_n(&data, 3).is_empty());
+    }
+
+    /// Tests `last_n` with zero, partial, and oversized counts.
+    ///
+    /// # Expected
+    /// Returns at most `n` trailing elements.
+    #[test]
+    fn test_last_n() {
+        let data = vec![1, 2, 3, 4, 5];
+        assert!(last_n(&data, 0).is_empty());
+        assert_eq!(last_n(&data, 2), &[4, 5]);
+        assert_eq!(last_n(&data, 10), &[1, 2, 3, 4, 5]);
+    }
+
+    /// Tests the accessors in `const` contexts.
+    ///
+    /// # Expected
+    /// Results are computed at compile time and match the runtime results.
+    #[test]
+    fn test_const_evaluation() {
+        const DATA: [u8; 4] = [1, 2, 3, 4];
+        const HEAD: Option<&u8> = head(&DATA);
+        const LAST: Option<&u8> = last(&DATA);
+        const TAIL: &[u8] = tail(&DATA);
+        const INITIAL: &[u8] = initial(&DATA);
+        const LAST_TWO: &[u8] = last_n(&DATA, 2);
+
+        assert_eq!(HEAD, Some(&1));
+        assert_eq!(LAST, Some(&4));
+        assert_eq!(TAIL, &[2, 3, 4]);
+        assert_eq!(INITIAL, &[1, 2, 3]);
+        assert_eq!(LAST_TWO, &[3, 4]);
+    }
+}
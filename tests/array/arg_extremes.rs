@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::arg_extremes::{
+        argmax, argmax_by_key, argmin, argmin_by_key, max_by_key_with_index, min_by_key_with_index,
+    };
+
+    /// Tests integer slices with a unique extreme.
+    ///
+    /// # Expected
+    /// Returns the index of the smallest and largest element.
+    #[test]
+    fn test_integers() {
+        let values = [4, -2, 7, 0];
+        assert_eq!(argmin(&values), Some(1));
+        assert_eq!(argmax(&values), Some(2));
+    }
+
+    /// Tests ties.
+    ///
+    /// # Expected
+    /// The first index of the extreme value is returned.
+    #[test]
+    fn test_ties_return_first() {
+        let values = [1, 5, 1, 5];
+        assert_eq!(argmin(&values), Some(0));
+        assert_eq!(argmax(&values), Some(1));
+        assert_eq!(argmax_by_key(&values, |v| *v), Some(1));
+        assert_eq!(argmin_by_key(&values, |v| *v), Some(0));
+    }
+
+    /// Tests floats containing `NaN`.
+    ///
+    /// # Expected
+    /// `NaN` values are skipped; all-`NaN` input returns `None`.
+    #[test]
+    fn test_nan_skipped() {
+        let values = [f64::NAN, 2.0, -1.0, f64::NAN];
+        assert_eq!(argmin(&values), Some(2));
+        assert_eq!(argmax(&values), Some(1));
+        assert_eq!(argmax(&[f64::NAN, f64::NAN]), None);
+    }
+
+    /// Tests empty input.
+    ///
+    /// # Expected
+    /// Every helper returns `None`.
+    #[test]
+    fn test_empty() {
+        let values: [u8; 0] = [];
+        assert_eq!(argmin(&values), None);
+        assert_eq!(argmax(&values), None);
+        assert_eq!(argmin_by_key(&values, |v| *v), None);
+        assert_eq!(argmax_by_key(&values, |v| *v), None);
+    }
+
+    /// Tests key-based variants on structs.
+    ///
+    /// # Expected
+    /// The key function decides which element is extreme.
+    #[test]
+    fn test_by_key() {
+        let people = vec![("ana", 31), ("bo", 25), ("cy", 40)];
+        assert_eq!(argmin_by_key(&people, |(_, age)| *age), Some(1));
+        assert_eq!(argmax_by_key(&people, |(name, _)| name.len()), Some(0));
+    }
+
+    /// Tests the `_with_index` variants.
+    ///
+    /// # Expected
+    /// Returns the first extreme position together with a reference to that element.
+    #[test]
+    fn test_with_index() {
+        let words = ["ccc", "a", "bbb", "d"];
+        assert_eq!(
+            max_by_key_with_index(&words, |w| w.len()),
+            Some((0, &"ccc"))
+        );
+        assert_eq!(min_by_key_with_index(&words, |w| w.len()), Some((1, &"a")));
+
+        let empty: [&str; 0] = [];
+        assert_eq!(max_by_key_with_index(&empty, |w| w.len()), None);
+        assert_eq!(min_by_key_with_index(&empty, |w| w.len()), None);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::assign::{assign_by_hash, assign_round_robin};
+    use pencil_box::array::stable_hash::stable_hash;
+
+    /// Tests round-robin distribution with an uneven split.
+    ///
+    /// # Expected
+    /// Items cycle through buckets and sizes differ by at most one.
+    #[test]
+    fn test_round_robin_uneven() {
+        let buckets = assign_round_robin((1..=7).collect(), 3).unwrap();
+        assert_eq!(buckets, vec![vec![1, 4, 7], vec![2, 5], vec![3, 6]]);
+    }
+
+    /// Tests round-robin with more workers than items.
+    ///
+    /// # Expected
+    /// Extra buckets are present but empty.
+    #[test]
+    fn test_round_robin_more_workers_than_items() {
+        let buckets = assign_round_robin(vec!["x".to_string()], 3).unwrap();
+        assert_eq!(buckets, vec![vec!["x".to_string()], vec![], vec![]]);
+    }
+
+    /// Tests a worker count of zero for both strategies.
+    ///
+    /// # Expected
+    /// Returns an error.
+    #[test]
+    fn test_zero_workers() {
+        assert!(assign_round_robin(vec![1, 2], 0).is_err());
+        assert!(assign_by_hash(vec![1, 2], 0, |v| *v).is_err());
+    }
+
+    /// Tests that equal keys always share a bucket.
+    ///
+    /// # Expected
+    /// Every item with the same key ends up in the same bucket, and nothing is lost.
+    #[test]
+    fn test_hash_groups_equal_keys() {
+        let items: Vec<(u32, u32)> = (0..200).map(|i| (i % 13, i)).collect();
+        let buckets = assign_by_hash(items, 5, |(key, _)| *key).unwrap();
+
+        assert_eq!(buckets.len(), 5);
+        assert_eq!(buckets.iter().map(Vec::len).sum::<usize>(), 200);
+        for key in 0..13 {
+            let holders = buckets
+                .iter()
+                .filter(|bucket| bucket.iter().any(|(k, _)| *k == key))
+                .count();
+            assert_eq!(holders, 1);
+        }
+    }
+
+    /// Tests that hash assignment is deterministic and order-preserving.
+    ///
+    /// # Expected
+    /// Two calls agree and items keep their input order within a bucket.
+    #[test]
+    fn test_hash_deterministic() {
+        let items: Vec<String> = (0..50).map(|i| format!("job-{i}")).collect();
+        let first = assign_by_hash(items.clone(), 4, |s| s.clone()).unwrap();
+        let second = assign_by_hash(items.clone(), 4, |s| s.clone()).unwrap();
+        assert_eq!(first, second);
+
+        for bucket in &first {
+            let positions: Vec<usize> = bucket
+                .iter()
+                .map(|job| items.iter().position(|i| i == job).unwrap())
+                .collect();
+            assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    /// Tests that buckets follow the release-stable hash.
+    ///
+    /// # Expected
+    /// Each item lands in `stable_hash(key) % worker_count`.
+    #[test]
+    fn test_hash_uses_stable_hash() {
+        let items: Vec<u32> = (0..100).collect();
+        let buckets = assign_by_hash(items, 7, |v| *v).unwrap();
+        for (index, bucket) in buckets.iter().enumerate() {
+            assert!(bucket
+                .iter()
+                .all(|v| (stable_hash(v) % 7) as usize == index));
+        }
+    }
+}
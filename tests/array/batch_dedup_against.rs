@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::batch_dedup_against::{filter_new, SeenSet};
+
+    /// Tests deduplication across several batches.
+    ///
+    /// # Expected
+    /// Only values never seen in earlier batches are returned.
+    #[test]
+    fn test_across_batches() {
+        let mut seen = SeenSet::new();
+        assert_eq!(filter_new(&mut seen, vec![1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(filter_new(&mut seen, vec![3, 4]), vec![4]);
+        assert_eq!(filter_new(&mut seen, vec![1, 2, 3, 4]), Vec::<i32>::new());
+        assert_eq!(seen.len(), 4);
+    }
+
+    /// Tests duplicates inside a single batch.
+    ///
+    /// # Expected
+    /// The first occurrence is kept and batch order is preserved.
+    #[test]
+    fn test_within_batch() {
+        let mut seen = SeenSet::new();
+        let batch = vec!["b".to_string(), "a".to_string(), "b".to_string()];
+        assert_eq!(filter_new(&mut seen, batch), vec!["b", "a"]);
+    }
+
+    /// Tests values inserted into the set manually.
+    ///
+    /// # Expected
+    /// Pre-seeded values are filtered out of the first batch.
+    #[test]
+    fn test_pre_seeded() {
+        let mut seen = SeenSet::with_capacity(8);
+        assert!(seen.insert(5));
+        assert!(!seen.insert(5));
+        assert!(seen.contains(&5));
+        assert_eq!(filter_new(&mut seen, 4..7), vec![4, 6]);
+    }
+
+    /// Tests clearing the set and empty batches.
+    ///
+    /// # Expected
+    /// After `clear`, previously seen values are new again.
+    #[test]
+    fn test_clear_and_empty() {
+        let mut seen = SeenSet::default();
+        assert!(filter_new(&mut seen, Vec::<u8>::new()).is_empty());
+        assert!(seen.is_empty());
+
+        filter_new(&mut seen, [1u8, 2]);
+        seen.clear();
+        assert_eq!(filter_new(&mut seen, [2u8]), vec![2]);
+    }
+}
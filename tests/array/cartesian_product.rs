@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::cartesian_product::{
+        cartesian_power, cartesian_power_iter, cartesian_product, cartesian_product_all,
+    };
+
+    /// Tests the product of two collections of different types.
+    ///
+    /// # Expected
+    /// Every pair appears once, ordered by the left collection first.
+    #[test]
+    fn test_pairs() {
+        let pairs = cartesian_product(&[1, 2, 3], &['a', 'b']);
+        assert_eq!(
+            pairs,
+            vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (3, 'a'), (3, 'b')]
+        );
+    }
+
+    /// Tests an empty side.
+    ///
+    /// # Expected
+    /// The product is empty.
+    #[test]
+    fn test_pairs_empty() {
+        assert!(cartesian_product::<i32, u8>(&[1, 2], &[]).is_empty());
+        assert!(cartesian_product::<i32, u8>(&[], &[1]).is_empty());
+    }
+
+    /// Tests the product of several collections.
+    ///
+    /// # Expected
+    /// Lexicographic order with the last collection varying fastest.
+    #[test]
+    fn test_all_order() {
+        let combos = cartesian_product_all(&[vec![0, 1], vec![0, 1], vec![0, 1]]);
+        let as_numbers: Vec<i32> = combos
+            .iter()
+            .map(|bits| bits.iter().fold(0, |n, bit| n * 2 + bit))
+            .collect();
+        assert_eq!(as_numbers, (0..8).collect::<Vec<_>>());
+    }
+
+    /// Tests slices of different lengths and borrowed inputs.
+    ///
+    /// # Expected
+    /// The result size is the product of the lengths.
+    #[test]
+    fn test_all_mixed_lengths() {
+        let sizes = ["s", "m", "l"];
+        let colors = ["red"];
+        let combos = cartesian_product_all(&[&sizes[..], &colors[..]]);
+        assert_eq!(
+            combos,
+            vec![vec!["s", "red"], vec!["m", "red"], vec!["l", "red"]]
+        );
+    }
+
+    /// Tests empty inputs.
+    ///
+    /// # Expected
+    /// An empty collection empties the product; no collections yield one empty combination.
+    #[test]
+    fn test_all_empty() {
+        let with_empty: Vec<Vec<u8>> = vec![vec![1, 2], vec![]];
+        assert!(cartesian_product_all(&with_empty).is_empty());
+
+        let none: Vec<Vec<u8>> = vec![];
+        assert_eq!(cartesian_product_all(&none), vec![Vec::<u8>::new()]);
+    }
+
+    /// Tests that `cartesian_power` matches `cartesian_product_all` with repeated sets.
+    ///
+    /// # Expected
+    /// Both produce the same tuples in the same order.
+    #[test]
+    fn test_power_matches_product_all() {
+        let alphabet = ['x', 'y', 'z'];
+        let expected = cartesian_product_all(&[alphabet, alphabet, alphabet]);
+        assert_eq!(cartesian_power(&alphabet, 3), expected);
+        assert_eq!(expected.len(), 27);
+    }
+
+    /// Tests the `k == 0` and empty-alphabet edge cases.
+    ///
+    /// # Expected
+    /// `k == 0` gives one empty tuple; an empty alphabet with `k > 0` gives none.
+    #[test]
+    fn test_power_edges() {
+        assert_eq!(cartesian_power(&[1, 2], 0), vec![Vec::<i32>::new()]);
+        assert!(cartesian_power::<i32>(&[], 2).is_empty());
+        assert_eq!(cartesian_power(&[7], 3), vec![vec![7, 7, 7]]);
+        assert_eq!(cartesian_power_iter(&[7], usize::MAX).total(), Some(1));
+    }
+
+    /// Tests index decoding against iteration order.
+    ///
+    /// # Expected
+    /// `nth_product(i)` equals the `i`-th item yielded, and out-of-range indices give `None`.
+    #[test]
+    fn test_nth_product_matches_iteration() {
+        let power = cartesian_power_iter(&[0, 1, 2], 4);
+        assert_eq!(power.total(), Some(81));
+        for (index, tuple) in power.clone().enumerate() {
+            assert_eq!(power.nth_product(index), Some(tuple));
+        }
+        assert_eq!(power.nth_product(81), None);
+    }
+
+    /// Tests skipping with `nth` and the size hint.
+    ///
+    /// # Expected
+    /// `nth` jumps directly; the size hint tracks what is left and the iterator is fused.
+    #[test]
+    fn test_iter_skip_and_size_hint() {
+        let mut power = cartesian_power_iter(&['a', 'b'], 3);
+        assert_eq!(power.size_hint(), (8, Some(8)));
+        assert_eq!(power.nth(5), Some(vec!['b', 'a', 'b']));
+        assert_eq!(power.size_hint(), (2, Some(2)));
+        assert_eq!(power.by_ref().count(), 2);
+        assert_eq!(power.next(), None);
+        assert_eq!(power.nth(3), None);
+    }
+
+    /// Tests a product space too large to count in a `usize`.
+    ///
+    /// # Expected
+    /// `total` is `None`, yet any index can still be decoded lazily.
+    #[test]
+    fn test_power_huge_space() {
+        let power = cartesian_power_iter(&[0u8, 1], 200);
+        assert_eq!(power.total(), None);
+        let tuple = power.nth_product(5).unwrap();
+        assert_eq!(tuple.len(), 200);
+        assert_eq!(&tuple[197..], &[1, 0, 1]);
+        assert!(tuple[..197].iter().all(|&bit| bit == 0));
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::change_points::change_points;
+
+    /// ✅ Detects every transition between differing neighbours.
+    /// Expects the index where each new run starts.
+    #[test]
+    fn test_inequality_transitions() {
+        let values = [1, 1, 2, 2, 2, 3, 1];
+        assert_eq!(change_points(&values, |a, b| a != b), vec![2, 5, 6]);
+    }
+
+    /// ✅ No transitions.
+    /// Expects an empty vector.
+    #[test]
+    fn test_constant_sequence() {
+        let values = [7, 7, 7];
+        assert!(change_points(&values, |a, b| a != b).is_empty());
+    }
+
+    /// ✅ Empty and single-element slices.
+    /// Expects an empty vector and no comparator calls.
+    #[test]
+    fn test_short_inputs() {
+        let empty: [i32; 0] = [];
+        assert!(change_points(&empty, |_, _| panic!("not called")).is_empty());
+        assert!(change_points(&[1], |_, _| panic!("not called")).is_empty());
+    }
+
+    /// ✅ Comparator sees pairs in `(previous, current)` order.
+    /// Expects only upward steps to be reported.
+    #[test]
+    fn test_directional_comparator() {
+        let values = [3, 5, 4, 4, 9];
+        assert_eq!(change_points(&values, |prev, next| next > prev), vec![1, 4]);
+    }
+
+    /// ✅ Works with structs, comparing on a single field.
+    /// Expects transitions when the `status` field changes.
+    #[test]
+    fn test_struct_field_transitions() {
+        #[derive(Debug)]
+        struct Event {
+            status: &'static str,
+            at: u32,
+        }
+
+        let events = [
+            Event { status: "up", at: 1 },
+            Event { status: "up", at: 2 },
+            Event { status: "down", at: 3 },
+            Event { status: "up", at: 4 },
+        ];
+        let changes = change_points(&events, |a, b| a.status != b.status);
+        assert_eq!(changes, vec![2, 3]);
+        assert_eq!(events[changes[0]].at, 3);
+    }
+}
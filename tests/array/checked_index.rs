@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::checked_index::{try_insert_at, try_replace_at, IndexError};
+
+    /// Tests inserting at the start, middle, and end.
+    ///
+    /// # Expected
+    /// Every index up to and including the length is accepted.
+    #[test]
+    fn test_insert_valid_indices() {
+        let mut data = vec![2, 4];
+        try_insert_at(&mut data, 0, 1).unwrap();
+        try_insert_at(&mut data, 2, 3).unwrap();
+        try_insert_at(&mut data, 4, 5).unwrap();
+        assert_eq!(data, vec![1, 2, 3, 4, 5]);
+    }
+
+    /// Tests inserting past the end.
+    ///
+    /// # Expected
+    /// An error with the index, length, and the rejected value; the vector is unchanged.
+    #[test]
+    fn test_insert_out_of_bounds() {
+        let mut data = vec![String::from("a")];
+        let error = try_insert_at(&mut data, 2, String::from("b")).unwrap_err();
+        assert_eq!(
+            error,
+            IndexError {
+                index: 2,
+                len: 1,
+                value: String::from("b")
+            }
+        );
+        assert_eq!(error.to_string(), "index 2 is out of bounds for length 1");
+        assert_eq!(data, vec!["a"]);
+    }
+
+    /// Tests replacing an existing element.
+    ///
+    /// # Expected
+    /// The old element is returned and the new one is in place.
+    #[test]
+    fn test_replace_valid_index() {
+        let mut data = vec!['x', 'y', 'z'];
+        assert_eq!(try_replace_at(&mut data, 2, 'w'), Ok('z'));
+        assert_eq!(data, vec!['x', 'y', 'w']);
+    }
+
+    /// Tests replacing at or past the end.
+    ///
+    /// # Expected
+    /// The length itself is out of bounds for replacement.
+    #[test]
+    fn test_replace_out_of_bounds() {
+        let mut data = vec![1, 2];
+        let error = try_replace_at(&mut data, 2, 9).unwrap_err();
+        assert_eq!((error.index, error.len, error.value), (2, 2, 9));
+
+        let mut empty: Vec<u8> = vec![];
+        assert!(try_replace_at(&mut empty, 0, 1).is_err());
+        assert!(try_insert_at(&mut empty, 0, 1).is_ok());
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::chunk::chunk;
+    use pencil_box::array::chunk::{chunk, chunk_with_ranges};
 
     /// Tests chunking a primitive `Vec<i32>` into even-sized groups.
     ///
@@ -159,4 +159,35 @@ mod tests {
             result
         );
     }
+
+    /// Tests that `chunk_with_ranges` returns the same chunks as `chunk`.
+    ///
+    /// # Expected
+    /// Each chunk equals the slice at its range, and the ranges cover the input.
+    #[test]
+    fn test_with_ranges_matches_chunk() {
+        let data: Vec<u32> = (0..11).collect();
+        let with_ranges = chunk_with_ranges(&data, 4).unwrap();
+        let plain = chunk(&data, 4).unwrap();
+        assert_eq!(with_ranges.len(), plain.len());
+        for ((range, values), expected) in with_ranges.iter().zip(&plain) {
+            assert_eq!(values, expected);
+            assert_eq!(&data[range.clone()], values.as_slice());
+        }
+        assert_eq!(with_ranges.first().unwrap().0.start, 0);
+        assert_eq!(with_ranges.last().unwrap().0, 8..11);
+    }
+
+    /// Tests `chunk_with_ranges` on empty input and a zero chunk size.
+    ///
+    /// # Expected
+    /// Empty input gives no chunks; a zero chunk size is an error.
+    #[test]
+    fn test_with_ranges_edge_cases() {
+        assert!(chunk_with_ranges::<u8>(&[], 3).unwrap().is_empty());
+        assert_eq!(
+            chunk_with_ranges(&[1, 2], 0),
+            Err("chunk_size must be greater than 0")
+        );
+    }
 }
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::chunk_transpose::chunk_transpose;
+
+    /// Tests transposing three columns into batches.
+    ///
+    /// # Expected
+    /// Full batches followed by a shorter final batch.
+    #[test]
+    fn test_batches_rows() {
+        let columns = vec![vec![1, 2, 3, 4, 5], vec![10, 20, 30, 40, 50], vec![7; 5]];
+        let batches = chunk_transpose(&columns, 2).unwrap();
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0], vec![vec![1, 10, 7], vec![2, 20, 7]]);
+        assert_eq!(batches[2], vec![vec![5, 50, 7]]);
+    }
+
+    /// Tests a batch size larger than the number of rows.
+    ///
+    /// # Expected
+    /// A single batch with every row.
+    #[test]
+    fn test_single_batch() {
+        let columns = vec![vec!["a".to_string(), "b".to_string()]];
+        let batches = chunk_transpose(&columns, 100).unwrap();
+        assert_eq!(batches, vec![vec![vec!["a"], vec!["b"]]]);
+    }
+
+    /// Tests empty inputs.
+    ///
+    /// # Expected
+    /// No columns or zero rows yield no batches.
+    #[test]
+    fn test_empty() {
+        let none: Vec<Vec<u8>> = vec![];
+        assert_eq!(chunk_transpose(&none, 3), Ok(vec![]));
+
+        let no_rows: Vec<Vec<u8>> = vec![vec![], vec![]];
+        assert_eq!(chunk_transpose(&no_rows, 3), Ok(vec![]));
+    }
+
+    /// Tests invalid arguments.
+    ///
+    /// # Expected
+    /// Ragged columns and a zero batch size are errors.
+    #[test]
+    fn test_invalid_arguments() {
+        let ragged = vec![vec![1, 2], vec![1]];
+        assert_eq!(
+            chunk_transpose(&ragged, 1),
+            Err("all columns must have the same length")
+        );
+        assert!(chunk_transpose(&[vec![1]], 0).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::clamp_len::clamp_len;
+
+    /// Tests a vector longer than `max`.
+    ///
+    /// # Expected
+    /// The vector is truncated to `max` elements.
+    #[test]
+    fn test_truncates_above_max() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        clamp_len(&mut data, 1, 3, 0).unwrap();
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests a vector shorter than `min`.
+    ///
+    /// # Expected
+    /// The vector is padded with `pad_value` up to `min` elements.
+    #[test]
+    fn test_pads_below_min() {
+        let mut data = vec!["a".to_string()];
+        clamp_len(&mut data, 3, 5, "-".to_string()).unwrap();
+        assert_eq!(data, vec!["a", "-", "-"]);
+    }
+
+    /// Tests a vector already within bounds, including both edges.
+    ///
+    /// # Expected
+    /// The vector remains unchanged.
+    #[test]
+    fn test_within_bounds_unchanged() {
+        for len in 2..=4 {
+            let mut data: Vec<usize> = (0..len).collect();
+            clamp_len(&mut data, 2, 4, 99).unwrap();
+            assert_eq!(data, (0..len).collect::<Vec<_>>());
+        }
+    }
+
+    /// Tests `min == max` as a fixed-size field.
+    ///
+    /// # Expected
+    /// Both short and long inputs end up with exactly that length.
+    #[test]
+    fn test_fixed_size() {
+        let mut short = vec![1];
+        clamp_len(&mut short, 3, 3, 0).unwrap();
+        assert_eq!(short, vec![1, 0, 0]);
+
+        let mut long = vec![1, 2, 3, 4];
+        clamp_len(&mut long, 3, 3, 0).unwrap();
+        assert_eq!(long, vec![1, 2, 3]);
+    }
+
+    /// Tests an empty vector with `min == 0` and with a positive `min`.
+    ///
+    /// # Expected
+    /// Stays empty, or is filled entirely with `pad_value`.
+    #[test]
+    fn test_empty_vector() {
+        let mut data: Vec<i32> = vec![];
+        clamp_len(&mut data, 0, 10, 7).unwrap();
+        assert!(data.is_empty());
+
+        clamp_len(&mut data, 2, 10, 7).unwrap();
+        assert_eq!(data, vec![7, 7]);
+    }
+
+    /// Tests invalid bounds where `min > max`.
+    ///
+    /// # Expected
+    /// Returns an error and leaves the vector untouched.
+    #[test]
+    fn test_invalid_bounds() {
+        let mut data = vec![1, 2];
+        assert!(clamp_len(&mut data, 3, 1, 0).is_err());
+        assert_eq!(data, vec![1, 2]);
+    }
+}
@@ -1,7 +1,17 @@
 #[cfg(test)]
 mod tests {
     use pencil_box::array::compact::compact;
+    use pencil_box::array::compact::compact_by;
+    use pencil_box::array::compact::compact_finite;
+    use pencil_box::array::compact::compact_options;
+    use pencil_box::array::compact::compacted;
     use pencil_box::array::compact::IsEmpty;
+    use std::borrow::Cow;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+    use std::ffi::{OsStr, OsString};
+    use std::path::{Path, PathBuf};
+    use std::rc::Rc;
+    use std::sync::Arc;
 
     // --- Direct IsEmpty Trait Implementation Tests ---
 
@@ -93,6 +103,164 @@ mod tests {
         assert!(!3.14_f64.is_empty());
     }
 
+    /// Tests `IsEmpty` for std collections (`VecDeque`, `HashMap`, `HashSet`, `BTreeMap`, `BTreeSet`).
+    ///
+    /// # Expected
+    /// A collection with no elements is empty; with any elements, it's not.
+    #[test]
+    fn test_std_collections_is_empty_impls() {
+        assert!(IsEmpty::is_empty(&VecDeque::<i32>::new()));
+        assert!(!IsEmpty::is_empty(&VecDeque::from(vec![0])));
+
+        assert!(IsEmpty::is_empty(&HashMap::<&str, i32>::new()));
+        assert!(!IsEmpty::is_empty(&HashMap::from([("a", 0)])));
+
+        assert!(IsEmpty::is_empty(&HashSet::<i32>::new()));
+        assert!(!IsEmpty::is_empty(&HashSet::from([0])));
+
+        assert!(IsEmpty::is_empty(&BTreeMap::<i32, i32>::new()));
+        assert!(!IsEmpty::is_empty(&BTreeMap::from([(1, 0)])));
+
+        assert!(IsEmpty::is_empty(&BTreeSet::<i32>::new()));
+        assert!(!IsEmpty::is_empty(&BTreeSet::from([1])));
+    }
+
+    /// Tests `IsEmpty` for fixed-size arrays and slices.
+    ///
+    /// # Expected
+    /// Only zero-length arrays and slices are empty, regardless of their contents.
+    #[test]
+    fn test_array_and_slice_is_empty_impls() {
+        let empty_array: [i32; 0] = [];
+        assert!(IsEmpty::is_empty(&empty_array));
+        assert!(!IsEmpty::is_empty(&[0, 0]));
+
+        let empty_slice: &[i32] = &[];
+        assert!(IsEmpty::is_empty(&empty_slice));
+        let zeros: &[i32] = &[0];
+        assert!(!IsEmpty::is_empty(&zeros));
+    }
+
+    /// Tests `compact` on vectors of maps and sets.
+    ///
+    /// # Expected
+    /// Removes empty collections and keeps non-empty ones in order.
+    #[test]
+    fn test_compact_maps_and_sets() {
+        let mut maps = vec![
+            HashMap::from([("k", 1)]),
+            HashMap::new(),
+            HashMap::from([("j", 2)]),
+        ];
+        compact(&mut maps);
+        assert_eq!(maps, vec![HashMap::from([("k", 1)]), HashMap::from([("j", 2)])]);
+
+        let mut sets = vec![BTreeSet::new(), BTreeSet::from([3]), BTreeSet::new()];
+        compact(&mut sets);
+        assert_eq!(sets, vec![BTreeSet::from([3])]);
+
+        let mut slices: Vec<&[u8]> = vec![b"", b"abc", b""];
+        compact(&mut slices);
+        assert_eq!(slices, vec![&b"abc"[..]]);
+    }
+
+    /// Tests `IsEmpty` for references, boxes, `Rc`, `Arc`, and `Cow`.
+    ///
+    /// # Expected
+    /// Each wrapper delegates to the emptiness of the value it points to.
+    #[test]
+    fn test_smart_pointer_is_empty_impls() {
+        let zero = 0;
+        assert!(IsEmpty::is_empty(&&zero));
+        assert!(!IsEmpty::is_empty(&&String::from("x")));
+
+        assert!(IsEmpty::is_empty(&Box::new(String::new())));
+        let boxed_str: Box<str> = "hi".into();
+        assert!(!IsEmpty::is_empty(&boxed_str));
+
+        assert!(IsEmpty::is_empty(&Rc::new(Vec::<i32>::new())));
+        let rc_slice: Rc<[i32]> = Rc::from(vec![1]);
+        assert!(!IsEmpty::is_empty(&rc_slice));
+
+        assert!(IsEmpty::is_empty(&Arc::new(false)));
+        assert!(!IsEmpty::is_empty(&Arc::new(7_u8)));
+
+        let borrowed: Cow<str> = Cow::Borrowed("");
+        let owned: Cow<str> = Cow::Owned("abc".to_string());
+        assert!(IsEmpty::is_empty(&borrowed));
+        assert!(!IsEmpty::is_empty(&owned));
+    }
+
+    /// Tests `compact` on vectors of borrowed and shared values.
+    ///
+    /// # Expected
+    /// Removes elements whose pointed-to value is empty, without cloning into owned types.
+    #[test]
+    fn test_compact_borrowed_and_shared() {
+        let owned = ["a".to_string(), String::new(), "b".to_string()];
+        let mut refs: Vec<&String> = owned.iter().collect();
+        compact(&mut refs);
+        assert_eq!(refs, vec![&owned[0], &owned[2]]);
+
+        let mut shared: Vec<Arc<str>> = vec!["x".into(), "".into(), "y".into()];
+        compact(&mut shared);
+        assert_eq!(shared, vec![Arc::<str>::from("x"), Arc::<str>::from("y")]);
+
+        let mut cows: Vec<Cow<[u8]>> = vec![Cow::Borrowed(&[]), Cow::Owned(vec![1, 2])];
+        compact(&mut cows);
+        assert_eq!(cows, vec![Cow::<[u8]>::Owned(vec![1, 2])]);
+    }
+
+    /// Tests `IsEmpty` for paths and OS strings.
+    ///
+    /// # Expected
+    /// Only values with no characters are empty; `"."` and `"/"` are not.
+    #[test]
+    fn test_path_and_os_string_is_empty_impls() {
+        assert!(IsEmpty::is_empty(&PathBuf::new()));
+        assert!(!IsEmpty::is_empty(&PathBuf::from(".")));
+        assert!(IsEmpty::is_empty(&Path::new("")));
+        assert!(!IsEmpty::is_empty(&Path::new("/")));
+
+        assert!(IsEmpty::is_empty(&OsString::new()));
+        assert!(!IsEmpty::is_empty(&OsString::from("x")));
+        assert!(IsEmpty::is_empty(&OsStr::new("")));
+        assert!(!IsEmpty::is_empty(&OsStr::new("y")));
+    }
+
+    /// Tests `IsEmpty` for `char`.
+    ///
+    /// # Expected
+    /// NUL and whitespace characters are empty; everything else is not.
+    #[test]
+    fn test_char_is_empty_impl() {
+        for c in ['\0', ' ', '\t', '\n', '\u{3000}'] {
+            assert!(IsEmpty::is_empty(&c), "{:?} should be empty", c);
+        }
+        for c in ['a', '0', '_', 'é'] {
+            assert!(!IsEmpty::is_empty(&c), "{:?} should not be empty", c);
+        }
+    }
+
+    /// Tests `compact` on paths collected from a directory walk and on chars.
+    ///
+    /// # Expected
+    /// Removes empty paths and blank characters.
+    #[test]
+    fn test_compact_paths_and_chars() {
+        let mut paths = vec![PathBuf::from("a.txt"), PathBuf::new(), PathBuf::from("b/c")];
+        compact(&mut paths);
+        assert_eq!(paths, vec![PathBuf::from("a.txt"), PathBuf::from("b/c")]);
+
+        let mut borrowed: Vec<&Path> = vec![Path::new(""), Path::new("x")];
+        compact(&mut borrowed);
+        assert_eq!(borrowed, vec![Path::new("x")]);
+
+        let mut chars: Vec<char> = "a b\0c".chars().collect();
+        compact(&mut chars);
+        assert_eq!(chars, vec!['a', 'b', 'c']);
+    }
+
     // --- Compact Function Tests ---
 
     /// Tests `compact` on integers, including zeros.
@@ -285,4 +453,323 @@ mod tests {
         compact(&mut v);
         assert_eq!(v, vec![vec![1, 2], vec![0, 0], vec![3]]);
     }
+
+    // --- Compact Finite Function Tests ---
+
+    /// Tests `compact_finite` on `f64` values polluted with `NaN` and infinities.
+    ///
+    /// # Expected
+    /// Removes zeros, `NaN`, and both infinities; keeps finite non-zero values in order.
+    #[test]
+    fn test_compact_finite_f64() {
+        let mut v = vec![
+            f64::NAN,
+            1.0,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            2.5,
+            f64::NEG_INFINITY,
+            f64::MIN_POSITIVE,
+        ];
+        compact_finite(&mut v);
+        assert_eq!(v, vec![1.0, 2.5, f64::MIN_POSITIVE]);
+    }
+
+    /// Tests `compact_finite` on `f32` values.
+    ///
+    /// # Expected
+    /// Behaves the same as for `f64`, including `f32::MAX` being kept.
+    #[test]
+    fn test_compact_finite_f32() {
+        let mut v: Vec<f32> = vec![f32::MAX, f32::NAN, 0.0, -1.0, f32::INFINITY];
+        compact_finite(&mut v);
+        assert_eq!(v, vec![f32::MAX, -1.0]);
+    }
+
+    /// Tests `compact_finite` on integers and empty input.
+    ///
+    /// # Expected
+    /// Integers are always finite, so only zeros are removed; empty input stays empty.
+    #[test]
+    fn test_compact_finite_integers_and_empty() {
+        let mut ints: Vec<i32> = vec![0, 5, -3, 0];
+        compact_finite(&mut ints);
+        assert_eq!(ints, vec![5, -3]);
+
+        let mut empty: Vec<f64> = vec![];
+        compact_finite(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    // --- Compacted Function Tests ---
+
+    /// Tests `compacted` on integers.
+    ///
+    /// # Expected
+    /// Returns non-zero values and leaves the input unchanged.
+    #[test]
+    fn test_compacted_preserves_input() {
+        let original = vec![1, 0, 2, 0, 3];
+        let result = compacted(&original);
+        assert_eq!(result, vec![1, 2, 3]);
+        assert_eq!(original, vec![1, 0, 2, 0, 3]);
+    }
+
+    /// Tests `compacted` on owned `String`s and `Option`s.
+    ///
+    /// # Expected
+    /// Uses the same emptiness rules as `compact`.
+    #[test]
+    fn test_compacted_strings_and_options() {
+        let strings = vec!["x".to_string(), "".to_string()];
+        assert_eq!(compacted(&strings), vec!["x"]);
+
+        let options = [Some(1), None, Some(0), Some(2)];
+        assert_eq!(compacted(&options), vec![Some(1), Some(2)]);
+    }
+
+    /// Tests `compacted` on empty and all-empty inputs.
+    ///
+    /// # Expected
+    /// Returns an empty vector in both cases.
+    #[test]
+    fn test_compacted_empty_results() {
+        let empty: [i32; 0] = [];
+        assert!(compacted(&empty).is_empty());
+
+        let all_empty = [false, false];
+        assert!(compacted(&all_empty).is_empty());
+    }
+
+    // --- Compact By Function Tests ---
+
+    /// Tests `compact_by` treating whitespace-only strings as empty.
+    ///
+    /// # Expected
+    /// Removes empty and whitespace-only strings, keeps the rest in order.
+    #[test]
+    fn test_compact_by_whitespace_strings() {
+        let mut v = vec![
+            "a".to_string(),
+            " \t".to_string(),
+            "".to_string(),
+            " b ".to_string(),
+        ];
+        compact_by(&mut v, |s| s.trim().is_empty());
+        assert_eq!(v, vec!["a", " b "]);
+    }
+
+    /// Tests `compact_by` with a sentinel value.
+    ///
+    /// # Expected
+    /// Removes only `-1`; zeros are kept because the predicate overrides `IsEmpty`.
+    #[test]
+    fn test_compact_by_sentinel() {
+        let mut v = vec![-1, 0, 3, -1, 5];
+        compact_by(&mut v, |x| *x == -1);
+        assert_eq!(v, vec![0, 3, 5]);
+    }
+
+    /// Tests `compact_by` on a type that does not implement `IsEmpty`.
+    ///
+    /// # Expected
+    /// Works with any element type.
+    #[test]
+    fn test_compact_by_custom_struct() {
+        #[derive(Debug, PartialEq)]
+        struct Reading {
+            value: Option<u32>,
+        }
+
+        let mut v = vec![
+            Reading { value: Some(1) },
+            Reading { value: None },
+            Reading { value: Some(2) },
+        ];
+        compact_by(&mut v, |r| r.value.is_none());
+        assert_eq!(v, vec![Reading { value: Some(1) }, Reading { value: Some(2) }]);
+    }
+
+    /// Tests `compact_by` on an empty vector and with a predicate that never matches.
+    ///
+    /// # Expected
+    /// Empty input stays empty; non-matching input is unchanged.
+    #[test]
+    fn test_compact_by_no_op() {
+        let mut empty: Vec<i32> = vec![];
+        compact_by(&mut empty, |_| true);
+        assert!(empty.is_empty());
+
+        let mut v = vec![1, 2, 3];
+        compact_by(&mut v, |_| false);
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+
+    // --- Compact Options Function Tests ---
+
+    /// Tests `compact_options` keeps `Some` values that `IsEmpty` would treat as empty.
+    ///
+    /// # Expected
+    /// Only `None` is removed; `Some(0)` survives and is unwrapped.
+    #[test]
+    fn test_compact_options_keeps_some_zero() {
+        let v = vec![Some(0), None, Some(5), None, Some(0)];
+        assert_eq!(compact_options(v), vec![0, 5, 0]);
+    }
+
+    /// Tests `compact_options` with a non-`Clone` inner type.
+    ///
+    /// # Expected
+    /// Values are moved out in order.
+    #[test]
+    fn test_compact_options_moves_values() {
+        #[derive(Debug, PartialEq)]
+        struct Token(u8);
+
+        let v = vec![None, Some(Token(1)), Some(Token(2))];
+        assert_eq!(compact_options(v), vec![Token(1), Token(2)]);
+    }
+
+    /// Tests `compact_options` on empty and all-`None` inputs.
+    ///
+    /// # Expected
+    /// Returns an empty vector.
+    #[test]
+    fn test_compact_options_empty_results() {
+        let empty: Vec<Option<i32>> = vec![];
+        assert!(compact_options(empty).is_empty());
+
+        let all_none: Vec<Option<String>> = vec![None, None];
+        assert!(compact_options(all_none).is_empty());
+    }
+
+    // --- Derive Macro Tests ---
+
+    /// Tests `#[derive(IsEmpty)]` on a struct with named fields.
+    ///
+    /// # Expected
+    /// The struct is empty only when every field is empty.
+    #[test]
+    fn test_derive_named_struct() {
+        #[derive(pencil_box_derive::IsEmpty)]
+        struct Contact {
+            name: String,
+            tags: Vec<String>,
+            score: Option<u32>,
+        }
+
+        let empty = Contact {
+            name: String::new(),
+            tags: vec![],
+            score: None,
+        };
+        let named = Contact {
+            name: "Ada".into(),
+            tags: vec![],
+            score: None,
+        };
+        let scored = Contact {
+            name: String::new(),
+            tags: vec![],
+            score: Some(3),
+        };
+        assert!(IsEmpty::is_empty(&empty));
+        assert!(!IsEmpty::is_empty(&named));
+        assert!(!IsEmpty::is_empty(&scored));
+    }
+
+    /// Tests `#[is_empty(skip)]` on fields, including a type without an `IsEmpty` impl.
+    ///
+    /// # Expected
+    /// Skipped fields do not influence emptiness.
+    #[test]
+    fn test_derive_skip_attribute() {
+        struct Metadata;
+
+        #[derive(pencil_box_derive::IsEmpty)]
+        struct Draft {
+            body: String,
+            #[is_empty(skip)]
+            revision: u32,
+            #[is_empty(skip)]
+            _meta: Metadata,
+        }
+
+        let draft = Draft {
+            body: String::new(),
+            revision: 7,
+            _meta: Metadata,
+        };
+        assert!(IsEmpty::is_empty(&draft));
+        assert_eq!(draft.revision, 7);
+    }
+
+    /// Tests `#[derive(IsEmpty)]` on tuple, unit, and generic structs.
+    ///
+    /// # Expected
+    /// Tuple fields are checked by position, unit structs are always empty,
+    /// and generic fields use the inner type's `IsEmpty` impl.
+    #[test]
+    fn test_derive_tuple_unit_and_generic() {
+        #[derive(pencil_box_derive::IsEmpty)]
+        struct Pair(i32, &'static str);
+
+        #[derive(pencil_box_derive::IsEmpty)]
+        struct Marker;
+
+        #[derive(pencil_box_derive::IsEmpty)]
+        struct Wrapper<T> {
+            inner: T,
+        }
+
+        assert!(IsEmpty::is_empty(&Pair(0, "")));
+        assert!(!IsEmpty::is_empty(&Pair(0, "x")));
+        assert!(IsEmpty::is_empty(&Marker));
+        assert!(IsEmpty::is_empty(&Wrapper { inner: vec![0u8; 0] }));
+        assert!(!IsEmpty::is_empty(&Wrapper { inner: 1.5 }));
+    }
+
+    /// Tests `compact` on a vector of derived structs.
+    ///
+    /// # Expected
+    /// Removes structs whose fields are all empty.
+    #[test]
+    fn test_compact_derived_structs() {
+        #[derive(Debug, PartialEq, pencil_box_derive::IsEmpty)]
+        struct Row {
+            key: String,
+            value: Option<String>,
+        }
+
+        let mut rows = vec![
+            Row {
+                key: "a".into(),
+                value: None,
+            },
+            Row {
+                key: String::new(),
+                value: Some(String::new()),
+            },
+            Row {
+                key: String::new(),
+                value: Some("v".into()),
+            },
+        ];
+        compact(&mut rows);
+        assert_eq!(
+            rows,
+            vec![
+                Row {
+                    key: "a".into(),
+                    value: None
+                },
+                Row {
+                    key: String::new(),
+                    value: Some("v".into())
+                },
+            ]
+        );
+    }
 }
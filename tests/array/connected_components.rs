@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::connected_components::connected_components;
+
+    /// Tests grouping an edge list.
+    ///
+    /// # Expected
+    /// Components and their members are ordered by first appearance.
+    #[test]
+    fn test_groups_edges() {
+        let edges = [(1, 2), (10, 11), (2, 3), (11, 12), (3, 1)];
+        assert_eq!(
+            connected_components(&edges),
+            vec![vec![1, 2, 3], vec![10, 11, 12]]
+        );
+    }
+
+    /// Tests components that merge late.
+    ///
+    /// # Expected
+    /// A bridging edge joins two earlier components into one.
+    #[test]
+    fn test_bridge_merges_components() {
+        let edges = [("a", "b"), ("c", "d"), ("b", "c")];
+        assert_eq!(connected_components(&edges), vec![vec!["a", "b", "c", "d"]]);
+    }
+
+    /// Tests self-edges, duplicates, and empty input.
+    ///
+    /// # Expected
+    /// Self-edges create singletons; no edges yield no components.
+    #[test]
+    fn test_self_edges_and_empty() {
+        let edges = [(5, 5), (6, 7), (7, 6), (6, 7)];
+        assert_eq!(connected_components(&edges), vec![vec![5], vec![6, 7]]);
+
+        let none: [(u8, u8); 0] = [];
+        assert!(connected_components(&none).is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::dedup_paths::{dedup_paths, dedup_paths_deepest};
+    use std::path::PathBuf;
+
+    /// ✅ Removes descendants of another entry.
+    /// Expects only the top-most paths, in original order.
+    #[test]
+    fn test_removes_descendants() {
+        let mut paths = vec!["/a/b/c", "/x", "/a", "/a/b", "/x/y"];
+        dedup_paths(&mut paths);
+        assert_eq!(paths, vec!["/x", "/a"]);
+    }
+
+    /// ✅ Sibling names sharing a string prefix are not ancestors.
+    /// Expects both entries to be kept.
+    #[test]
+    fn test_component_wise_prefix() {
+        let mut paths = vec!["/a/b", "/a/bc", "/a/b/c"];
+        dedup_paths(&mut paths);
+        assert_eq!(paths, vec!["/a/b", "/a/bc"]);
+    }
+
+    /// ✅ Exact and lexically equal duplicates.
+    /// Expects the first occurrence to be kept.
+    #[test]
+    fn test_duplicates_keep_first() {
+        let mut paths = vec![
+            PathBuf::from("dir/"),
+            PathBuf::from("dir"),
+            PathBuf::from("./other"),
+            PathBuf::from("dir//sub"),
+        ];
+        dedup_paths(&mut paths);
+        assert_eq!(paths, vec![PathBuf::from("dir/"), PathBuf::from("./other")]);
+    }
+
+    /// ✅ Works with owned `String` paths.
+    /// Expects relative paths to be handled like absolute ones.
+    #[test]
+    fn test_strings() {
+        let mut paths = vec![
+            "src/lib.rs".to_string(),
+            "src".to_string(),
+            "tests".to_string(),
+        ];
+        dedup_paths(&mut paths);
+        assert_eq!(paths, vec!["src", "tests"]);
+    }
+
+    /// ✅ Empty and single-element inputs.
+    /// Expects no change and no panic.
+    #[test]
+    fn test_empty_and_single() {
+        let mut empty: Vec<&str> = vec![];
+        dedup_paths(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = vec!["/only"];
+        dedup_paths_deepest(&mut single);
+        assert_eq!(single, vec!["/only"]);
+    }
+
+    /// ✅ Deepest variant removes ancestors of another entry.
+    /// Expects only leaf paths, in original order.
+    #[test]
+    fn test_deepest_removes_ancestors() {
+        let mut paths = vec!["/a", "/a/b/c", "/x/y", "/a/b", "/x", "/z"];
+        dedup_paths_deepest(&mut paths);
+        assert_eq!(paths, vec!["/a/b/c", "/x/y", "/z"]);
+    }
+
+    /// ✅ Deepest variant with duplicates and component-wise prefixes.
+    /// Expects duplicates collapsed to the first occurrence.
+    #[test]
+    fn test_deepest_duplicates_and_siblings() {
+        let mut paths = vec!["/a/b", "/a/bc", "/a/b", "/a/bc/d"];
+        dedup_paths_deepest(&mut paths);
+        assert_eq!(paths, vec!["/a/b", "/a/bc/d"]);
+    }
+
+    /// ✅ Deepest variant where a duplicated path also has a descendant.
+    /// Expects every copy of the ancestor to be removed.
+    #[test]
+    fn test_deepest_duplicated_ancestor() {
+        let mut paths = vec!["/a", "/a", "/a/b"];
+        dedup_paths_deepest(&mut paths);
+        assert_eq!(paths, vec!["/a/b"]);
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::delimited::{join_delimited, parse_delimited};
+    use std::error::Error;
+
+    /// ✅ Parses integers with surrounding whitespace.
+    /// Expects all items trimmed and parsed in order.
+    #[test]
+    fn test_parse_integers() {
+        let values: Vec<i64> = parse_delimited(" 1,-2 ,  3 ", ",").unwrap();
+        assert_eq!(values, vec![1, -2, 3]);
+    }
+
+    /// ✅ Multi-character separators.
+    /// Expects the separator to be matched as a whole.
+    #[test]
+    fn test_parse_multi_char_separator() {
+        let values: Vec<String> = parse_delimited("a || b||c", "||").unwrap();
+        assert_eq!(values, vec!["a", "b", "c"]);
+    }
+
+    /// ✅ Empty and whitespace-only input.
+    /// Expects an empty vector rather than a single failed item.
+    #[test]
+    fn test_parse_empty_input() {
+        assert_eq!(parse_delimited::<u8>("", ",").unwrap(), Vec::<u8>::new());
+        assert_eq!(parse_delimited::<u8>("   ", ",").unwrap(), Vec::<u8>::new());
+    }
+
+    /// ✅ Failing item reports its index, text, and source error.
+    /// Expects the first failure to be returned.
+    #[test]
+    fn test_parse_error_details() {
+        let error = parse_delimited::<u32>("4, 5, x1, -1", ",").unwrap_err();
+        assert_eq!(error.index, 2);
+        assert_eq!(error.item, "x1");
+        assert!(error.source().is_some());
+        assert!(error.to_string().contains("item 2"));
+    }
+
+    /// ✅ Empty items between separators.
+    /// Expects an error for numbers and an empty string for `String`.
+    #[test]
+    fn test_parse_empty_items() {
+        let error = parse_delimited::<i32>("1,,2", ",").unwrap_err();
+        assert_eq!(error.index, 1);
+        assert_eq!(error.item, "");
+
+        let values: Vec<String> = parse_delimited("a,,b", ",").unwrap();
+        assert_eq!(values, vec!["a", "", "b"]);
+    }
+
+    /// ✅ Empty separator.
+    /// Expects the whole input to be parsed as one item.
+    #[test]
+    fn test_parse_empty_separator() {
+        assert_eq!(parse_delimited::<u16>(" 42 ", "").unwrap(), vec![42]);
+        assert!(parse_delimited::<u16>("4,2", "").is_err());
+    }
+
+    /// ✅ Joins values with a separator.
+    /// Expects `Display` output between separators.
+    #[test]
+    fn test_join() {
+        assert_eq!(join_delimited(&[1, 2, 3], ", "), "1, 2, 3");
+        assert_eq!(join_delimited(&["x"], ","), "x");
+        let empty: [i32; 0] = [];
+        assert_eq!(join_delimited(&empty, ","), "");
+    }
+
+    /// ✅ Round-trips through join and parse.
+    /// Expects the original values back.
+    #[test]
+    fn test_round_trip() {
+        let values = vec![true, false, true];
+        let joined = join_delimited(&values, ";");
+        assert_eq!(parse_delimited::<bool>(&joined, ";").unwrap(), values);
+    }
+}
@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::diff_ops::{apply_diff, diff_ops, ApplyDiffError, DiffOp, DiffScript};
+
+    /// Rebuilds the old and new sequences from an edit script.
+    fn replay<T: Clone>(ops: &[DiffOp<T>]) -> (Vec<T>, Vec<T>) {
+        let mut old = Vec::new();
+        let mut new = Vec::new();
+        for op in ops {
+            match op {
+                DiffOp::Equal(v) => {
+                    old.push(v.clone());
+                    new.push(v.clone());
+                }
+                DiffOp::Delete(v) => old.push(v.clone()),
+                DiffOp::Insert(v) => new.push(v.clone()),
+            }
+        }
+        (old, new)
+    }
+
+    /// Tests identical inputs.
+    ///
+    /// # Expected
+    /// Only `Equal` ops are produced.
+    #[test]
+    fn test_identical() {
+        let ops = diff_ops(&[1, 2, 3], &[1, 2, 3]);
+        assert_eq!(
+            ops,
+            vec![DiffOp::Equal(1), DiffOp::Equal(2), DiffOp::Equal(3)]
+        );
+    }
+
+    /// Tests empty inputs on either side.
+    ///
+    /// # Expected
+    /// Everything is inserted or deleted; two empty inputs give an empty script.
+    #[test]
+    fn test_empty_sides() {
+        assert!(diff_ops::<i32>(&[], &[]).is_empty());
+        assert_eq!(
+            diff_ops(&[], &[1, 2]),
+            vec![DiffOp::Insert(1), DiffOp::Insert(2)]
+        );
+        assert_eq!(
+            diff_ops(&[1, 2], &[]),
+            vec![DiffOp::Delete(1), DiffOp::Delete(2)]
+        );
+    }
+
+    /// Tests a replacement in the middle.
+    ///
+    /// # Expected
+    /// The delete comes before the insert, surrounded by `Equal` ops.
+    #[test]
+    fn test_replacement_order() {
+        let ops = diff_ops(&['a', 'b', 'c'], &['a', 'x', 'c']);
+        assert_eq!(
+            ops,
+            vec![
+                DiffOp::Equal('a'),
+                DiffOp::Delete('b'),
+                DiffOp::Insert('x'),
+                DiffOp::Equal('c'),
+            ]
+        );
+    }
+
+    /// Tests that the script is minimal for a classic LCS example.
+    ///
+    /// # Expected
+    /// The number of `Equal` ops equals the LCS length (4 for ABCBDAB / BDCABA).
+    #[test]
+    fn test_minimal_script() {
+        let old: Vec<char> = "ABCBDAB".chars().collect();
+        let new: Vec<char> = "BDCABA".chars().collect();
+        let ops = diff_ops(&old, &new);
+        let equal = ops.iter().filter(|op| !op.is_change()).count();
+        assert_eq!(equal, 4);
+        assert_eq!(replay(&ops), (old, new));
+    }
+
+    /// Tests minimality against a reference LCS on many small pseudo-random inputs.
+    ///
+    /// # Expected
+    /// The number of `Equal` ops always equals the LCS length, and every changed block lists
+    /// its deletions before its insertions.
+    #[test]
+    fn test_minimal_against_reference() {
+        fn lcs_len(a: &[u8], b: &[u8]) -> usize {
+            let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+            for i in 0..a.len() {
+                for j in 0..b.len() {
+                    table[i + 1][j + 1] = if a[i] == b[j] {
+                        table[i][j] + 1
+                    } else {
+                        table[i][j + 1].max(table[i + 1][j])
+                    };
+                }
+            }
+            table[a.len()][b.len()]
+        }
+
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |bound: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % bound) as u8
+        };
+        for _ in 0..500 {
+            let old: Vec<u8> = (0..next(12)).map(|_| next(4)).collect();
+            let new: Vec<u8> = (0..next(12)).map(|_| next(4)).collect();
+            let ops = diff_ops(&old, &new);
+            let equal = ops.iter().filter(|op| !op.is_change()).count();
+            assert_eq!(equal, lcs_len(&old, &new), "{old:?} -> {new:?}");
+            assert_eq!(replay(&ops), (old.clone(), new.clone()));
+            assert!(ops
+                .windows(2)
+                .all(|pair| !matches!(pair, [DiffOp::Insert(_), DiffOp::Delete(_)])));
+        }
+    }
+
+    /// Tests large inputs whose middle sections differ.
+    ///
+    /// # Expected
+    /// Completes quickly with a minimal script; a full LCS table would need gigabytes here.
+    #[test]
+    fn test_large_input_with_changed_middle() {
+        let old: Vec<u32> = (0..20_000).collect();
+        let mut new = old.clone();
+        let mut changed = 0;
+        for index in (1_000..19_000).step_by(97) {
+            new[index] += 1_000_000;
+            changed += 1;
+        }
+        new.insert(10_000, 7_777_777);
+
+        let ops = diff_ops(&old, &new);
+        let deletes = ops
+            .iter()
+            .filter(|op| matches!(op, DiffOp::Delete(_)))
+            .count();
+        let inserts = ops
+            .iter()
+            .filter(|op| matches!(op, DiffOp::Insert(_)))
+            .count();
+        assert_eq!(deletes, changed);
+        assert_eq!(inserts, changed + 1);
+        assert_eq!(replay(&ops), (old, new));
+    }
+
+    /// Tests that replaying the script rebuilds both inputs.
+    ///
+    /// # Expected
+    /// `Equal` + `Delete` give `old`, `Equal` + `Insert` give `new`.
+    #[test]
+    fn test_replay_round_trip() {
+        let old = vec!["x", "a", "b", "c", "y", "z"];
+        let new = vec!["a", "c", "q", "y", "z", "w"];
+        let ops = diff_ops(&old, &new);
+        assert_eq!(replay(&ops), (old, new));
+    }
+
+    /// Tests the `Display` format and accessors.
+    ///
+    /// # Expected
+    /// Each op renders with a one-character prefix.
+    #[test]
+    fn test_display_and_accessors() {
+        let ops = diff_ops(&["keep", "old"], &["keep", "new"]);
+        let rendered: Vec<String> = ops.iter().map(ToString::to_string).collect();
+        assert_eq!(rendered, vec![" keep", "-old", "+new"]);
+        assert_eq!(*ops[1].value(), "old");
+        assert!(ops[2].is_change());
+        assert!(!ops[0].is_change());
+    }
+
+    /// Tests that applying a computed script turns `old` into `new`.
+    ///
+    /// # Expected
+    /// The patched vector equals `new` for several input pairs, including empty ones.
+    #[test]
+    fn test_apply_round_trip() {
+        let cases: Vec<(Vec<&str>, Vec<&str>)> = vec![
+            (vec![], vec![]),
+            (vec![], vec!["a", "b"]),
+            (vec!["a", "b"], vec![]),
+            (
+                vec!["x", "a", "b", "c", "y", "z"],
+                vec!["a", "c", "q", "y", "z", "w"],
+            ),
+            (vec!["a", "b", "c"], vec!["c", "b", "a"]),
+        ];
+        for (old, new) in cases {
+            let ops = diff_ops(&old, &new);
+            let mut values = old.clone();
+            apply_diff(&mut values, &ops).unwrap();
+            assert_eq!(values, new);
+        }
+    }
+
+    /// Tests a script whose `Delete` value does not match the target.
+    ///
+    /// # Expected
+    /// `Mismatch` with the op and element indices; the vector is unchanged.
+    #[test]
+    fn test_apply_mismatch() {
+        let ops = vec![DiffOp::Equal(1), DiffOp::Delete(2), DiffOp::Insert(9)];
+        let mut values = vec![1, 3];
+        assert_eq!(
+            apply_diff(&mut values, &ops),
+            Err(ApplyDiffError::Mismatch { op: 1, position: 1 })
+        );
+        assert_eq!(values, vec![1, 3]);
+    }
+
+    /// Tests a script that refers to more elements than the vector has.
+    ///
+    /// # Expected
+    /// `UnexpectedEnd` pointing past the last element; the vector is unchanged.
+    #[test]
+    fn test_apply_unexpected_end() {
+        let ops = vec![DiffOp::Insert(0), DiffOp::Equal(1), DiffOp::Equal(2)];
+        let mut values = vec![1];
+        assert_eq!(
+            apply_diff(&mut values, &ops),
+            Err(ApplyDiffError::UnexpectedEnd { op: 2, position: 1 })
+        );
+        assert_eq!(values, vec![1]);
+    }
+
+    /// Tests a script that leaves elements unconsumed.
+    ///
+    /// # Expected
+    /// `TrailingElements` with the first leftover index and count; the vector is unchanged.
+    #[test]
+    fn test_apply_trailing_elements() {
+        let ops = vec![DiffOp::Equal('a')];
+        let mut values = vec!['a', 'b', 'c'];
+        let err = apply_diff(&mut values, &ops).unwrap_err();
+        assert_eq!(
+            err,
+            ApplyDiffError::TrailingElements {
+                position: 1,
+                remaining: 2
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "script ended with 2 element(s) left from index 1"
+        );
+        assert_eq!(values, vec!['a', 'b', 'c']);
+    }
+
+    /// Tests printing a whole script with `DiffScript`.
+    ///
+    /// # Expected
+    /// One line per op, each ending in a newline; an empty script prints nothing.
+    #[test]
+    fn test_script_display() {
+        let ops = diff_ops(&[1, 2, 3], &[1, 3, 4]);
+        assert_eq!(DiffScript { ops: &ops }.to_string(), " 1\n-2\n 3\n+4\n");
+
+        let empty: Vec<DiffOp<i32>> = Vec::new();
+        assert_eq!(DiffScript { ops: &empty }.to_string(), "");
+    }
+
+    /// Tests the alternate `{:#}` form.
+    ///
+    /// # Expected
+    /// With the `color` feature, changes are wrapped in ANSI codes; without it, output is plain.
+    #[test]
+    fn test_script_alternate_display() {
+        let ops = diff_ops(&["a", "b"], &["a", "c"]);
+        let script = DiffScript { ops: &ops };
+        let rendered = format!("{script:#}");
+        if cfg!(feature = "color") {
+            assert_eq!(rendered, " a\n\x1b[31m-b\x1b[0m\n\x1b[32m+c\x1b[0m\n");
+        } else {
+            assert_eq!(rendered, " a\n-b\n+c\n");
+        }
+        assert_eq!(script.to_string(), " a\n-b\n+c\n");
+        assert_eq!(format!("{:#}", ops[0]), " a");
+    }
+}
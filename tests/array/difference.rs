@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::difference::{difference, difference_performant};
+    use pencil_box::array::difference::{difference, difference_performant, difference_secure};
 
-    /// Shared test helper to compare results between `difference` and `difference_performant`.
+    /// Shared test helper to compare results between `difference`, `difference_secure`, and `difference_performant`.
     fn assert_both_equal<T: Eq + std::hash::Hash + Clone + std::fmt::Debug>(
         to_compare: Vec<T>,
         others: Vec<&Vec<T>>,
@@ -10,8 +10,10 @@ mod tests {
     ) {
         let result_std = difference(&to_compare, &others);
         let result_ahash = difference_performant(&to_compare, &others);
+        let result_secure = difference_secure(&to_compare, &others);
         assert_eq!(result_std, expected);
         assert_eq!(result_ahash, expected);
+        assert_eq!(result_secure, expected);
     }
 
     /// Tests the case where both `to_compare` and `others` are empty.
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::downsample_lttb::downsample_lttb;
+
+    fn series(ys: &[f64]) -> Vec<(f64, f64)> {
+        ys.iter().enumerate().map(|(x, &y)| (x as f64, y)).collect()
+    }
+
+    /// Tests that the output length and endpoints are correct.
+    ///
+    /// # Expected
+    /// Exactly `target_points` points, starting and ending with the input endpoints.
+    #[test]
+    fn test_length_and_endpoints() {
+        let points: Vec<(f64, f64)> = (0..1000)
+            .map(|x| (x as f64, (x as f64 / 50.0).sin()))
+            .collect();
+        for target in [3, 7, 100, 999] {
+            let reduced = downsample_lttb(&points, target);
+            assert_eq!(reduced.len(), target);
+            assert_eq!(reduced[0], points[0]);
+            assert_eq!(reduced[target - 1], points[999]);
+        }
+    }
+
+    /// Tests that selected points come from the input in order.
+    ///
+    /// # Expected
+    /// Every output point exists in the input and `x` is strictly increasing.
+    #[test]
+    fn test_subset_in_order() {
+        let points = series(&[3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0]);
+        let reduced = downsample_lttb(&points, 5);
+        assert!(reduced.iter().all(|p| points.contains(p)));
+        assert!(reduced.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    /// Tests that extremes are preserved.
+    ///
+    /// # Expected
+    /// A spike and a dip in a flat signal are both kept.
+    #[test]
+    fn test_keeps_extremes() {
+        let mut ys = vec![0.0; 60];
+        ys[17] = 10.0;
+        ys[44] = -10.0;
+        let reduced = downsample_lttb(&series(&ys), 6);
+        assert!(reduced.contains(&(17.0, 10.0)));
+        assert!(reduced.contains(&(44.0, -10.0)));
+    }
+
+    /// Tests small targets and short inputs.
+    ///
+    /// # Expected
+    /// `0`, `1`, and `2` targets give empty, first, and endpoints; large targets copy the input.
+    #[test]
+    fn test_small_targets() {
+        let points = series(&[1.0, 2.0, 3.0, 4.0]);
+        assert!(downsample_lttb(&points, 0).is_empty());
+        assert_eq!(downsample_lttb(&points, 1), vec![(0.0, 1.0)]);
+        assert_eq!(downsample_lttb(&points, 2), vec![(0.0, 1.0), (3.0, 4.0)]);
+        assert_eq!(downsample_lttb(&points, 4), points);
+        assert_eq!(downsample_lttb(&points, 10), points);
+        assert!(downsample_lttb(&[], 5).is_empty());
+    }
+
+    /// Tests a `NaN` value inside a bucket.
+    ///
+    /// # Expected
+    /// A comparable point is selected over the `NaN` one.
+    #[test]
+    fn test_nan_not_preferred() {
+        let points = series(&[0.0, f64::NAN, 5.0, 0.0, 0.0]);
+        let reduced = downsample_lttb(&points, 3);
+        assert_eq!(reduced.len(), 3);
+        assert!(!reduced[1].1.is_nan());
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::drop_start::drop_start;
+    use pencil_box::array::drop_start::{drop_start, drop_start_collect};
 
     /// Tests the case where 0 elements are dropped from a non-empty vector.
     ///
@@ -116,4 +116,42 @@ mod tests {
         drop_start(&mut data, 0);
         assert!(data.is_empty());
     }
+
+    /// Tests collecting fewer elements than the vector length.
+    ///
+    /// # Expected
+    /// The prefix is returned in order and the remainder stays in place.
+    #[test]
+    fn test_drop_start_collect_less_than_length() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        let dropped = drop_start_collect(&mut data, 2);
+        assert_eq!(dropped, vec![1, 2]);
+        assert_eq!(data, vec![3, 4, 5]);
+    }
+
+    /// Tests collecting zero elements.
+    ///
+    /// # Expected
+    /// Returns an empty vector and leaves the input unchanged.
+    #[test]
+    fn test_drop_start_collect_zero_elements() {
+        let mut data = vec![1, 2, 3];
+        assert!(drop_start_collect(&mut data, 0).is_empty());
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests collecting at least as many elements as the vector holds.
+    ///
+    /// # Expected
+    /// Every element is returned and the input is left empty.
+    #[test]
+    fn test_drop_start_collect_more_than_length() {
+        let mut data = vec!["a".to_string(), "b".to_string()];
+        let dropped = drop_start_collect(&mut data, 5);
+        assert_eq!(dropped, vec!["a", "b"]);
+        assert!(data.is_empty());
+
+        let mut empty: Vec<i32> = vec![];
+        assert!(drop_start_collect(&mut empty, 1).is_empty());
+    }
 }
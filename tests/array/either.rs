@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::either::{lefts, partition_eithers, rights, Either};
+
+    /// Tests the variant checks and conversions.
+    ///
+    /// # Expected
+    /// Each accessor reports the side it was built with.
+    #[test]
+    fn test_accessors() {
+        let left: Either<i32, &str> = Either::Left(7);
+        let right: Either<i32, &str> = Either::Right("seven");
+        assert!(left.is_left() && !left.is_right());
+        assert!(right.is_right() && !right.is_left());
+        assert_eq!(left.left(), Some(7));
+        assert_eq!(left.right(), None);
+        assert_eq!(right.right(), Some("seven"));
+        assert_eq!(right.as_ref(), Either::Right(&"seven"));
+    }
+
+    /// Tests flipping sides.
+    ///
+    /// # Expected
+    /// `Left` becomes `Right` and flipping twice is the identity.
+    #[test]
+    fn test_flip() {
+        let value: Either<u8, char> = Either::Left(1);
+        assert_eq!(value.flip(), Either::Right(1));
+        assert_eq!(value.flip().flip(), value);
+    }
+
+    /// Tests the slice helpers on mixed input.
+    ///
+    /// # Expected
+    /// Each helper keeps input order; the slice is left intact.
+    #[test]
+    fn test_slice_helpers() {
+        let values = vec![
+            Either::Right("a".to_string()),
+            Either::Left(1),
+            Either::Right("b".to_string()),
+            Either::Left(2),
+        ];
+        assert_eq!(lefts(&values), vec![1, 2]);
+        assert_eq!(rights(&values), vec!["a", "b"]);
+        assert_eq!(values.len(), 4);
+
+        let (l, r) = partition_eithers(values);
+        assert_eq!(l, vec![1, 2]);
+        assert_eq!(r, vec!["a", "b"]);
+    }
+
+    /// Tests empty and one-sided input.
+    ///
+    /// # Expected
+    /// Missing sides come back empty.
+    #[test]
+    fn test_one_sided() {
+        let only_left: Vec<Either<u8, u8>> = vec![Either::Left(1), Either::Left(2)];
+        assert!(rights(&only_left).is_empty());
+        let (l, r) = partition_eithers(only_left);
+        assert_eq!(l, vec![1, 2]);
+        assert!(r.is_empty());
+
+        let (l, r) = partition_eithers(Vec::<Either<u8, u8>>::new());
+        assert!(l.is_empty() && r.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::extract_n::extract_n;
+
+    /// Tests extracting fewer matches than are available.
+    ///
+    /// # Expected
+    /// Only the first `n` matches are removed; later matches remain.
+    #[test]
+    fn test_caps_at_n() {
+        let mut data = vec![1, 2, 3, 4, 5, 6];
+        let extracted = extract_n(&mut data, 2, |x| x % 2 == 0);
+        assert_eq!(extracted, vec![2, 4]);
+        assert_eq!(data, vec![1, 3, 5, 6]);
+    }
+
+    /// Tests a cap larger than the number of matches.
+    ///
+    /// # Expected
+    /// All matches are removed.
+    #[test]
+    fn test_fewer_matches_than_cap() {
+        let mut data = vec![1, 2, 3];
+        assert_eq!(extract_n(&mut data, 5, |x| *x > 1), vec![2, 3]);
+        assert_eq!(data, vec![1]);
+    }
+
+    /// Tests `n == 0`.
+    ///
+    /// # Expected
+    /// Nothing is removed and the predicate is never called.
+    #[test]
+    fn test_zero_cap() {
+        let mut data = vec![1, 2, 3];
+        let mut calls = 0;
+        let extracted = extract_n(&mut data, 0, |_| {
+            calls += 1;
+            true
+        });
+        assert!(extracted.is_empty());
+        assert_eq!(calls, 0);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests that the predicate stops being called after `n` matches.
+    ///
+    /// # Expected
+    /// The predicate is called only up to and including the last extracted element.
+    #[test]
+    fn test_stops_scanning_after_cap() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        let mut calls = 0;
+        extract_n(&mut data, 1, |x| {
+            calls += 1;
+            *x == 2
+        });
+        assert_eq!(calls, 2);
+        assert_eq!(data, vec![1, 3, 4, 5]);
+    }
+
+    /// Tests no matching elements and an empty input.
+    ///
+    /// # Expected
+    /// Returns an empty vector and leaves the input unchanged.
+    #[test]
+    fn test_no_matches() {
+        let mut data = vec!["a".to_string(), "b".to_string()];
+        assert!(extract_n(&mut data, 3, |s| s.is_empty()).is_empty());
+        assert_eq!(data, vec!["a", "b"]);
+
+        let mut empty: Vec<i32> = vec![];
+        assert!(extract_n(&mut empty, 3, |_| true).is_empty());
+    }
+
+    /// Tests that extraction works in place.
+    ///
+    /// # Expected
+    /// The vector keeps its buffer and spare capacity.
+    #[test]
+    fn test_keeps_capacity() {
+        let mut queue: Vec<u32> = Vec::with_capacity(1_000);
+        queue.extend(0..100);
+        let buffer = queue.as_ptr();
+
+        assert_eq!(extract_n(&mut queue, 1, |job| job % 10 == 9), vec![9]);
+        assert_eq!(queue.len(), 99);
+        assert_eq!(queue.capacity(), 1_000);
+        assert_eq!(queue.as_ptr(), buffer);
+    }
+}
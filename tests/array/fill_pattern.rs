@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::fill_pattern::fill_pattern;
+
+    /// Tests a size that is not a multiple of the pattern length.
+    ///
+    /// # Expected
+    /// The last repetition is truncated.
+    #[test]
+    fn test_partial_repeat() {
+        assert_eq!(fill_pattern(&[1, 2, 3], 7), vec![1, 2, 3, 1, 2, 3, 1]);
+    }
+
+    /// Tests a size smaller than the pattern.
+    ///
+    /// # Expected
+    /// Returns a prefix of the pattern.
+    #[test]
+    fn test_shorter_than_pattern() {
+        assert_eq!(fill_pattern(&['a', 'b', 'c'], 2), vec!['a', 'b']);
+    }
+
+    /// Tests a zero size and an empty pattern.
+    ///
+    /// # Expected
+    /// Both return an empty vector.
+    #[test]
+    fn test_empty_cases() {
+        assert!(fill_pattern(&[1, 2], 0).is_empty());
+        let empty: [String; 0] = [];
+        assert!(fill_pattern(&empty, 10).is_empty());
+    }
+
+    /// Tests cloning owned values.
+    ///
+    /// # Expected
+    /// Each slot holds its own clone.
+    #[test]
+    fn test_owned_values() {
+        let pattern = vec!["x".to_string()];
+        let values = fill_pattern(&pattern, 3);
+        assert_eq!(values, vec!["x", "x", "x"]);
+        assert_eq!(values.capacity(), 3);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::fill_with::{fill_with, try_fill_with};
+    use std::cell::RefCell;
+
+    /// Tests generating values from their index.
+    ///
+    /// # Expected
+    /// Element `i` equals `f(i)`.
+    #[test]
+    fn test_index_based() {
+        assert_eq!(fill_with(4, |i| i * 10), vec![0, 10, 20, 30]);
+    }
+
+    /// Tests a zero size.
+    ///
+    /// # Expected
+    /// Returns an empty vector without calling the closure.
+    #[test]
+    fn test_zero_size() {
+        let values: Vec<u8> = fill_with(0, |_| panic!("not called"));
+        assert!(values.is_empty());
+    }
+
+    /// Tests a stateful closure.
+    ///
+    /// # Expected
+    /// The closure is called in ascending index order.
+    #[test]
+    fn test_stateful_closure() {
+        let mut previous = 1u64;
+        let powers = fill_with(5, |_| {
+            let current = previous;
+            previous *= 2;
+            current
+        });
+        assert_eq!(powers, vec![1, 2, 4, 8, 16]);
+    }
+
+    /// Tests generating values that do not implement `Clone`.
+    ///
+    /// # Expected
+    /// Each slot holds its own independent value.
+    #[test]
+    fn test_non_clone_values() {
+        let cells = fill_with(3, |i| RefCell::new(vec![i]));
+        cells[0].borrow_mut().push(99);
+        assert_eq!(*cells[0].borrow(), vec![0, 99]);
+        assert_eq!(*cells[2].borrow(), vec![2]);
+    }
+
+    /// Tests a fallible closure that always succeeds.
+    ///
+    /// # Expected
+    /// Returns all generated values.
+    #[test]
+    fn test_try_fill_with_ok() {
+        let result: Result<Vec<usize>, String> = try_fill_with(3, |i| Ok(i + 1));
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+    }
+
+    /// Tests a fallible closure that fails part-way.
+    ///
+    /// # Expected
+    /// Returns the first error and stops calling the closure.
+    #[test]
+    fn test_try_fill_with_short_circuits() {
+        let mut calls = 0;
+        let result: Result<Vec<i32>, usize> = try_fill_with(10, |i| {
+            calls += 1;
+            if i == 2 {
+                Err(i)
+            } else {
+                Ok(i as i32)
+            }
+        });
+        assert_eq!(result, Err(2));
+        assert_eq!(calls, 3);
+    }
+
+    /// Tests a zero size with a fallible closure.
+    ///
+    /// # Expected
+    /// Returns an empty vector without calling the closure.
+    #[test]
+    fn test_try_fill_with_zero_size() {
+        let result: Result<Vec<u8>, ()> = try_fill_with(0, |_| Err(()));
+        assert_eq!(result, Ok(vec![]));
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::find_index::find_index;
+    use pencil_box::array::find_index::{find_index, find_index_from, find_map_index};
 
     /// ✅ Finds the index of the first even number in a list of integers.
     /// Expects index of the first element divisible by 2.
@@ -100,4 +100,46 @@ mod tests {
         let result = find_index(&values, |x| *x == 99);
         assert_eq!(result, Some(4));
     }
+
+    /// ✅ Resumes a search from a start offset.
+    /// Expects absolute indices and skipped elements before `start`.
+    #[test]
+    fn test_find_index_from_resumes() {
+        let values = [2, 1, 2, 3, 2];
+        let mut found = Vec::new();
+        let mut start = 0;
+        while let Some(index) = find_index_from(&values, start, |x| *x == 2) {
+            found.push(index);
+            start = index + 1;
+        }
+        assert_eq!(found, vec![0, 2, 4]);
+    }
+
+    /// ✅ Start offset at or beyond the slice length.
+    /// Expects `None` without panicking.
+    #[test]
+    fn test_find_index_from_out_of_range() {
+        let values = [1, 2, 3];
+        assert_eq!(find_index_from(&values, 3, |_| true), None);
+        assert_eq!(find_index_from(&values, usize::MAX, |_| true), None);
+    }
+
+    /// ✅ Finds and transforms the first parseable element.
+    /// Expects the index together with the mapped value.
+    #[test]
+    fn test_find_map_index_parses() {
+        let values = ["a", "b", "12", "34"];
+        let result = find_map_index(&values, |s| s.parse::<i32>().ok());
+        assert_eq!(result, Some((2, 12)));
+    }
+
+    /// ✅ Mapper never returns `Some`, including for empty input.
+    /// Expects `None`.
+    #[test]
+    fn test_find_map_index_none() {
+        let values = ["a", "b"];
+        assert_eq!(find_map_index(&values, |s| s.parse::<i32>().ok()), None);
+        let empty: [&str; 0] = [];
+        assert_eq!(find_map_index(&empty, |s| Some(s.len())), None);
+    }
 }
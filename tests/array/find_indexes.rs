@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::find_indexes::find_indexes;
+    use pencil_box::array::find_indexes::{find_indexes, find_indexes_limited, find_indexes_rev};
+    use std::cell::Cell;
 
     /// ✅ Tests finding even numbers in a list of integers.
     /// Expects indices of all elements divisible by 2.
@@ -94,4 +95,99 @@ mod tests {
         let result = find_indexes(&values, |_| true);
         assert_eq!(result, vec![0, 1, 2]);
     }
+
+    /// ✅ Tests that no matches do not reserve capacity for the whole input.
+    /// Expects an empty, unallocated result.
+    #[test]
+    fn test_no_matches_does_not_allocate() {
+        let values = vec![0u8; 10_000];
+        let result = find_indexes(&values, |x| *x == 1);
+        assert_eq!(result.capacity(), 0);
+    }
+
+    /// ✅ Tests that the limited variant stops after `max` matches.
+    /// Expects only the first hits and no further matcher calls.
+    #[test]
+    fn test_find_indexes_limited_stops_early() {
+        let calls = Cell::new(0);
+        let values = [2, 4, 6, 8, 10];
+        let result = find_indexes_limited(
+            &values,
+            |_| {
+                calls.set(calls.get() + 1);
+                true
+            },
+            2,
+        );
+        assert_eq!(result, vec![0, 1]);
+        assert_eq!(calls.get(), 2);
+    }
+
+    /// ✅ Tests the limited variant with fewer matches than `max`.
+    /// Expects all matches, like `find_indexes`.
+    #[test]
+    fn test_find_indexes_limited_fewer_matches() {
+        let values = [1, 2, 3, 4, 5];
+        assert_eq!(
+            find_indexes_limited(&values, |x| *x > 3, 10),
+            find_indexes(&values, |x| *x > 3)
+        );
+    }
+
+    /// ✅ Tests the limited variant with `max` of zero.
+    /// Expects an empty result.
+    #[test]
+    fn test_find_indexes_limited_zero() {
+        let values = [1, 2, 3];
+        assert!(find_indexes_limited(&values, |_| true, 0).is_empty());
+    }
+
+    /// ✅ Tests the limited variant with an effectively unlimited `max`.
+    /// Expects no reservation of the full input length up front.
+    #[test]
+    fn test_find_indexes_limited_no_full_reservation() {
+        let values = vec![0u8; 10_000];
+        let result = find_indexes_limited(&values, |_| false, usize::MAX);
+        assert!(result.is_empty());
+        assert_eq!(result.capacity(), 0);
+    }
+
+    /// ✅ Tests reverse-order matching.
+    /// Expects the same indices as `find_indexes`, in descending order.
+    #[test]
+    fn test_find_indexes_rev_order() {
+        let values = ["a", "bb", "c", "dd", "e"];
+        let singles: Vec<usize> = find_indexes_rev(&values, |s| s.len() == 1).collect();
+        assert_eq!(singles, vec![4, 2, 0]);
+
+        let mut forward = find_indexes(&values, |s| s.len() == 2);
+        forward.reverse();
+        let backward: Vec<usize> = find_indexes_rev(&values, |s| s.len() == 2).collect();
+        assert_eq!(backward, forward);
+    }
+
+    /// ✅ Tests reverse matching with no matches and empty input.
+    /// Expects an empty result.
+    #[test]
+    fn test_find_indexes_rev_empty() {
+        assert_eq!(find_indexes_rev(&[1, 3, 5], |x| x % 2 == 0).next(), None);
+        let empty: [i32; 0] = [];
+        assert_eq!(find_indexes_rev(&empty, |_| true).count(), 0);
+    }
+
+    /// ✅ Tests taking the last N matches from the reverse iterator.
+    /// Expects the scan to stop once N matches have been found.
+    #[test]
+    fn test_find_indexes_rev_take_stops_early() {
+        let calls = Cell::new(0);
+        let values: Vec<u32> = (0..100).collect();
+        let last_three: Vec<usize> = find_indexes_rev(&values, |x| {
+            calls.set(calls.get() + 1);
+            x % 10 == 0
+        })
+        .take(3)
+        .collect();
+        assert_eq!(last_three, vec![90, 80, 70]);
+        assert_eq!(calls.get(), 30);
+    }
 }
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::find_last_index::find_last_index;
+    use pencil_box::array::find_last_index::{find_last, find_last_index, find_last_index_from};
+    use std::cell::Cell;
 
     /// ✅ Finds the index of the last even number.
     /// Ensures the last match is returned.
@@ -107,4 +108,59 @@ mod tests {
         let result = find_last_index(&values, |_| true);
         assert_eq!(result, Some(2));
     }
+
+    /// ✅ Stops scanning once the last element matches.
+    /// Expects the matcher to be called exactly once.
+    #[test]
+    fn test_early_exit_from_end() {
+        let calls = Cell::new(0);
+        let values = [1, 2, 3, 4];
+        let result = find_last_index(&values, |_| {
+            calls.set(calls.get() + 1);
+            true
+        });
+        assert_eq!(result, Some(3));
+        assert_eq!(calls.get(), 1);
+    }
+
+    /// ✅ `find_last` returns a reference to the last match.
+    /// Expects the element rather than its index.
+    #[test]
+    fn test_find_last_returns_element() {
+        let values = vec![(1, "a"), (2, "b"), (3, "a")];
+        assert_eq!(find_last(&values, |(_, tag)| *tag == "a"), Some(&(3, "a")));
+    }
+
+    /// ✅ `find_last` with no match or empty input.
+    /// Expects `None`.
+    #[test]
+    fn test_find_last_none() {
+        let values = [1, 3, 5];
+        assert_eq!(find_last(&values, |x| x % 2 == 0), None);
+        let empty: [i32; 0] = [];
+        assert_eq!(find_last(&empty, |_| true), None);
+    }
+
+    /// ✅ Resumes a reverse search before an end offset.
+    /// Expects matches in descending order, ignoring elements at or after `end`.
+    #[test]
+    fn test_find_last_index_from_resumes() {
+        let values = [2, 1, 2, 3, 2];
+        let mut found = Vec::new();
+        let mut end = values.len();
+        while let Some(index) = find_last_index_from(&values, end, |x| *x == 2) {
+            found.push(index);
+            end = index;
+        }
+        assert_eq!(found, vec![4, 2, 0]);
+    }
+
+    /// ✅ End offset of zero or beyond the slice length.
+    /// Expects `None` for zero and a full search when too large.
+    #[test]
+    fn test_find_last_index_from_bounds() {
+        let values = [1, 2, 3];
+        assert_eq!(find_last_index_from(&values, 0, |_| true), None);
+        assert_eq!(find_last_index_from(&values, 100, |x| *x < 3), Some(1));
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::flatten::flatten;
+    use pencil_box::array::flatten::{flatten, flatten_into};
 
     /// 🧪 Tests flattening a slice of slices (`&[&[T]]`)
     ///
@@ -192,4 +192,67 @@ mod tests {
         let data: Vec<Vec<i32>> = vec![vec![], vec![], vec![]];
         assert_eq!(flatten(&data), vec![]);
     }
+
+    /// 🧪 Tests that flatten pre-sizes its output
+    ///
+    /// # ✅ Expected
+    /// The result capacity equals the summed length of the inner collections.
+    #[test]
+    fn test_flatten_capacity() {
+        let data: Vec<Vec<i32>> = vec![vec![1, 2, 3], vec![], vec![4, 5]];
+        let result = flatten(&data);
+        assert_eq!(result, vec![1, 2, 3, 4, 5]);
+        assert_eq!(result.capacity(), 5);
+    }
+
+    /// 🧪 Tests consuming flatten of a Vec<Vec<String>>
+    ///
+    /// # ✅ Expected
+    /// Returns all strings moved into a single vector in order.
+    #[test]
+    fn test_flatten_into_strings() {
+        let data = vec![
+            vec!["foo".to_string(), "bar".to_string()],
+            vec!["baz".to_string()],
+        ];
+        assert_eq!(flatten_into(data), vec!["foo", "bar", "baz"]);
+    }
+
+    /// 🧪 Tests consuming flatten with a non-`Clone` element type
+    ///
+    /// # ✅ Expected
+    /// Elements are moved, so no `Clone` bound is required.
+    #[test]
+    fn test_flatten_into_non_clone() {
+        #[derive(Debug, PartialEq)]
+        struct Handle(u32);
+
+        let data = vec![vec![Handle(1)], vec![Handle(2), Handle(3)]];
+        assert_eq!(flatten_into(data), vec![Handle(1), Handle(2), Handle(3)]);
+    }
+
+    /// 🧪 Tests consuming flatten with empty outer and inner vectors
+    ///
+    /// # ✅ Expected
+    /// Returns an empty vector in both cases.
+    #[test]
+    fn test_flatten_into_empty() {
+        let outer: Vec<Vec<i32>> = vec![];
+        assert!(flatten_into(outer).is_empty());
+
+        let inner: Vec<Vec<i32>> = vec![vec![], vec![]];
+        assert!(flatten_into(inner).is_empty());
+    }
+
+    /// 🧪 Tests that consuming flatten allocates exactly the total length
+    ///
+    /// # ✅ Expected
+    /// The result capacity equals the summed length of the inner vectors.
+    #[test]
+    fn test_flatten_into_capacity() {
+        let data = vec![vec![1, 2, 3], vec![], vec![4, 5]];
+        let result = flatten_into(data);
+        assert_eq!(result, vec![1, 2, 3, 4, 5]);
+        assert_eq!(result.capacity(), 5);
+    }
 }
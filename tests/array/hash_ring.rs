@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::hash_ring::HashRing;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    /// A node whose hash ignores its name, so equal `slot`s put every point in the same place.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Aliased {
+        name: &'static str,
+        slot: u8,
+    }
+
+    impl Hash for Aliased {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.slot.hash(state);
+        }
+    }
+
+    fn aliased(name: &'static str, slot: u8) -> Aliased {
+        Aliased { name, slot }
+    }
+
+    fn ring_with(nodes: &[&'static str]) -> HashRing<&'static str> {
+        let mut ring = HashRing::new(100);
+        for node in nodes {
+            ring.add_node(*node);
+        }
+        ring
+    }
+
+    /// Tests routing on an empty ring.
+    ///
+    /// # Expected
+    /// Returns `None`.
+    #[test]
+    fn test_empty_ring() {
+        let ring: HashRing<String> = HashRing::new(10);
+        assert!(ring.is_empty());
+        assert_eq!(ring.route("anything"), None);
+    }
+
+    /// Tests a single-node ring.
+    ///
+    /// # Expected
+    /// Every key routes to that node.
+    #[test]
+    fn test_single_node() {
+        let ring = ring_with(&["only"]);
+        for key in 0..100 {
+            assert_eq!(ring.route(&key), Some(&"only"));
+        }
+    }
+
+    /// Tests adding and removing nodes, including duplicates and unknown nodes.
+    ///
+    /// # Expected
+    /// Membership changes are reported accurately.
+    #[test]
+    fn test_add_remove() {
+        let mut ring = HashRing::new(0);
+        assert!(ring.add_node("a"));
+        assert!(!ring.add_node("a"));
+        assert!(ring.add_node("b"));
+        assert_eq!(ring.nodes(), &["a", "b"]);
+
+        assert!(ring.remove_node(&"a"));
+        assert!(!ring.remove_node(&"a"));
+        assert_eq!(ring.len(), 1);
+        assert_eq!(ring.route("key"), Some(&"b"));
+    }
+
+    /// Tests that keys spread across all nodes.
+    ///
+    /// # Expected
+    /// Every node receives a reasonable share of keys.
+    #[test]
+    fn test_distribution() {
+        let ring = ring_with(&["a", "b", "c", "d"]);
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for key in 0..4_000 {
+            *counts.entry(ring.route(&key).unwrap()).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 4);
+        assert!(counts.values().all(|count| *count > 500));
+    }
+
+    /// Tests that removing a node only re-maps that node's keys.
+    ///
+    /// # Expected
+    /// Keys owned by the remaining nodes keep their owner.
+    #[test]
+    fn test_minimal_remapping() {
+        let mut ring = ring_with(&["a", "b", "c"]);
+        let before: Vec<&str> = (0..1_000usize)
+            .map(|key| *ring.route(&key).unwrap())
+            .collect();
+
+        ring.remove_node(&"b");
+        for (key, owner) in before.iter().enumerate() {
+            let now = *ring.route(&key).unwrap();
+            if *owner == "b" {
+                assert_ne!(now, "b");
+            } else {
+                assert_eq!(now, *owner);
+            }
+        }
+    }
+
+    /// Tests that removing and re-adding a node restores the original routing.
+    ///
+    /// # Expected
+    /// Every key routes to the same node as before the removal.
+    #[test]
+    fn test_remove_then_readd() {
+        let mut ring = ring_with(&["a", "b", "c"]);
+        let before: Vec<&str> = (0..1_000usize)
+            .map(|key| *ring.route(&key).unwrap())
+            .collect();
+
+        assert!(ring.remove_node(&"a"));
+        assert!(ring.add_node("a"));
+        let fresh = ring_with(&["b", "c", "a"]);
+        for (key, owner) in before.iter().enumerate() {
+            assert_eq!(ring.route(&key), Some(owner));
+            assert_eq!(fresh.route(&key), Some(owner));
+        }
+    }
+
+    /// Tests removing the owner of points that collide with another node's points.
+    ///
+    /// # Expected
+    /// The next node in insertion order takes over, matching a freshly built ring.
+    #[test]
+    fn test_colliding_points_survive_removal() {
+        let mut ring = HashRing::new(20);
+        ring.add_node(aliased("first", 1));
+        ring.add_node(aliased("second", 1));
+        ring.add_node(aliased("third", 1));
+        ring.add_node(aliased("other", 2));
+
+        let mut fresh = HashRing::new(20);
+        fresh.add_node(aliased("second", 1));
+        fresh.add_node(aliased("third", 1));
+        fresh.add_node(aliased("other", 2));
+
+        assert!(ring.remove_node(&aliased("first", 1)));
+        for key in 0..1_000usize {
+            assert_eq!(ring.route(&key), fresh.route(&key));
+        }
+        assert!((0..1_000usize).any(|key| ring.route(&key).unwrap().name == "second"));
+    }
+
+    /// Tests removing a node whose points are all shadowed by an earlier node.
+    ///
+    /// # Expected
+    /// Routing is unchanged, and removing the owner afterwards does not revive it.
+    #[test]
+    fn test_remove_shadowed_node() {
+        let mut ring = HashRing::new(20);
+        ring.add_node(aliased("first", 1));
+        ring.add_node(aliased("second", 1));
+        ring.add_node(aliased("other", 2));
+        let before: Vec<_> = (0..500usize).map(|key| ring.route(&key).cloned()).collect();
+
+        assert!(ring.remove_node(&aliased("second", 1)));
+        for (key, owner) in before.iter().enumerate() {
+            assert_eq!(ring.route(&key), owner.as_ref());
+        }
+
+        assert!(ring.remove_node(&aliased("first", 1)));
+        assert!((0..500usize).all(|key| ring.route(&key).unwrap().name == "other"));
+    }
+
+    /// Tests that routing is pinned to the stable hash.
+    ///
+    /// # Expected
+    /// The same keys land on the same nodes in every build, so persisted placement stays valid.
+    #[test]
+    fn test_routing_is_stable() {
+        let mut ring = HashRing::new(16);
+        for node in ["a", "b", "c"] {
+            ring.add_node(node);
+        }
+        let owners: Vec<&str> = (0..8)
+            .map(|key| *ring.route(&format!("user:{key}")).unwrap())
+            .collect();
+        assert_eq!(owners, vec!["b", "a", "b", "c", "b", "c", "a", "c"]);
+    }
+}
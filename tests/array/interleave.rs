@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::interleave::interleave;
+
+    /// Tests equal-length inputs.
+    ///
+    /// # Expected
+    /// Both modes give the same full round-robin order.
+    #[test]
+    fn test_equal_lengths() {
+        let values = [vec![1, 4], vec![2, 5], vec![3, 6]];
+        assert_eq!(interleave(&values, false), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(interleave(&values, true), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    /// Tests ragged inputs when continuing past exhausted collections.
+    ///
+    /// # Expected
+    /// Every element appears once; exhausted inputs are skipped.
+    #[test]
+    fn test_ragged_continue() {
+        let values: [&[char]; 3] = [&['a'], &['b', 'B', 'β'], &[]];
+        assert_eq!(interleave(&values, false), vec!['a', 'b', 'B', 'β']);
+    }
+
+    /// Tests ragged inputs when stopping at the shortest collection.
+    ///
+    /// # Expected
+    /// Only complete rounds are emitted; an empty input yields nothing.
+    #[test]
+    fn test_ragged_stop() {
+        let values = [vec![1, 2, 3], vec![10, 20]];
+        assert_eq!(interleave(&values, true), vec![1, 10, 2, 20]);
+
+        let with_empty = [vec![1, 2], vec![]];
+        assert!(interleave(&with_empty, true).is_empty());
+    }
+
+    /// Tests no inputs and a single input.
+    ///
+    /// # Expected
+    /// No inputs give an empty result; a single input is returned unchanged.
+    #[test]
+    fn test_degenerate_inputs() {
+        let none: [Vec<u8>; 0] = [];
+        assert!(interleave(&none, false).is_empty());
+        assert!(interleave(&none, true).is_empty());
+        assert_eq!(interleave(&[vec![7, 8, 9]], false), vec![7, 8, 9]);
+    }
+}
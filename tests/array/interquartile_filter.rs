@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::interquartile_filter::{remove_outliers_iqr, remove_outliers_zscore};
+
+    /// Tests removing high and low outliers with the IQR rule.
+    ///
+    /// # Expected
+    /// Values beyond the fences are dropped; the rest keep their order.
+    #[test]
+    fn test_iqr_removes_both_sides() {
+        let mut data = vec![5.0, -100.0, 4.0, 6.0, 5.5, 4.5, 100.0];
+        assert_eq!(remove_outliers_iqr(&mut data, 1.5), Ok(2));
+        assert_eq!(data, vec![5.0, 4.0, 6.0, 5.5, 4.5]);
+    }
+
+    /// Tests that values on the fence are kept.
+    ///
+    /// # Expected
+    /// With Q1 = 2, Q3 = 4 and k = 1, the fences are 0 and 6 inclusive.
+    #[test]
+    fn test_iqr_fence_inclusive() {
+        let mut data = vec![6.0, 2.0, 2.0, 2.0, 3.0, 4.0, 4.0, 4.0, -0.5];
+        assert_eq!(remove_outliers_iqr(&mut data, 1.0), Ok(1));
+        assert_eq!(data, vec![6.0, 2.0, 2.0, 2.0, 3.0, 4.0, 4.0, 4.0]);
+    }
+
+    /// Tests `NaN` handling and empty input for the IQR rule.
+    ///
+    /// # Expected
+    /// `NaN` is always removed; empty input removes nothing.
+    #[test]
+    fn test_iqr_nan_and_empty() {
+        let mut data = vec![1.0, f64::NAN, 1.0];
+        assert_eq!(remove_outliers_iqr(&mut data, 1.5), Ok(1));
+        assert_eq!(data, vec![1.0, 1.0]);
+
+        let mut empty: Vec<f64> = vec![];
+        assert_eq!(remove_outliers_iqr(&mut empty, 1.5), Ok(0));
+
+        let mut only_nan = vec![f64::NAN];
+        assert_eq!(remove_outliers_iqr(&mut only_nan, 1.5), Ok(1));
+        assert!(only_nan.is_empty());
+    }
+
+    /// Tests removing values by z-score.
+    ///
+    /// # Expected
+    /// Only the value far from the mean is dropped.
+    #[test]
+    fn test_zscore_removes_far_values() {
+        let mut data = vec![1.0, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 30.0];
+        assert_eq!(remove_outliers_zscore(&mut data, 2.5), Ok(1));
+        assert!(!data.contains(&30.0));
+        assert_eq!(data.len(), 8);
+    }
+
+    /// Tests constant input and `NaN` for the z-score rule.
+    ///
+    /// # Expected
+    /// Nothing finite is removed when the standard deviation is zero.
+    #[test]
+    fn test_zscore_constant_and_nan() {
+        let mut data = vec![7.0, f64::NAN, 7.0, 7.0];
+        assert_eq!(remove_outliers_zscore(&mut data, 0.0), Ok(1));
+        assert_eq!(data, vec![7.0, 7.0, 7.0]);
+    }
+
+    /// Tests invalid multipliers.
+    ///
+    /// # Expected
+    /// Negative and non-finite values are rejected without modifying the vector.
+    #[test]
+    fn test_invalid_arguments() {
+        let mut data = vec![1.0, 100.0];
+        assert!(remove_outliers_iqr(&mut data, -1.0).is_err());
+        assert!(remove_outliers_iqr(&mut data, f64::NAN).is_err());
+        assert!(remove_outliers_zscore(&mut data, f64::INFINITY).is_err());
+        assert_eq!(data, vec![1.0, 100.0]);
+    }
+}
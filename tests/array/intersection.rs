@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::intersection::intersection;
+    use pencil_box::array::intersection::{
+        intersection, intersection_performant, intersection_secure,
+    };
 
     use std::collections::HashSet;
 
@@ -182,4 +184,24 @@ mod tests {
         assert_eq!(result.len(), 1);
         matches!(result[0], &MyEnum::B);
     }
+
+    /// 🧪 Hasher variants
+    ///
+    /// Expect: `intersection`, `intersection_secure`, and `intersection_performant` return the
+    /// same elements, whatever the `fast-hash` feature.
+    #[test]
+    fn test_variants_agree() {
+        let a: Vec<u32> = (0..200).collect();
+        let b: Vec<u32> = (100..300).collect();
+        let c: Vec<u32> = (0..400).step_by(2).collect();
+        let inputs = [a, b, c];
+
+        let default: HashSet<u32> = intersection(&inputs).into_iter().collect();
+        let secure: HashSet<u32> = intersection_secure(&inputs).into_iter().collect();
+        let performant: HashSet<u32> = intersection_performant(&inputs).into_iter().collect();
+
+        assert_eq!(default.len(), 50);
+        assert_eq!(default, secure);
+        assert_eq!(default, performant);
+    }
 }
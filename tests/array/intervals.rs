@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::intervals::{
+        find_gaps, intersect_sets, merge_overlapping, total_coverage,
+    };
+
+    /// Tests merging unsorted, overlapping, and touching intervals.
+    ///
+    /// # Expected
+    /// Produces sorted, disjoint intervals with touching ones combined.
+    #[test]
+    fn test_merge_overlapping() {
+        let merged = merge_overlapping(&[(10, 12), (1, 5), (2, 3), (5, 7), (9, 10)]);
+        assert_eq!(merged, vec![(1, 7), (9, 12)]);
+    }
+
+    /// Tests merging with empty and inverted intervals.
+    ///
+    /// # Expected
+    /// Degenerate intervals are dropped.
+    #[test]
+    fn test_merge_ignores_degenerate() {
+        assert_eq!(merge_overlapping(&[(3, 3), (5, 1), (0, 1)]), vec![(0, 1)]);
+        assert!(merge_overlapping::<i32>(&[]).is_empty());
+    }
+
+    /// Tests intersecting two interval sets.
+    ///
+    /// # Expected
+    /// Only the shared portions are returned; touching endpoints do not count.
+    #[test]
+    fn test_intersect_sets() {
+        let a = [(0, 5), (10, 20)];
+        let b = [(3, 12), (20, 25), (15, 16)];
+        assert_eq!(intersect_sets(&a, &b), vec![(3, 5), (10, 12), (15, 16)]);
+    }
+
+    /// Tests intersecting with a disjoint or empty set.
+    ///
+    /// # Expected
+    /// Returns an empty vector.
+    #[test]
+    fn test_intersect_disjoint() {
+        assert!(intersect_sets(&[(0, 5)], &[(5, 10)]).is_empty());
+        assert!(intersect_sets(&[(0, 5)], &[]).is_empty());
+    }
+
+    /// Tests finding gaps inside a range.
+    ///
+    /// # Expected
+    /// Returns uncovered portions, clipped to the range.
+    #[test]
+    fn test_find_gaps() {
+        let covered = [(-5, 2), (4, 6), (5, 8), (12, 20)];
+        assert_eq!(find_gaps((0, 15), &covered), vec![(2, 4), (8, 12)]);
+    }
+
+    /// Tests gap finding with full, no, and degenerate coverage.
+    ///
+    /// # Expected
+    /// Fully covered ranges have no gaps; uncovered ranges are one gap; empty ranges have none.
+    #[test]
+    fn test_find_gaps_edges() {
+        assert!(find_gaps((0, 10), &[(0, 10)]).is_empty());
+        assert_eq!(find_gaps((0, 10), &[(20, 30)]), vec![(0, 10)]);
+        assert_eq!(find_gaps((0, 10), &[]), vec![(0, 10)]);
+        assert!(find_gaps((5, 5), &[(0, 1)]).is_empty());
+    }
+
+    /// Tests total coverage with overlaps.
+    ///
+    /// # Expected
+    /// Overlapping regions are counted once.
+    #[test]
+    fn test_total_coverage() {
+        assert_eq!(total_coverage(&[(0, 4), (2, 6), (10, 11)]), 7);
+        assert_eq!(total_coverage::<i64>(&[]), 0);
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::map_in_place::{map_convert, map_in_place};
+
+    /// Tests mapping numbers in place.
+    ///
+    /// # Expected
+    /// Every element is transformed and the buffer is reused.
+    #[test]
+    fn test_map_in_place_reuses_buffer() {
+        let mut values: Vec<u64> = (1..=100).collect();
+        let before = values.as_ptr();
+
+        map_in_place(&mut values, |v| v * 2);
+
+        assert_eq!(values.as_ptr(), before);
+        assert_eq!(values[0], 2);
+        assert_eq!(values[99], 200);
+        assert_eq!(values.len(), 100);
+    }
+
+    /// Tests mapping non-`Clone` values in place.
+    ///
+    /// # Expected
+    /// Elements are moved through the mapper in order.
+    #[test]
+    fn test_map_in_place_moves_values() {
+        let mut boxes = vec![Box::new(1), Box::new(2)];
+        let mut seen = vec![];
+        map_in_place(&mut boxes, |b| {
+            seen.push(*b);
+            Box::new(*b + 10)
+        });
+        assert_eq!(seen, vec![1, 2]);
+        assert_eq!(boxes, vec![Box::new(11), Box::new(12)]);
+    }
+
+    /// Tests converting between types of the same layout.
+    ///
+    /// # Expected
+    /// The allocation is reused for the converted vector.
+    #[test]
+    fn test_map_convert_same_layout() {
+        let values: Vec<u32> = vec![1, 2, 3];
+        let before = values.as_ptr() as usize;
+
+        let converted: Vec<i32> = map_convert(values, |v| -(v as i32));
+
+        assert_eq!(converted.as_ptr() as usize, before);
+        assert_eq!(converted, vec![-1, -2, -3]);
+    }
+
+    /// Tests converting to a type with a different layout, and empty input.
+    ///
+    /// # Expected
+    /// The conversion is correct regardless of allocation reuse.
+    #[test]
+    fn test_map_convert_different_layout() {
+        let words = map_convert(vec![1u8, 22], |v| v.to_string());
+        assert_eq!(words, vec!["1", "22"]);
+
+        let empty: Vec<String> = map_convert(Vec::<u8>::new(), |v| v.to_string());
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::matrix::{transpose, transpose_padded, TransposeError};
+
+    /// Tests transposing a rectangular matrix.
+    ///
+    /// # Expected
+    /// Rows become columns.
+    #[test]
+    fn test_transpose_rectangular() {
+        let rows = vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']];
+        let columns = transpose(&rows).unwrap();
+        assert_eq!(columns, vec![vec!['a', 'c', 'e'], vec!['b', 'd', 'f']]);
+        assert_eq!(transpose(&columns).unwrap(), rows);
+    }
+
+    /// Tests ragged rows.
+    ///
+    /// # Expected
+    /// The first mismatching row is reported with both lengths.
+    #[test]
+    fn test_transpose_ragged() {
+        let rows = vec![vec![1, 2], vec![3, 4], vec![5, 6, 7], vec![8]];
+        let error = transpose(&rows).unwrap_err();
+        assert_eq!(
+            error,
+            TransposeError {
+                row: 2,
+                expected: 2,
+                found: 3
+            }
+        );
+        assert_eq!(error.to_string(), "row 2 has 3 elements, expected 2");
+    }
+
+    /// Tests empty matrices.
+    ///
+    /// # Expected
+    /// No rows or empty rows yield no columns.
+    #[test]
+    fn test_transpose_empty() {
+        let none: Vec<Vec<u8>> = vec![];
+        assert_eq!(transpose(&none), Ok(vec![]));
+
+        let empty_rows: Vec<Vec<u8>> = vec![vec![], vec![]];
+        assert_eq!(transpose(&empty_rows), Ok(vec![]));
+        assert!(transpose_padded(&empty_rows).is_empty());
+    }
+
+    /// Tests padding ragged rows.
+    ///
+    /// # Expected
+    /// Missing cells are filled with the default value.
+    #[test]
+    fn test_transpose_padded() {
+        let rows = vec![vec![1, 2, 3], vec![], vec![4]];
+        assert_eq!(
+            transpose_padded(&rows),
+            vec![vec![1, 0, 4], vec![2, 0, 0], vec![3, 0, 0]]
+        );
+
+        let square = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(transpose_padded(&square), transpose(&square).unwrap());
+    }
+}
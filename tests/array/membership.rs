@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::membership::{ensure_contains, ensure_contains_all, toggle};
+
+    /// Tests pushing a missing value.
+    ///
+    /// # Expected
+    /// The value is appended and `true` is returned.
+    #[test]
+    fn test_ensure_contains_pushes_missing() {
+        let mut data = vec![1, 2];
+        assert!(ensure_contains(&mut data, 3));
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests a value that is already present.
+    ///
+    /// # Expected
+    /// The vector is unchanged and `false` is returned.
+    #[test]
+    fn test_ensure_contains_existing() {
+        let mut data = vec!["a".to_string(), "b".to_string()];
+        assert!(!ensure_contains(&mut data, "a".to_string()));
+        assert_eq!(data, vec!["a", "b"]);
+    }
+
+    /// Tests toggling a value on and off again.
+    ///
+    /// # Expected
+    /// The vector returns to its original contents.
+    #[test]
+    fn test_toggle_round_trip() {
+        let mut data = vec![1, 2];
+        assert!(toggle(&mut data, 3));
+        assert_eq!(data, vec![1, 2, 3]);
+        assert!(!toggle(&mut data, 3));
+        assert_eq!(data, vec![1, 2]);
+    }
+
+    /// Tests toggling a value that appears more than once.
+    ///
+    /// # Expected
+    /// Every occurrence is removed and the remaining order is preserved.
+    #[test]
+    fn test_toggle_removes_all_duplicates() {
+        let mut data = vec![1, 2, 1, 3, 1];
+        assert!(!toggle(&mut data, 1));
+        assert_eq!(data, vec![2, 3]);
+    }
+
+    /// Tests toggling on an empty vector.
+    ///
+    /// # Expected
+    /// The value is pushed.
+    #[test]
+    fn test_toggle_empty() {
+        let mut data: Vec<char> = vec![];
+        assert!(toggle(&mut data, 'x'));
+        assert_eq!(data, vec!['x']);
+    }
+
+    /// Tests the hashed batch variant with overlapping and repeated items.
+    ///
+    /// # Expected
+    /// Only new, distinct items are appended in iteration order.
+    #[test]
+    fn test_ensure_contains_all() {
+        let mut data = vec![5, 1];
+        assert_eq!(ensure_contains_all(&mut data, vec![1, 2, 2, 5, 3]), 2);
+        assert_eq!(data, vec![5, 1, 2, 3]);
+    }
+
+    /// Tests the hashed batch variant with no items.
+    ///
+    /// # Expected
+    /// Nothing is pushed.
+    #[test]
+    fn test_ensure_contains_all_empty_items() {
+        let mut data = vec![1];
+        assert_eq!(ensure_contains_all(&mut data, Vec::new()), 0);
+        assert_eq!(data, vec![1]);
+    }
+}
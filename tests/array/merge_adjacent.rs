@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::merge_adjacent::merge_adjacent;
+
+    fn merge_ranges(a: &(i32, i32), b: &(i32, i32)) -> Option<(i32, i32)> {
+        (b.0 <= a.1).then(|| (a.0, a.1.max(b.1)))
+    }
+
+    /// Tests merging sorted, partially overlapping ranges.
+    ///
+    /// # Expected
+    /// Overlapping runs collapse into single ranges.
+    #[test]
+    fn test_merge_overlapping_ranges() {
+        let mut ranges = vec![(1, 2), (2, 4), (3, 7), (10, 12), (11, 11), (20, 21)];
+        merge_adjacent(&mut ranges, merge_ranges);
+        assert_eq!(ranges, vec![(1, 7), (10, 12), (20, 21)]);
+    }
+
+    /// Tests a closure that never merges.
+    ///
+    /// # Expected
+    /// The vector remains unchanged.
+    #[test]
+    fn test_no_merges() {
+        let mut data = vec![1, 2, 3];
+        merge_adjacent(&mut data, |_, _| None);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests a closure that always merges.
+    ///
+    /// # Expected
+    /// Everything collapses into a single value.
+    #[test]
+    fn test_merge_everything() {
+        let mut data = vec![1, 2, 3, 4];
+        merge_adjacent(&mut data, |a, b| Some(a + b));
+        assert_eq!(data, vec![10]);
+    }
+
+    /// Tests that only neighbours are compared.
+    ///
+    /// # Expected
+    /// Equal values separated by a different one are not merged.
+    #[test]
+    fn test_only_neighbours_merge() {
+        let mut data = vec![(1, 'a'), (2, 'a'), (1, 'b'), (3, 'a')];
+        merge_adjacent(&mut data, |a, b| (a.1 == b.1).then(|| (a.0 + b.0, a.1)));
+        assert_eq!(data, vec![(3, 'a'), (1, 'b'), (3, 'a')]);
+    }
+
+    /// Tests empty and single-element vectors.
+    ///
+    /// # Expected
+    /// The closure is never called and the vector is unchanged.
+    #[test]
+    fn test_short_inputs() {
+        let mut empty: Vec<String> = vec![];
+        merge_adjacent(&mut empty, |_, _| panic!("should not be called"));
+        assert!(empty.is_empty());
+
+        let mut single = vec!["x".to_string()];
+        merge_adjacent(&mut single, |_, _| panic!("should not be called"));
+        assert_eq!(single, vec!["x"]);
+    }
+
+    /// Tests that merging happens in place.
+    ///
+    /// # Expected
+    /// The vector keeps its buffer and capacity, and merged-away elements are dropped.
+    #[test]
+    fn test_in_place() {
+        let mut values: Vec<String> = Vec::with_capacity(32);
+        values.extend(["a", "a", "b", "c", "c", "c"].map(String::from));
+        let buffer = values.as_ptr();
+
+        merge_adjacent(&mut values, |a, b| {
+            (a[..1] == b[..1]).then(|| format!("{a}{b}"))
+        });
+        assert_eq!(values, vec!["aa", "b", "ccc"]);
+        assert_eq!(values.capacity(), 32);
+        assert_eq!(values.as_ptr(), buffer);
+    }
+}
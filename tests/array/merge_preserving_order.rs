@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::merge_preserving_order::{merge_preserving_order, MergeSummary};
+
+    /// Tests appending without removal.
+    ///
+    /// # Expected
+    /// Existing items stay in place, new ones are appended in incoming order.
+    #[test]
+    fn test_append_only() {
+        let mut local = vec![3, 1, 2];
+        let summary = merge_preserving_order(&mut local, &[5, 1, 4, 3], false);
+        assert_eq!(local, vec![3, 1, 2, 5, 4]);
+        assert_eq!(
+            summary,
+            MergeSummary {
+                added: 2,
+                removed: 0
+            }
+        );
+    }
+
+    /// Tests syncing with removal of missing items.
+    ///
+    /// # Expected
+    /// Items absent from `incoming` are dropped; the rest keep their local order.
+    #[test]
+    fn test_remove_missing() {
+        let mut local = vec!['a', 'b', 'c', 'd'];
+        let summary = merge_preserving_order(&mut local, &['d', 'b', 'e'], true);
+        assert_eq!(local, vec!['b', 'd', 'e']);
+        assert_eq!(
+            summary,
+            MergeSummary {
+                added: 1,
+                removed: 2
+            }
+        );
+    }
+
+    /// Tests duplicates in `incoming` and in `current`.
+    ///
+    /// # Expected
+    /// Incoming duplicates are appended once; existing duplicates are kept.
+    #[test]
+    fn test_duplicates() {
+        let mut local = vec![1, 1];
+        let summary = merge_preserving_order(&mut local, &[2, 2, 1, 2], false);
+        assert_eq!(local, vec![1, 1, 2]);
+        assert_eq!(summary.added, 1);
+    }
+
+    /// Tests empty inputs.
+    ///
+    /// # Expected
+    /// An empty `incoming` with removal clears the list; an empty `current` takes `incoming`
+    /// deduplicated.
+    #[test]
+    fn test_empty_sides() {
+        let mut local = vec!["x", "y"];
+        let summary = merge_preserving_order(&mut local, &[], true);
+        assert!(local.is_empty());
+        assert_eq!(
+            summary,
+            MergeSummary {
+                added: 0,
+                removed: 2
+            }
+        );
+
+        let mut local: Vec<&str> = Vec::new();
+        merge_preserving_order(&mut local, &["a", "b", "a"], true);
+        assert_eq!(local, vec!["a", "b"]);
+    }
+
+    /// Tests that a repeated sync is a no-op.
+    ///
+    /// # Expected
+    /// The second merge adds and removes nothing.
+    #[test]
+    fn test_idempotent() {
+        let incoming = vec![String::from("a"), String::from("b")];
+        let mut local = vec![String::from("b"), String::from("z")];
+        merge_preserving_order(&mut local, &incoming, true);
+        let snapshot = local.clone();
+        let summary = merge_preserving_order(&mut local, &incoming, true);
+        assert_eq!(local, snapshot);
+        assert_eq!(summary, MergeSummary::default());
+    }
+}
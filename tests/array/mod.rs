@@ -1,13 +1,81 @@
+mod access;
+mod arg_extremes;
+mod assign;
+mod batch_dedup_against;
+mod cartesian_product;
+mod change_points;
+mod checked_index;
 mod chunk;
+mod chunk_transpose;
+mod clamp_len;
 mod compact;
+mod connected_components;
+mod dedup_paths;
+mod delimited;
+mod diff_ops;
 mod difference;
+mod downsample_lttb;
 mod drop_end;
 mod drop_start;
+mod either;
+mod extract_n;
 mod fill_default;
+mod fill_pattern;
 mod fill_value;
+mod fill_with;
 mod find_index;
 mod find_indexes;
 mod find_last_index;
 mod flatten;
+mod hash_ring;
+mod interleave;
+mod interquartile_filter;
 mod intersection;
+mod intervals;
+mod map_in_place;
+mod matrix;
+mod membership;
+mod merge_adjacent;
+mod merge_preserving_order;
+mod monotonic_check;
+mod move_item;
+mod nth;
+mod order;
+mod order_by;
+mod permutations;
+mod pull;
+mod range;
+mod range_set;
+mod ranges;
+mod remove_at_indices;
+mod remove_matching;
+mod resample;
+#[cfg(feature = "rand")]
+mod reservoir_sample;
+mod retain_with_index;
+mod rotate;
+#[cfg(feature = "rand")]
+mod sample_stratified;
+#[cfg(feature = "rand")]
+mod sample_weighted;
+#[cfg(feature = "rand")]
+mod shuffle;
+#[cfg(feature = "rand")]
+mod shuffle_groups_preserving_members;
+mod slice;
+mod sort_by_frequency;
+mod sorted_index;
+mod sorted_insert;
+mod split_at_many;
+mod split_when;
+mod stable_hash;
+mod take_end;
+mod take_start;
+mod take_until_total;
+mod topo_sort;
+mod truncate_middle;
+mod try_map;
 mod uniq;
+mod uniq_by_distance;
+mod windowed_rate;
+mod without;
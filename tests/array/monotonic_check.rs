@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::monotonic_check::{
+        is_decreasing, is_increasing, is_sorted, is_sorted_by_key, is_strictly_decreasing,
+        is_strictly_increasing, monotonic_direction,
+    };
+    use std::cmp::Ordering;
+
+    /// ✅ Non-decreasing and strictly increasing checks.
+    /// Expects repeated values to be accepted only by the non-strict check.
+    #[test]
+    fn test_increasing() {
+        assert!(is_increasing(&[1, 2, 2, 3]));
+        assert!(!is_strictly_increasing(&[1, 2, 2, 3]));
+        assert!(is_strictly_increasing(&[1, 2, 3]));
+        assert!(!is_increasing(&[2, 1]));
+    }
+
+    /// ✅ Non-increasing and strictly decreasing checks.
+    /// Expects repeated values to be accepted only by the non-strict check.
+    #[test]
+    fn test_decreasing() {
+        assert!(is_decreasing(&["c", "b", "b", "a"]));
+        assert!(!is_strictly_decreasing(&["c", "b", "b", "a"]));
+        assert!(is_strictly_decreasing(&["c", "b", "a"]));
+        assert!(!is_decreasing(&["a", "b"]));
+    }
+
+    /// ✅ Empty and single-element slices.
+    /// Expects every check to hold and the direction to be `Equal`.
+    #[test]
+    fn test_trivial_inputs() {
+        let empty: [i32; 0] = [];
+        for values in [&empty[..], &[42][..]] {
+            assert!(is_increasing(values));
+            assert!(is_strictly_increasing(values));
+            assert!(is_decreasing(values));
+            assert!(is_strictly_decreasing(values));
+            assert_eq!(monotonic_direction(values), Some(Ordering::Equal));
+        }
+    }
+
+    /// ✅ `NaN` is incomparable.
+    /// Expects all checks to fail and the direction to be `None`.
+    #[test]
+    fn test_nan() {
+        let values = [1.0, f64::NAN, 2.0];
+        assert!(!is_increasing(&values));
+        assert!(!is_decreasing(&values));
+        assert_eq!(monotonic_direction(&values), None);
+    }
+
+    /// ✅ Direction detection across plateaus.
+    /// Expects equal neighbours to be ignored when deciding the direction.
+    #[test]
+    fn test_direction() {
+        assert_eq!(monotonic_direction(&[5, 5, 6, 6, 8]), Some(Ordering::Less));
+        assert_eq!(monotonic_direction(&[8, 8, 3]), Some(Ordering::Greater));
+        assert_eq!(monotonic_direction(&[4, 4, 4]), Some(Ordering::Equal));
+        assert_eq!(monotonic_direction(&[1, 2, 2, 1]), None);
+        assert_eq!(monotonic_direction(&[3, 2, 3]), None);
+    }
+
+    /// ✅ Validates timestamps from a sensor stream.
+    /// Expects a duplicated timestamp to break strict monotonicity only.
+    #[test]
+    fn test_timestamps() {
+        let timestamps: Vec<u64> = vec![1_700_000_000, 1_700_000_010, 1_700_000_010];
+        assert!(is_increasing(&timestamps));
+        assert!(!is_strictly_increasing(&timestamps));
+    }
+
+    /// ✅ Checks `is_sorted` against sorting the input.
+    /// Expects `true` exactly when sorting would leave the slice unchanged.
+    #[test]
+    fn test_is_sorted() {
+        for values in [vec![], vec![1], vec![1, 1, 2], vec![2, 1], vec![1, 3, 2, 4]] {
+            let mut sorted = values.clone();
+            sorted.sort();
+            assert_eq!(is_sorted(&values), values == sorted);
+        }
+        assert!(!is_sorted(&[1.0, f64::NAN, 2.0]));
+    }
+
+    /// ✅ Checks `is_sorted_by_key` with derived keys.
+    /// Expects the key to decide the order and `NaN` keys to fail.
+    #[test]
+    fn test_is_sorted_by_key() {
+        let words = ["a", "bb", "cc", "ddd"];
+        assert!(is_sorted_by_key(&words, |w| w.len()));
+        assert!(!is_sorted_by_key(&words, |w| std::cmp::Reverse(w.len())));
+        assert!(is_sorted_by_key::<i32, i32, _>(&[], |_| unreachable!()));
+        assert!(!is_sorted_by_key(&[1.0, 2.0], |&x: &f64| if x > 1.5 {
+            f64::NAN
+        } else {
+            x
+        }));
+    }
+
+    /// ✅ Counts key extractions.
+    /// Expects each key to be computed once.
+    #[test]
+    fn test_is_sorted_by_key_calls() {
+        let mut calls = 0;
+        assert!(is_sorted_by_key(&[1, 2, 3, 4], |&x| {
+            calls += 1;
+            x
+        }));
+        assert_eq!(calls, 4);
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::move_item::{move_item, MoveError};
+
+    /// Tests moving an element forward.
+    ///
+    /// # Expected
+    /// Elements in between shift one place back.
+    #[test]
+    fn test_move_forward() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        move_item(&mut data, 1, 3).unwrap();
+        assert_eq!(data, vec![1, 3, 4, 2, 5]);
+    }
+
+    /// Tests moving an element backward.
+    ///
+    /// # Expected
+    /// Elements in between shift one place forward.
+    #[test]
+    fn test_move_backward() {
+        let mut data = vec!["a", "b", "c", "d", "e"];
+        move_item(&mut data, 4, 1).unwrap();
+        assert_eq!(data, vec!["a", "e", "b", "c", "d"]);
+    }
+
+    /// Tests moving an element onto its own position.
+    ///
+    /// # Expected
+    /// The vector is unchanged.
+    #[test]
+    fn test_same_index() {
+        let mut data = vec![String::from("x"), String::from("y")];
+        move_item(&mut data, 1, 1).unwrap();
+        assert_eq!(data, vec!["x", "y"]);
+    }
+
+    /// Tests out-of-bounds indices.
+    ///
+    /// # Expected
+    /// A typed error naming the offending index; the vector is unchanged.
+    #[test]
+    fn test_out_of_bounds() {
+        let mut data = vec![1, 2, 3];
+        assert_eq!(
+            move_item(&mut data, 3, 0),
+            Err(MoveError::FromOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            move_item(&mut data, 0, 9),
+            Err(MoveError::ToOutOfBounds { index: 9, len: 3 })
+        );
+        assert_eq!(data, vec![1, 2, 3]);
+
+        let mut empty: Vec<u8> = vec![];
+        let error = move_item(&mut empty, 0, 0).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "from index 0 is out of bounds for length 0"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::nth::nth;
+
+    /// Tests non-negative indices.
+    ///
+    /// # Expected
+    /// Behaves like `slice::get`.
+    #[test]
+    fn test_positive_index() {
+        let data = vec![10, 20, 30];
+        assert_eq!(nth(&data, 0), Some(&10));
+        assert_eq!(nth(&data, 2), Some(&30));
+        assert_eq!(nth(&data, 3), None);
+    }
+
+    /// Tests negative indices.
+    ///
+    /// # Expected
+    /// `-1` is the last element and `-len` the first.
+    #[test]
+    fn test_negative_index() {
+        let data = vec![10, 20, 30];
+        assert_eq!(nth(&data, -1), Some(&30));
+        assert_eq!(nth(&data, -3), Some(&10));
+        assert_eq!(nth(&data, -4), None);
+    }
+
+    /// Tests extreme index values.
+    ///
+    /// # Expected
+    /// Returns `None` without panicking.
+    #[test]
+    fn test_extreme_indices() {
+        let data = vec![1];
+        assert_eq!(nth(&data, isize::MIN), None);
+        assert_eq!(nth(&data, isize::MAX), None);
+    }
+
+    /// Tests an empty slice.
+    ///
+    /// # Expected
+    /// Every index returns `None`.
+    #[test]
+    fn test_empty_slice() {
+        let data: Vec<String> = vec![];
+        assert_eq!(nth(&data, 0), None);
+        assert_eq!(nth(&data, -1), None);
+    }
+
+    /// Tests lookups in a constant table at compile time.
+    ///
+    /// # Expected
+    /// Positive and negative indices resolve in `const` items.
+    #[test]
+    fn test_const_evaluation() {
+        const LEVELS: [&str; 3] = ["debug", "info", "warn"];
+        const MOST_SEVERE: Option<&&str> = nth(&LEVELS, -1);
+        const MISSING: Option<&&str> = nth(&LEVELS, 3);
+
+        assert_eq!(MOST_SEVERE, Some(&"warn"));
+        assert_eq!(MISSING, None);
+    }
+}
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::order::Order;
+
+    /// Tests the default direction.
+    ///
+    /// # Expected
+    /// `Order::default()` is ascending.
+    #[test]
+    fn test_default_is_ascending() {
+        assert_eq!(Order::default(), Order::Ascending);
+    }
+
+    /// Tests reversing a direction.
+    ///
+    /// # Expected
+    /// Each direction flips to the other, and reversing twice is the identity.
+    #[test]
+    fn test_reverse() {
+        assert_eq!(Order::Ascending.reverse(), Order::Descending);
+        assert_eq!(Order::Descending.reverse(), Order::Ascending);
+        assert_eq!(Order::Descending.reverse().reverse(), Order::Descending);
+    }
+}
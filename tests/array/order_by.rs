@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::order_by::{order_by, order_by_many, Order, SortKey};
+
+    /// Tests ascending and descending order.
+    ///
+    /// # Expected
+    /// The keys are sorted in the requested direction.
+    #[test]
+    fn test_directions() {
+        let mut values = vec![-3, 1, -2, 4];
+        order_by(&mut values, |v: &i32| v.abs(), Order::Ascending);
+        assert_eq!(values, vec![1, -2, -3, 4]);
+        order_by(&mut values, |v: &i32| v.abs(), Order::Descending);
+        assert_eq!(values, vec![4, -3, -2, 1]);
+    }
+
+    /// Tests stability for equal keys.
+    ///
+    /// # Expected
+    /// Equal keys keep their original relative order in both directions.
+    #[test]
+    fn test_stable() {
+        let original = vec![("a", 2), ("b", 1), ("c", 2), ("d", 1)];
+
+        let mut asc = original.clone();
+        order_by(&mut asc, |p| p.1, Order::Ascending);
+        assert_eq!(asc, vec![("b", 1), ("d", 1), ("a", 2), ("c", 2)]);
+
+        let mut desc = original;
+        order_by(&mut desc, |p| p.1, Order::Descending);
+        assert_eq!(desc, vec![("a", 2), ("c", 2), ("b", 1), ("d", 1)]);
+    }
+
+    /// Tests that keys are computed once per element.
+    ///
+    /// # Expected
+    /// The key closure runs exactly `n` times.
+    #[test]
+    fn test_key_computed_once() {
+        let mut values: Vec<u32> = (0..50).rev().collect();
+        let mut calls = 0;
+        order_by(
+            &mut values,
+            |v| {
+                calls += 1;
+                v.to_string()
+            },
+            Order::Ascending,
+        );
+        assert_eq!(calls, 50);
+        assert_eq!(values[..3], [0, 1, 10]);
+    }
+
+    /// Tests the default direction and trivial inputs.
+    ///
+    /// # Expected
+    /// `Order::default()` is ascending; empty input is left unchanged.
+    #[test]
+    fn test_default_and_empty() {
+        assert_eq!(Order::default(), Order::Ascending);
+        let mut empty: Vec<i32> = Vec::new();
+        order_by(&mut empty, |v| *v, Order::Descending);
+        assert!(empty.is_empty());
+    }
+
+    /// Tests sorting by two columns with mixed directions.
+    ///
+    /// # Expected
+    /// The first key decides, the second breaks ties in its own direction.
+    #[test]
+    fn test_many_mixed_directions() {
+        let mut rows = vec![("b", 1), ("a", 1), ("b", 3), ("a", 2)];
+        order_by_many(
+            &mut rows,
+            &[
+                SortKey::desc(|r: &(&str, i32)| r.0),
+                SortKey::asc(|r: &(&str, i32)| r.1),
+            ],
+        );
+        assert_eq!(rows, vec![("b", 1), ("b", 3), ("a", 1), ("a", 2)]);
+    }
+
+    /// Tests keys of different types and stability on full ties.
+    ///
+    /// # Expected
+    /// Elements equal on every key keep their original order.
+    #[test]
+    fn test_many_stable_heterogeneous() {
+        let mut rows = vec![(2, "x", 'p'), (1, "y", 'q'), (2, "x", 'r'), (1, "x", 's')];
+        order_by_many(
+            &mut rows,
+            &[
+                SortKey::new(|r: &(i32, &str, char)| r.0, Order::Ascending),
+                SortKey::new(|r: &(i32, &str, char)| r.1.to_string(), Order::Ascending),
+            ],
+        );
+        let tags: Vec<char> = rows.iter().map(|r| r.2).collect();
+        assert_eq!(tags, vec!['s', 'q', 'p', 'r']);
+    }
+
+    /// Tests that a single key matches `order_by`, and no keys is a no-op.
+    ///
+    /// # Expected
+    /// Same result as `order_by`; an empty key list leaves the order unchanged.
+    #[test]
+    fn test_many_single_and_empty() {
+        let original = vec![5, 3, 9, 1, 3];
+
+        let mut single = original.clone();
+        order_by_many(&mut single, &[SortKey::desc(|v: &i32| *v)]);
+        let mut expected = original.clone();
+        order_by(&mut expected, |v| *v, Order::Descending);
+        assert_eq!(single, expected);
+
+        let mut untouched = original.clone();
+        order_by_many(&mut untouched, &[]);
+        assert_eq!(untouched, original);
+        assert_eq!(SortKey::desc(|v: &i32| *v).order(), Order::Descending);
+    }
+}
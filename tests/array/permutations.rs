@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::permutations::{permutations, permutations_iter};
+    use std::collections::HashSet;
+
+    /// Tests full permutations of a small input.
+    ///
+    /// # Expected
+    /// All 24 orderings appear once, in lexicographic order.
+    #[test]
+    fn test_full_permutations() {
+        let all = permutations(&[1, 2, 3, 4], 4);
+        assert_eq!(all.len(), 24);
+        let unique: HashSet<_> = all.iter().cloned().collect();
+        assert_eq!(unique.len(), 24);
+        let mut sorted = all.clone();
+        sorted.sort();
+        assert_eq!(all, sorted);
+    }
+
+    /// Tests partial permutations.
+    ///
+    /// # Expected
+    /// `n! / (n - k)!` arrangements of length `k`, each without repeated positions.
+    #[test]
+    fn test_partial_permutations() {
+        let pairs = permutations(&['a', 'b', 'c', 'd'], 2);
+        assert_eq!(pairs.len(), 12);
+        assert!(pairs.iter().all(|p| p.len() == 2 && p[0] != p[1]));
+        assert_eq!(pairs[0], vec!['a', 'b']);
+        assert_eq!(pairs[11], vec!['d', 'c']);
+    }
+
+    /// Tests the `k == 0` and `k > len` edge cases.
+    ///
+    /// # Expected
+    /// One empty permutation for `k == 0`, none when `k` exceeds the length.
+    #[test]
+    fn test_edge_lengths() {
+        assert_eq!(permutations(&[1, 2], 0), vec![Vec::<i32>::new()]);
+        assert_eq!(permutations::<i32>(&[], 0), vec![Vec::<i32>::new()]);
+        assert!(permutations(&[1, 2], 3).is_empty());
+        assert!(permutations::<i32>(&[], 1).is_empty());
+    }
+
+    /// Tests that duplicates are treated as distinct positions.
+    ///
+    /// # Expected
+    /// `[1, 1]` yields two identical permutations.
+    #[test]
+    fn test_duplicates_by_position() {
+        assert_eq!(permutations(&[1, 1], 2), vec![vec![1, 1], vec![1, 1]]);
+    }
+
+    /// Tests that the iterator is lazy and fused.
+    ///
+    /// # Expected
+    /// Taking a prefix matches the eager result; an exhausted iterator keeps returning `None`.
+    #[test]
+    fn test_iter_lazy_and_fused() {
+        let values: Vec<u32> = (0..10).collect();
+        let first: Vec<Vec<u32>> = permutations_iter(&values, 10).take(3).collect();
+        assert_eq!(first[0], values);
+        assert_eq!(first[1], vec![0, 1, 2, 3, 4, 5, 6, 7, 9, 8]);
+
+        let mut iter = permutations_iter(&["x"], 1);
+        assert_eq!(iter.next(), Some(vec!["x"]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
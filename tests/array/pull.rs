@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::pull::{pull, pull_performant};
+
+    /// Tests removing every occurrence of several values.
+    ///
+    /// # Expected
+    /// All matches are removed and the rest keep their order.
+    #[test]
+    fn test_pull_removes_all_occurrences() {
+        let mut data = vec![1, 2, 3, 1, 2, 3, 4];
+        assert_eq!(pull(&mut data, &[2, 3]), 4);
+        assert_eq!(data, vec![1, 1, 4]);
+    }
+
+    /// Tests owned, non-`Clone`-needing values and duplicate removal lists.
+    ///
+    /// # Expected
+    /// Strings are compared by value and duplicates in `to_remove` change nothing.
+    #[test]
+    fn test_pull_strings() {
+        let mut data = vec!["x".to_string(), "y".to_string(), "x".to_string()];
+        let to_remove = vec!["x".to_string(), "x".to_string()];
+        assert_eq!(pull(&mut data, &to_remove), 2);
+        assert_eq!(data, vec!["y"]);
+    }
+
+    /// Tests empty inputs and values that are absent.
+    ///
+    /// # Expected
+    /// Nothing is removed.
+    #[test]
+    fn test_pull_nothing_to_remove() {
+        let mut data = vec![1, 2];
+        assert_eq!(pull(&mut data, &[]), 0);
+        assert_eq!(pull(&mut data, &[9]), 0);
+        assert_eq!(data, vec![1, 2]);
+
+        let mut empty: Vec<i32> = vec![];
+        assert_eq!(pull(&mut empty, &[1]), 0);
+    }
+
+    /// Tests that the performant variant matches `pull`.
+    ///
+    /// # Expected
+    /// Both variants produce the same vector and count.
+    #[test]
+    fn test_performant_matches_pull() {
+        let original: Vec<u32> = (0..1_000).map(|n| n % 17).collect();
+        let to_remove = [0, 5, 16, 99];
+
+        let mut expected = original.clone();
+        let mut actual = original;
+        assert_eq!(
+            pull(&mut expected, &to_remove),
+            pull_performant(&mut actual, &to_remove)
+        );
+        assert_eq!(actual, expected);
+    }
+}
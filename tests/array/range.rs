@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::range::{range, range_step};
+
+    /// Tests ascending and descending integer ranges.
+    ///
+    /// # Expected
+    /// Counts toward `end`, excluding it.
+    #[test]
+    fn test_range_integers() {
+        assert_eq!(range(2, 6), vec![2, 3, 4, 5]);
+        assert_eq!(range(2, -2), vec![2, 1, 0, -1]);
+        assert_eq!(range(4usize, 1), vec![4, 3, 2]);
+    }
+
+    /// Tests equal bounds.
+    ///
+    /// # Expected
+    /// Returns an empty vector.
+    #[test]
+    fn test_range_empty() {
+        assert!(range(5, 5).is_empty());
+        assert!(range_step(5.0, 5.0, 1.0).is_empty());
+    }
+
+    /// Tests ranges touching the limits of the type.
+    ///
+    /// # Expected
+    /// No overflow occurs.
+    #[test]
+    fn test_range_type_limits() {
+        assert_eq!(range(253u8, 255), vec![253, 254]);
+        assert_eq!(range(1u8, 0), vec![1]);
+        assert_eq!(range(i8::MIN, i8::MAX).len(), 255);
+        assert_eq!(range_step(250u8, 255, 10), vec![250]);
+        assert_eq!(range_step(i64::MIN + 1, i64::MIN, -5), vec![i64::MIN + 1]);
+    }
+
+    /// Tests positive and negative steps.
+    ///
+    /// # Expected
+    /// Values advance by `step` and stop before `end`.
+    #[test]
+    fn test_range_step_directions() {
+        assert_eq!(range_step(0, 20, 5), vec![0, 5, 10, 15]);
+        assert_eq!(range_step(0, -7, -3), vec![0, -3, -6]);
+    }
+
+    /// Tests steps pointing away from `end`, and a zero step.
+    ///
+    /// # Expected
+    /// Returns an empty vector.
+    #[test]
+    fn test_range_step_mismatched_or_zero() {
+        assert!(range_step(0, 10, -1).is_empty());
+        assert!(range_step(10, 0, 1).is_empty());
+        assert!(range_step(0, 10, 0).is_empty());
+        assert!(range_step(0.0, 1.0, f64::NAN).is_empty());
+    }
+
+    /// Tests float ranges without accumulated error.
+    ///
+    /// # Expected
+    /// The tenth element is exactly `start + 10 * step`.
+    #[test]
+    fn test_float_ranges() {
+        let values = range_step(0.0, 1.0, 0.1);
+        assert_eq!(values.len(), 10);
+        assert_eq!(values[3], 0.1 * 3.0);
+        assert_eq!(range(1.5f32, -1.0), vec![1.5, 0.5, -0.5]);
+    }
+}
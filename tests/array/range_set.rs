@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::range_set::RangeSet;
+
+    /// Tests inserting disjoint, overlapping, and touching ranges.
+    ///
+    /// # Expected
+    /// Ranges are kept sorted and coalesced.
+    #[test]
+    fn test_insert_coalesces() {
+        let mut set = RangeSet::new();
+        set.insert((10, 20));
+        set.insert((0, 5));
+        set.insert((30, 40));
+        assert_eq!(set.as_slice(), &[(0, 5), (10, 20), (30, 40)]);
+
+        set.insert((5, 10));
+        assert_eq!(set.as_slice(), &[(0, 20), (30, 40)]);
+
+        set.insert((15, 35));
+        assert_eq!(set.as_slice(), &[(0, 40)]);
+    }
+
+    /// Tests inserting a range already covered by the set.
+    ///
+    /// # Expected
+    /// The set remains unchanged.
+    #[test]
+    fn test_insert_covered_range() {
+        let mut set: RangeSet<u32> = vec![(0, 10)].into();
+        set.insert((2, 8));
+        assert_eq!(set.as_slice(), &[(0, 10)]);
+    }
+
+    /// Tests ignoring empty and inverted ranges.
+    ///
+    /// # Expected
+    /// Neither insert nor remove has any effect.
+    #[test]
+    fn test_degenerate_ranges_ignored() {
+        let mut set = RangeSet::new();
+        set.insert((5, 5));
+        set.insert((7, 3));
+        assert!(set.is_empty());
+
+        set.insert((0, 10));
+        set.remove((4, 4));
+        set.remove((8, 2));
+        assert_eq!(set.as_slice(), &[(0, 10)]);
+    }
+
+    /// Tests removing the middle of a range.
+    ///
+    /// # Expected
+    /// The range is split in two.
+    #[test]
+    fn test_remove_splits() {
+        let mut set: RangeSet<i32> = vec![(0, 10)].into();
+        set.remove((3, 6));
+        assert_eq!(set.as_slice(), &[(0, 3), (6, 10)]);
+    }
+
+    /// Tests removing a span covering several ranges and partial ends.
+    ///
+    /// # Expected
+    /// Fully covered ranges disappear and partial ones are trimmed.
+    #[test]
+    fn test_remove_across_ranges() {
+        let mut set: RangeSet<i32> = vec![(0, 5), (10, 15), (20, 25), (30, 35)].into();
+        set.remove((3, 22));
+        assert_eq!(set.as_slice(), &[(0, 3), (22, 25), (30, 35)]);
+
+        set.remove((100, 200));
+        assert_eq!(set.len(), 3);
+
+        set.remove((-10, 100));
+        assert!(set.is_empty());
+    }
+
+    /// Tests membership checks, including range bounds.
+    ///
+    /// # Expected
+    /// Starts are included and ends are excluded.
+    #[test]
+    fn test_contains() {
+        let set: RangeSet<u64> = vec![(10, 20), (30, 31)].into();
+        assert!(!set.contains(9));
+        assert!(set.contains(10));
+        assert!(set.contains(19));
+        assert!(!set.contains(20));
+        assert!(set.contains(30));
+        assert!(!set.contains(31));
+        assert!(!RangeSet::<u64>::new().contains(0));
+    }
+
+    /// Tests building from unsorted ranges and extending.
+    ///
+    /// # Expected
+    /// Input is normalized the same way as repeated inserts.
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut set: RangeSet<i32> = [(8, 9), (1, 3), (2, 4)].into_iter().collect();
+        assert_eq!(set.as_slice(), &[(1, 4), (8, 9)]);
+
+        set.extend([(4, 8), (20, 21)]);
+        let ranges: Vec<_> = (&set).into_iter().copied().collect();
+        assert_eq!(ranges, vec![(1, 9), (20, 21)]);
+        assert_eq!(Vec::from(set), vec![(1, 9), (20, 21)]);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::ranges::{collapse_to_ranges, explode_ranges};
+
+    /// Tests collapsing sorted runs and isolated values.
+    ///
+    /// # Expected
+    /// Runs become ranges; isolated values become single-element ranges.
+    #[test]
+    fn test_collapse_runs() {
+        assert_eq!(
+            collapse_to_ranges(&[1, 2, 3, 7, 8, 12]),
+            vec![1..=3, 7..=8, 12..=12]
+        );
+    }
+
+    /// Tests collapsing unsorted input with duplicates.
+    ///
+    /// # Expected
+    /// Produces the same ranges as the sorted, deduplicated input.
+    #[test]
+    fn test_collapse_unsorted_with_duplicates() {
+        assert_eq!(collapse_to_ranges(&[5, 3, 4, 4, 1, 5]), vec![1..=1, 3..=5]);
+    }
+
+    /// Tests collapsing at the top of the `u64` range.
+    ///
+    /// # Expected
+    /// No overflow occurs.
+    #[test]
+    fn test_collapse_u64_max() {
+        assert_eq!(
+            collapse_to_ranges(&[u64::MAX, u64::MAX - 1, 0]),
+            vec![0..=0, u64::MAX - 1..=u64::MAX]
+        );
+    }
+
+    /// Tests empty input for both directions.
+    ///
+    /// # Expected
+    /// Returns empty vectors.
+    #[test]
+    fn test_empty() {
+        assert!(collapse_to_ranges(&[]).is_empty());
+        assert!(explode_ranges(&[]).is_empty());
+    }
+
+    /// Tests expanding ranges, including an empty one.
+    ///
+    /// # Expected
+    /// Values appear in range order; empty ranges are skipped.
+    #[test]
+    fn test_explode() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let ranges = [10..=11, 5..=4, 1..=2];
+        assert_eq!(explode_ranges(&ranges), vec![10, 11, 1, 2]);
+    }
+
+    /// Tests that exploding the collapsed form round-trips.
+    ///
+    /// # Expected
+    /// Returns the sorted, deduplicated input.
+    #[test]
+    fn test_round_trip() {
+        let ids = [9, 2, 3, 4, 9, 20, 21];
+        assert_eq!(
+            explode_ranges(&collapse_to_ranges(&ids)),
+            vec![2, 3, 4, 9, 20, 21]
+        );
+    }
+}
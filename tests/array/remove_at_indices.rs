@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::remove_at_indices::remove_at_indices;
+
+    /// Tests removing sorted indices.
+    ///
+    /// # Expected
+    /// The listed positions are removed and the rest keep their order.
+    #[test]
+    fn test_sorted_indices() {
+        let mut data: Vec<i32> = (0..6).collect();
+        assert_eq!(remove_at_indices(&mut data, &[1, 2, 5]), 3);
+        assert_eq!(data, vec![0, 3, 4]);
+    }
+
+    /// Tests unsorted and duplicate indices.
+    ///
+    /// # Expected
+    /// Indices refer to original positions and duplicates count once.
+    #[test]
+    fn test_unsorted_and_duplicate_indices() {
+        let mut data = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(remove_at_indices(&mut data, &[2, 0, 2, 0]), 2);
+        assert_eq!(data, vec!["b"]);
+    }
+
+    /// Tests out-of-range indices and empty inputs.
+    ///
+    /// # Expected
+    /// Out-of-range indices are ignored; nothing panics.
+    #[test]
+    fn test_out_of_range_and_empty() {
+        let mut data = vec![1, 2];
+        assert_eq!(remove_at_indices(&mut data, &[2, usize::MAX]), 0);
+        assert_eq!(remove_at_indices(&mut data, &[]), 0);
+        assert_eq!(data, vec![1, 2]);
+
+        let mut empty: Vec<u8> = vec![];
+        assert_eq!(remove_at_indices(&mut empty, &[0]), 0);
+    }
+
+    /// Tests removing every element.
+    ///
+    /// # Expected
+    /// The vector is emptied.
+    #[test]
+    fn test_remove_all() {
+        let mut data = vec![9, 8, 7];
+        assert_eq!(remove_at_indices(&mut data, &[0, 1, 2]), 3);
+        assert!(data.is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::remove_matching::remove_matching;
+
+    /// Tests removing all matching elements.
+    ///
+    /// # Expected
+    /// Removed and remaining elements both keep their relative order.
+    #[test]
+    fn test_removes_all_matches() {
+        let mut data: Vec<i32> = (1..=10).collect();
+        let evens = remove_matching(&mut data, |x| x % 2 == 0);
+        assert_eq!(evens, vec![2, 4, 6, 8, 10]);
+        assert_eq!(data, vec![1, 3, 5, 7, 9]);
+    }
+
+    /// Tests a predicate that matches nothing or everything.
+    ///
+    /// # Expected
+    /// Either nothing is removed or the vector is emptied.
+    #[test]
+    fn test_none_or_all() {
+        let mut data = vec![String::from("a"), String::from("b")];
+        assert!(remove_matching(&mut data, |_| false).is_empty());
+        assert_eq!(data.len(), 2);
+
+        assert_eq!(remove_matching(&mut data, |_| true), vec!["a", "b"]);
+        assert!(data.is_empty());
+    }
+
+    /// Tests that the predicate is called once per element, in order.
+    ///
+    /// # Expected
+    /// Every element is visited exactly once.
+    #[test]
+    fn test_predicate_called_once_each() {
+        let mut data = vec![3, 1, 4, 1, 5];
+        let mut visited = vec![];
+        remove_matching(&mut data, |x| {
+            visited.push(*x);
+            *x == 1
+        });
+        assert_eq!(visited, vec![3, 1, 4, 1, 5]);
+        assert_eq!(data, vec![3, 4, 5]);
+    }
+
+    /// Tests that removal works in place.
+    ///
+    /// # Expected
+    /// The vector keeps its buffer and spare capacity, whether or not anything matches.
+    #[test]
+    fn test_keeps_capacity() {
+        let mut data: Vec<u32> = Vec::with_capacity(64);
+        data.extend(0..10);
+        let buffer = data.as_ptr();
+
+        assert!(remove_matching(&mut data, |x| *x > 100).is_empty());
+        assert_eq!(
+            remove_matching(&mut data, |x| x % 2 == 0),
+            vec![0, 2, 4, 6, 8]
+        );
+        assert_eq!(data, vec![1, 3, 5, 7, 9]);
+        assert_eq!(data.capacity(), 64);
+        assert_eq!(data.as_ptr(), buffer);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::resample::{resample, ResampleMode};
+
+    /// Tests linear upsampling.
+    ///
+    /// # Expected
+    /// New points lie on the lines between the original samples.
+    #[test]
+    fn test_linear_upsample() {
+        let result = resample(&[1.0, 3.0], 5, ResampleMode::Linear);
+        assert_eq!(result, vec![1.0, 1.5, 2.0, 2.5, 3.0]);
+    }
+
+    /// Tests downsampling in both modes.
+    ///
+    /// # Expected
+    /// Endpoints are kept and the middle point is picked or interpolated.
+    #[test]
+    fn test_downsample() {
+        let series = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(
+            resample(&series, 3, ResampleMode::Nearest),
+            vec![0.0, 3.0, 5.0]
+        );
+        assert_eq!(
+            resample(&series, 3, ResampleMode::Linear),
+            vec![0.0, 2.5, 5.0]
+        );
+    }
+
+    /// Tests that resampling to the same length is the identity.
+    ///
+    /// # Expected
+    /// The output equals the input in both modes.
+    #[test]
+    fn test_same_length_identity() {
+        let series = [0.3, -1.7, 9.1, 4.4];
+        assert_eq!(resample(&series, 4, ResampleMode::Linear), series.to_vec());
+        assert_eq!(resample(&series, 4, ResampleMode::Nearest), series.to_vec());
+    }
+
+    /// Tests edge cases for lengths.
+    ///
+    /// # Expected
+    /// Empty input or zero target yields empty output; single samples are repeated.
+    #[test]
+    fn test_edge_lengths() {
+        assert!(resample(&[], 3, ResampleMode::Linear).is_empty());
+        assert!(resample(&[1.0, 2.0], 0, ResampleMode::Nearest).is_empty());
+        assert_eq!(resample(&[7.0], 3, ResampleMode::Linear), vec![7.0; 3]);
+        assert_eq!(resample(&[7.0, 8.0], 1, ResampleMode::Linear), vec![7.0]);
+    }
+}
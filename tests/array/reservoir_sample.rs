@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::reservoir_sample::reservoir_sample;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Tests that a sample of `k` distinct items is taken from a long stream.
+    ///
+    /// # Expected
+    /// Exactly `k` items, in stream order.
+    #[test]
+    fn test_samples_k_items() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let sample = reservoir_sample(0..10_000, 20, &mut rng);
+        assert_eq!(sample.len(), 20);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    /// Tests short streams and `k = 0`.
+    ///
+    /// # Expected
+    /// Short streams are returned whole; `k = 0` leaves the iterator untouched.
+    #[test]
+    fn test_short_stream_and_zero_k() {
+        let mut rng = StdRng::seed_from_u64(2);
+        assert_eq!(
+            reservoir_sample(vec!["a", "b"], 5, &mut rng),
+            vec!["a", "b"]
+        );
+        assert!(reservoir_sample(Vec::<u8>::new(), 3, &mut rng).is_empty());
+
+        let mut stream = 0..10;
+        assert!(reservoir_sample(stream.by_ref(), 0, &mut rng).is_empty());
+        assert_eq!(stream.next(), Some(0));
+    }
+
+    /// Tests that every item is equally likely to be sampled.
+    ///
+    /// # Expected
+    /// Each of 10 items appears in roughly 30% of samples of size 3.
+    #[test]
+    fn test_uniform_inclusion() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut counts = [0usize; 10];
+        for _ in 0..10_000 {
+            for item in reservoir_sample(0..10, 3, &mut rng) {
+                counts[item] += 1;
+            }
+        }
+        assert!(
+            counts.iter().all(|count| (2_800..3_200).contains(count)),
+            "counts = {counts:?}"
+        );
+    }
+
+    /// Tests reproducibility with non-`Clone` items.
+    ///
+    /// # Expected
+    /// The same seed yields the same sample.
+    #[test]
+    fn test_seeded_is_deterministic() {
+        struct Token(u32);
+        let run = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            reservoir_sample((0..500).map(Token), 4, &mut rng)
+                .into_iter()
+                .map(|token| token.0)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(9), run(9));
+    }
+}
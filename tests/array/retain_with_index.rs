@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::retain_with_index::retain_with_index;
+
+    /// Tests keeping every Nth element.
+    ///
+    /// # Expected
+    /// Only elements at indices divisible by N remain.
+    #[test]
+    fn test_every_nth() {
+        let mut data: Vec<char> = "abcdefg".chars().collect();
+        retain_with_index(&mut data, |index, _| index % 2 == 0);
+        assert_eq!(data, vec!['a', 'c', 'e', 'g']);
+    }
+
+    /// Tests that indices refer to the original positions.
+    ///
+    /// # Expected
+    /// Indices keep counting after removals and each is seen once.
+    #[test]
+    fn test_indices_are_original_positions() {
+        let mut data = vec![10, 20, 30, 40];
+        let mut seen = vec![];
+        retain_with_index(&mut data, |index, value| {
+            seen.push((index, *value));
+            index != 0 && index != 1
+        });
+        assert_eq!(seen, vec![(0, 10), (1, 20), (2, 30), (3, 40)]);
+        assert_eq!(data, vec![30, 40]);
+    }
+
+    /// Tests removing all elements and an empty input.
+    ///
+    /// # Expected
+    /// The vector becomes empty and the predicate is never called for empty input.
+    #[test]
+    fn test_remove_all_and_empty() {
+        let mut data = vec![String::from("x"), String::from("y")];
+        retain_with_index(&mut data, |_, _| false);
+        assert!(data.is_empty());
+
+        let mut empty: Vec<u8> = vec![];
+        retain_with_index(&mut empty, |_, _| panic!("not called"));
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::rotate::rotate;
+
+    /// Tests rotating left with a positive offset.
+    ///
+    /// # Expected
+    /// The first `offset` elements move to the end.
+    #[test]
+    fn test_positive_rotates_left() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        rotate(&mut data, 2);
+        assert_eq!(data, vec![3, 4, 5, 1, 2]);
+    }
+
+    /// Tests rotating right with a negative offset.
+    ///
+    /// # Expected
+    /// The last `|offset|` elements move to the front.
+    #[test]
+    fn test_negative_rotates_right() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        rotate(&mut data, -2);
+        assert_eq!(data, vec![4, 5, 1, 2, 3]);
+    }
+
+    /// Tests offsets beyond the length.
+    ///
+    /// # Expected
+    /// Offsets are reduced modulo the length in both directions.
+    #[test]
+    fn test_wraps_large_offsets() {
+        let mut data = vec!['a', 'b', 'c'];
+        rotate(&mut data, 7);
+        assert_eq!(data, vec!['b', 'c', 'a']);
+        rotate(&mut data, -7);
+        assert_eq!(data, vec!['a', 'b', 'c']);
+        rotate(&mut data, 3);
+        assert_eq!(data, vec!['a', 'b', 'c']);
+    }
+
+    /// Tests empty input and extreme offsets.
+    ///
+    /// # Expected
+    /// No panic for empty vectors or `isize::MIN` / `isize::MAX`.
+    #[test]
+    fn test_empty_and_extreme_offsets() {
+        let mut empty: Vec<String> = vec![];
+        rotate(&mut empty, 3);
+        assert!(empty.is_empty());
+
+        let mut data = vec![1, 2, 3, 4];
+        rotate(&mut data, isize::MIN);
+        assert_eq!(data, vec![1, 2, 3, 4]);
+        rotate(&mut data, isize::MAX);
+        assert_eq!(data, vec![4, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::sample_stratified::sample_stratified;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    /// Tests that each group contributes at most `per_group_n` elements.
+    ///
+    /// # Expected
+    /// Large groups are capped and small groups are kept in full.
+    #[test]
+    fn test_caps_each_group() {
+        let values: Vec<u32> = (0..100).collect();
+        let mut rng = StdRng::seed_from_u64(1);
+        let sample = sample_stratified(
+            &values,
+            |v| if *v < 95 { "big" } else { "small" },
+            3,
+            &mut rng,
+        );
+
+        assert_eq!(sample.iter().filter(|v| **v < 95).count(), 3);
+        assert_eq!(sample.iter().filter(|v| **v >= 95).count(), 3);
+    }
+
+    /// Tests that small groups are returned whole, in input order.
+    ///
+    /// # Expected
+    /// The output equals the input when every group fits.
+    #[test]
+    fn test_small_groups_unchanged() {
+        let values = vec![("a", 1), ("b", 2), ("a", 3)];
+        let mut rng = StdRng::seed_from_u64(2);
+        assert_eq!(sample_stratified(&values, |v| v.0, 5, &mut rng), values);
+    }
+
+    /// Tests that sampled elements keep their original relative order.
+    ///
+    /// # Expected
+    /// The output is a subsequence of the input.
+    #[test]
+    fn test_preserves_input_order() {
+        let values: Vec<u32> = (0..1_000).collect();
+        let mut rng = StdRng::seed_from_u64(3);
+        let sample = sample_stratified(&values, |v| v % 7, 4, &mut rng);
+        assert_eq!(sample.len(), 28);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    /// Tests that seeded generators give reproducible results.
+    ///
+    /// # Expected
+    /// Two runs with the same seed produce the same sample.
+    #[test]
+    fn test_deterministic_with_seed() {
+        let values: Vec<u32> = (0..500).collect();
+        let first = sample_stratified(&values, |v| v % 3, 5, &mut StdRng::seed_from_u64(9));
+        let second = sample_stratified(&values, |v| v % 3, 5, &mut StdRng::seed_from_u64(9));
+        assert_eq!(first, second);
+    }
+
+    /// Tests that every element of a group can be selected.
+    ///
+    /// # Expected
+    /// Over many runs, each of the ten elements is chosen roughly equally often.
+    #[test]
+    fn test_roughly_uniform() {
+        let values: Vec<u32> = (0..10).collect();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+        for _ in 0..5_000 {
+            for value in sample_stratified(&values, |_| (), 1, &mut rng) {
+                *counts.entry(value).or_default() += 1;
+            }
+        }
+        assert_eq!(counts.len(), 10);
+        assert!(counts.values().all(|count| (350..650).contains(count)));
+    }
+
+    /// Tests a zero cap and empty input.
+    ///
+    /// # Expected
+    /// Returns an empty vector without calling `key_fn`.
+    #[test]
+    fn test_zero_cap_and_empty_input() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let values = vec![1, 2, 3];
+        let sample = sample_stratified(&values, |_| -> u8 { panic!("not called") }, 0, &mut rng);
+        assert!(sample.is_empty());
+
+        let empty: Vec<i32> = vec![];
+        assert!(sample_stratified(&empty, |v| *v, 3, &mut rng).is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::sample_weighted::{sample_weighted, sample_weighted_with_replacement};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Tests sampling without replacement returns distinct elements in input order.
+    ///
+    /// # Expected
+    /// Exactly `k` distinct elements, sorted by their input position.
+    #[test]
+    fn test_without_replacement_distinct() {
+        let values: Vec<u32> = (0..50).collect();
+        let weights: Vec<f64> = (1..=50).map(f64::from).collect();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let sample = sample_weighted(&values, &weights, 10, &mut rng).unwrap();
+        assert_eq!(sample.len(), 10);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    /// Tests that heavier elements are chosen more often without replacement.
+    ///
+    /// # Expected
+    /// With weights 1 and 9 and `k = 1`, the heavy element wins about 90% of the time.
+    #[test]
+    fn test_without_replacement_proportional() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let heavy = (0..5_000)
+            .filter(|_| {
+                sample_weighted(&["light", "heavy"], &[1.0, 9.0], 1, &mut rng).unwrap()
+                    == vec!["heavy"]
+            })
+            .count();
+        assert!((4_350..4_650).contains(&heavy), "heavy = {heavy}");
+    }
+
+    /// Tests the skipping phase on a long input.
+    ///
+    /// # Expected
+    /// Inclusion frequencies follow the weights: the heavy tail is picked far more often.
+    #[test]
+    fn test_without_replacement_long_stream() {
+        let values: Vec<usize> = (0..1_000).collect();
+        let weights: Vec<f64> = values
+            .iter()
+            .map(|v| if *v >= 990 { 100.0 } else { 1.0 })
+            .collect();
+        let mut rng = StdRng::seed_from_u64(9);
+
+        let mut tail_hits = 0;
+        for _ in 0..200 {
+            let sample = sample_weighted(&values, &weights, 5, &mut rng).unwrap();
+            assert_eq!(sample.len(), 5);
+            tail_hits += sample.iter().filter(|v| **v >= 990).count();
+        }
+        assert!(tail_hits > 400, "tail_hits = {tail_hits}");
+    }
+
+    /// Tests zero weights and `k` larger than the number of candidates.
+    ///
+    /// # Expected
+    /// Zero-weight elements are never returned; all positive-weight elements are.
+    #[test]
+    fn test_zero_weights_and_large_k() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let sample = sample_weighted(&['a', 'b', 'c', 'd'], &[0.0, 2.0, 0.0, 1.0], 10, &mut rng);
+        assert_eq!(sample, Ok(vec!['b', 'd']));
+
+        let none = sample_weighted(&['a'], &[0.0], 1, &mut rng);
+        assert_eq!(none, Ok(vec![]));
+    }
+
+    /// Tests sampling with replacement.
+    ///
+    /// # Expected
+    /// Exactly `k` draws, distributed according to the weights, never picking zero weights.
+    #[test]
+    fn test_with_replacement_proportional() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let draws = sample_weighted_with_replacement(&[0, 1, 2], &[1.0, 0.0, 3.0], 8_000, &mut rng)
+            .unwrap();
+
+        assert_eq!(draws.len(), 8_000);
+        assert!(!draws.contains(&1));
+        let twos = draws.iter().filter(|v| **v == 2).count();
+        assert!((5_800..6_200).contains(&twos), "twos = {twos}");
+    }
+
+    /// Tests finite weights whose sum overflows `f64`.
+    ///
+    /// # Expected
+    /// The two huge weights are drawn about equally often and the tiny one essentially never.
+    #[test]
+    fn test_with_replacement_huge_weights() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let draws = sample_weighted_with_replacement(
+            &[0, 1, 2],
+            &[f64::MAX, f64::MAX, 1.0],
+            30_000,
+            &mut rng,
+        )
+        .unwrap();
+
+        let counts: Vec<usize> = (0..3)
+            .map(|value| draws.iter().filter(|v| **v == value).count())
+            .collect();
+        assert!((14_400..15_600).contains(&counts[0]), "counts = {counts:?}");
+        assert!((14_400..15_600).contains(&counts[1]), "counts = {counts:?}");
+        assert_eq!(counts[2], 0);
+    }
+
+    /// Tests argument validation.
+    ///
+    /// # Expected
+    /// Mismatched lengths, invalid weights, and all-zero weights with replacement are errors.
+    #[test]
+    fn test_invalid_arguments() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(sample_weighted(&[1, 2], &[1.0], 1, &mut rng).is_err());
+        assert!(sample_weighted(&[1, 2], &[1.0, -1.0], 1, &mut rng).is_err());
+        assert!(sample_weighted(&[1], &[f64::NAN], 1, &mut rng).is_err());
+        assert!(sample_weighted_with_replacement(&[1], &[f64::INFINITY], 1, &mut rng).is_err());
+        assert!(sample_weighted_with_replacement(&[1, 2], &[0.0, 0.0], 1, &mut rng).is_err());
+        assert_eq!(
+            sample_weighted_with_replacement(&[1, 2], &[0.0, 0.0], 0, &mut rng),
+            Ok(vec![])
+        );
+    }
+}
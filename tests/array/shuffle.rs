@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::shuffle::{shuffle, shuffle_thread_rng};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    /// Tests that shuffling keeps every element.
+    ///
+    /// # Expected
+    /// The shuffled vector is a permutation of the input.
+    #[test]
+    fn test_is_permutation() {
+        let mut values: Vec<u32> = (0..100).collect();
+        shuffle(&mut values, &mut StdRng::seed_from_u64(1));
+        assert_ne!(values, (0..100).collect::<Vec<_>>());
+        values.sort();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+    }
+
+    /// Tests reproducibility with a seeded RNG.
+    ///
+    /// # Expected
+    /// The same seed produces the same order.
+    #[test]
+    fn test_seeded_is_deterministic() {
+        let mut first: Vec<char> = "abcdefgh".chars().collect();
+        let mut second = first.clone();
+        shuffle(&mut first, &mut StdRng::seed_from_u64(5));
+        shuffle(&mut second, &mut StdRng::seed_from_u64(5));
+        assert_eq!(first, second);
+    }
+
+    /// Tests that all permutations of three elements appear evenly.
+    ///
+    /// # Expected
+    /// Each of the six orders occurs roughly one sixth of the time.
+    #[test]
+    fn test_uniform_permutations() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut counts: HashMap<Vec<u8>, usize> = HashMap::new();
+        for _ in 0..6_000 {
+            let mut values = vec![1, 2, 3];
+            shuffle(&mut values, &mut rng);
+            *counts.entry(values).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 6);
+        assert!(counts.values().all(|count| (850..1_150).contains(count)));
+    }
+
+    /// Tests empty and single-element inputs, and the thread RNG wrapper.
+    ///
+    /// # Expected
+    /// Short inputs are unchanged; the wrapper keeps every element.
+    #[test]
+    fn test_short_inputs_and_wrapper() {
+        let mut empty: Vec<String> = vec![];
+        shuffle_thread_rng(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = vec![42];
+        shuffle_thread_rng(&mut single);
+        assert_eq!(single, vec![42]);
+
+        let mut values = vec![3, 1, 2];
+        shuffle_thread_rng(&mut values);
+        values.sort();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::shuffle_groups_preserving_members::shuffle_groups_preserving_members;
+    use pencil_box::array::split_when::split_when;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    /// Tests that groups stay contiguous and ordered internally.
+    ///
+    /// # Expected
+    /// Re-splitting the result gives the same set of groups, each unchanged.
+    #[test]
+    fn test_groups_preserved() {
+        let original = vec![1, 1, 1, 2, 3, 3, 4, 4, 4, 4, 5];
+        let mut values = original.clone();
+        shuffle_groups_preserving_members(
+            &mut values,
+            |a, b| a != b,
+            &mut StdRng::seed_from_u64(2),
+        );
+
+        let before: HashSet<Vec<i32>> = split_when(&original, |a, b| a != b).into_iter().collect();
+        let after = split_when(&values, |a, b| a != b);
+        assert_eq!(after.len(), before.len());
+        assert_eq!(after.into_iter().collect::<HashSet<_>>(), before);
+    }
+
+    /// Tests that members keep their relative order.
+    ///
+    /// # Expected
+    /// Elements within each group appear in their original order.
+    #[test]
+    fn test_member_order() {
+        let mut values = vec![('a', 1), ('a', 2), ('a', 3), ('b', 1), ('b', 2), ('c', 1)];
+        shuffle_groups_preserving_members(
+            &mut values,
+            |x, y| x.0 != y.0,
+            &mut StdRng::seed_from_u64(9),
+        );
+        for key in ['a', 'b', 'c'] {
+            let members: Vec<i32> = values.iter().filter(|v| v.0 == key).map(|v| v.1).collect();
+            let mut sorted = members.clone();
+            sorted.sort();
+            assert_eq!(members, sorted);
+        }
+    }
+
+    /// Tests that every group order is reachable.
+    ///
+    /// # Expected
+    /// Three groups produce all six orders over many seeds.
+    #[test]
+    fn test_all_orders_reachable() {
+        let mut seen = HashSet::new();
+        for seed in 0..200 {
+            let mut values = vec![1, 1, 2, 3, 3];
+            shuffle_groups_preserving_members(
+                &mut values,
+                |a, b| a != b,
+                &mut StdRng::seed_from_u64(seed),
+            );
+            seen.insert(values);
+        }
+        assert_eq!(seen.len(), 6);
+    }
+
+    /// Tests inputs with fewer than two groups.
+    ///
+    /// # Expected
+    /// The vector is unchanged.
+    #[test]
+    fn test_single_group() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut empty: Vec<u8> = Vec::new();
+        shuffle_groups_preserving_members(&mut empty, |_, _| true, &mut rng);
+        assert!(empty.is_empty());
+
+        let mut same = vec![4, 4, 4];
+        shuffle_groups_preserving_members(&mut same, |a, b| a != b, &mut rng);
+        assert_eq!(same, vec![4, 4, 4]);
+    }
+
+    /// Tests non-`Clone` elements and the vector's buffer.
+    ///
+    /// # Expected
+    /// Every element is moved back exactly once and `values` keeps its capacity.
+    #[test]
+    fn test_moves_without_clone() {
+        struct Ticket(u32);
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut tickets: Vec<Ticket> = Vec::with_capacity(16);
+        tickets.extend([1, 1, 2, 3, 3, 3].map(Ticket));
+        shuffle_groups_preserving_members(&mut tickets, |a, b| a.0 != b.0, &mut rng);
+
+        let mut ids: Vec<u32> = tickets.iter().map(|ticket| ticket.0).collect();
+        assert_eq!(ids.windows(2).filter(|pair| pair[0] != pair[1]).count(), 2);
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 1, 2, 3, 3, 3]);
+        assert_eq!(tickets.capacity(), 16);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::slice::slice;
+
+    /// Tests a plain positive range.
+    ///
+    /// # Expected
+    /// Behaves like standard range indexing.
+    #[test]
+    fn test_positive_range() {
+        let data = vec![1, 2, 3, 4, 5];
+        assert_eq!(slice(&data, 1, 3), vec![2, 3]);
+        assert_eq!(slice(&data, 0, 5), data);
+    }
+
+    /// Tests negative start and end indices.
+    ///
+    /// # Expected
+    /// Indices count back from the end of the slice.
+    #[test]
+    fn test_negative_indices() {
+        let data = vec![1, 2, 3, 4, 5];
+        assert_eq!(slice(&data, -3, -1), vec![3, 4]);
+        assert_eq!(slice(&data, -2, 5), vec![4, 5]);
+        assert_eq!(slice(&data, 0, -4), vec![1]);
+    }
+
+    /// Tests indices beyond either end.
+    ///
+    /// # Expected
+    /// Indices are clamped and no panic occurs.
+    #[test]
+    fn test_out_of_range_clamped() {
+        let data = vec![1, 2, 3];
+        assert_eq!(slice(&data, -100, 100), vec![1, 2, 3]);
+        assert_eq!(slice(&data, 2, 100), vec![3]);
+        assert!(slice(&data, 5, 10).is_empty());
+        assert_eq!(slice(&data, isize::MIN, isize::MAX), vec![1, 2, 3]);
+    }
+
+    /// Tests ranges where start is not before end.
+    ///
+    /// # Expected
+    /// Returns an empty vector.
+    #[test]
+    fn test_empty_or_reversed_range() {
+        let data = vec![1, 2, 3, 4];
+        assert!(slice(&data, 2, 2).is_empty());
+        assert!(slice(&data, 3, 1).is_empty());
+        assert!(slice(&data, -1, -2).is_empty());
+    }
+
+    /// Tests an empty input slice.
+    ///
+    /// # Expected
+    /// Always returns an empty vector.
+    #[test]
+    fn test_empty_input() {
+        let data: Vec<String> = vec![];
+        assert!(slice(&data, -1, 1).is_empty());
+    }
+
+    /// Tests cloning owned values.
+    ///
+    /// # Expected
+    /// Returned values are clones and the input is untouched.
+    #[test]
+    fn test_owned_values() {
+        let data = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(slice(&data, -2, 3), vec!["b", "c"]);
+        assert_eq!(data.len(), 3);
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::order::Order;
+    use pencil_box::array::sort_by_frequency::sort_by_frequency;
+
+    /// Tests most-common-first ordering.
+    ///
+    /// # Expected
+    /// Values are grouped and ordered by descending count.
+    #[test]
+    fn test_descending() {
+        let mut values = vec![3, 1, 2, 2, 3, 3, 4, 2, 3];
+        sort_by_frequency(&mut values, Order::Descending);
+        assert_eq!(values, vec![3, 3, 3, 3, 2, 2, 2, 1, 4]);
+    }
+
+    /// Tests least-common-first ordering.
+    ///
+    /// # Expected
+    /// Values are grouped and ordered by ascending count.
+    #[test]
+    fn test_ascending() {
+        let mut values = vec!['b', 'a', 'b', 'c', 'a', 'b'];
+        sort_by_frequency(&mut values, Order::Ascending);
+        assert_eq!(values, vec!['c', 'a', 'a', 'b', 'b', 'b']);
+    }
+
+    /// Tests tie-breaking between equally common values.
+    ///
+    /// # Expected
+    /// Ties follow first appearance in both directions.
+    #[test]
+    fn test_ties_by_first_appearance() {
+        let mut values = vec!["y", "x", "z", "x", "y", "z"];
+        sort_by_frequency(&mut values, Order::Descending);
+        assert_eq!(values, vec!["y", "y", "x", "x", "z", "z"]);
+        sort_by_frequency(&mut values, Order::Ascending);
+        assert_eq!(values, vec!["y", "y", "x", "x", "z", "z"]);
+    }
+
+    /// Tests empty, single-element, and non-`Clone` inputs.
+    ///
+    /// # Expected
+    /// Small inputs are unchanged; non-`Clone` values are moved.
+    #[test]
+    fn test_small_and_non_clone() {
+        let mut empty: Vec<u8> = Vec::new();
+        sort_by_frequency(&mut empty, Order::Descending);
+        assert!(empty.is_empty());
+
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        struct Token(u8);
+        let mut tokens = vec![Token(1), Token(2), Token(2)];
+        sort_by_frequency(&mut tokens, Order::Descending);
+        assert_eq!(tokens, vec![Token(2), Token(2), Token(1)]);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::sorted_index::{sorted_index, sorted_index_by, sorted_last_index};
+
+    /// Tests insertion points around duplicates.
+    ///
+    /// # Expected
+    /// `sorted_index` points before the run, `sorted_last_index` after it.
+    #[test]
+    fn test_duplicates() {
+        let values = [1, 3, 3, 3, 5];
+        assert_eq!(sorted_index(&values, &3), 1);
+        assert_eq!(sorted_last_index(&values, &3), 4);
+    }
+
+    /// Tests values below, between, and above the slice.
+    ///
+    /// # Expected
+    /// Both functions agree when `item` is not present.
+    #[test]
+    fn test_missing_values() {
+        let values = [10, 20, 30];
+        for (item, expected) in [(0, 0), (15, 1), (25, 2), (35, 3)] {
+            assert_eq!(sorted_index(&values, &item), expected);
+            assert_eq!(sorted_last_index(&values, &item), expected);
+        }
+    }
+
+    /// Tests an empty slice.
+    ///
+    /// # Expected
+    /// The insertion point is 0.
+    #[test]
+    fn test_empty() {
+        assert_eq!(sorted_index::<i32>(&[], &1), 0);
+        assert_eq!(sorted_last_index::<i32>(&[], &1), 0);
+    }
+
+    /// Tests that inserting at the returned index keeps the slice sorted.
+    ///
+    /// # Expected
+    /// The vector stays sorted after every insertion.
+    #[test]
+    fn test_insert_keeps_sorted() {
+        let mut values: Vec<i32> = Vec::new();
+        for item in [5, 1, 4, 1, 3, 9, 2] {
+            let at = sorted_index(&values, &item);
+            values.insert(at, item);
+        }
+        assert_eq!(values, vec![1, 1, 2, 3, 4, 5, 9]);
+    }
+
+    /// Tests key-based lookup.
+    ///
+    /// # Expected
+    /// The key is applied to both the item and the elements.
+    #[test]
+    fn test_by_key() {
+        let words = ["a", "to", "the", "word"];
+        assert_eq!(sorted_index_by(&words, &"cat", |w| w.len()), 2);
+        assert_eq!(sorted_index_by(&words, &"longest", |w| w.len()), 4);
+        assert_eq!(sorted_index_by(&words, &"", |w| w.len()), 0);
+    }
+}
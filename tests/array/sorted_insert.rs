@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::sorted_insert::{sorted_insert, sorted_insert_by_key};
+
+    /// Tests building a sorted vector one element at a time.
+    ///
+    /// # Expected
+    /// The vector is sorted after every insertion.
+    #[test]
+    fn test_keeps_sorted() {
+        let mut values = Vec::new();
+        for item in [7, 3, 9, 1, 3, 8, 0] {
+            sorted_insert(&mut values, item);
+            assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        }
+        assert_eq!(values, vec![0, 1, 3, 3, 7, 8, 9]);
+    }
+
+    /// Tests the returned index at the edges.
+    ///
+    /// # Expected
+    /// Front, back, and empty-vector insertions report the right index.
+    #[test]
+    fn test_returned_index() {
+        let mut values = Vec::new();
+        assert_eq!(sorted_insert(&mut values, 5), 0);
+        assert_eq!(sorted_insert(&mut values, 9), 1);
+        assert_eq!(sorted_insert(&mut values, 2), 0);
+        assert_eq!(sorted_insert(&mut values, 5), 2);
+        assert_eq!(values, vec![2, 5, 5, 9]);
+    }
+
+    /// Tests that ties keep insertion order when sorting by key.
+    ///
+    /// # Expected
+    /// Later elements with an equal key go after earlier ones.
+    #[test]
+    fn test_by_key_stable() {
+        let mut events: Vec<(u32, &str)> = Vec::new();
+        for event in [(2, "b1"), (1, "a1"), (2, "b2"), (1, "a2"), (3, "c1")] {
+            sorted_insert_by_key(&mut events, event, |e| e.0);
+        }
+        let names: Vec<&str> = events.iter().map(|e| e.1).collect();
+        assert_eq!(names, vec!["a1", "a2", "b1", "b2", "c1"]);
+    }
+}
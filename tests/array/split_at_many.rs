@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::split_at_many::split_at_many;
+
+    /// Tests splitting at several interior positions.
+    ///
+    /// # Expected
+    /// One more part than indices, covering every element in order.
+    #[test]
+    fn test_interior_splits() {
+        let parts = split_at_many(&['a', 'b', 'c', 'd', 'e'], &[1, 3]).unwrap();
+        assert_eq!(parts, vec![vec!['a'], vec!['b', 'c'], vec!['d', 'e']]);
+    }
+
+    /// Tests boundary and repeated indices.
+    ///
+    /// # Expected
+    /// Indices at 0, at the end, or repeated produce empty parts.
+    #[test]
+    fn test_empty_parts() {
+        let parts = split_at_many(&[1, 2], &[0, 1, 1, 2]).unwrap();
+        assert_eq!(parts, vec![vec![], vec![1], vec![], vec![2], vec![]]);
+    }
+
+    /// Tests no indices and an empty slice.
+    ///
+    /// # Expected
+    /// The whole slice comes back as a single part.
+    #[test]
+    fn test_no_indices() {
+        assert_eq!(split_at_many(&[1, 2, 3], &[]).unwrap(), vec![vec![1, 2, 3]]);
+        assert_eq!(
+            split_at_many::<i32>(&[], &[0]).unwrap(),
+            vec![Vec::<i32>::new(), Vec::new()]
+        );
+    }
+
+    /// Tests invalid indices.
+    ///
+    /// # Expected
+    /// Decreasing or out-of-bounds indices return the matching error message.
+    #[test]
+    fn test_invalid_indices() {
+        assert_eq!(
+            split_at_many(&[1, 2, 3], &[2, 1]),
+            Err("split indices must be in non-decreasing order")
+        );
+        assert_eq!(
+            split_at_many(&[1, 2, 3], &[1, 4]),
+            Err("split index out of bounds")
+        );
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::split_when::split_when;
+
+    /// Tests splitting on runs of equal values.
+    ///
+    /// # Expected
+    /// A new group starts whenever the value changes.
+    #[test]
+    fn test_runs() {
+        let groups = split_when(&['a', 'a', 'b', 'c', 'c', 'c'], |a, b| a != b);
+        assert_eq!(groups, vec![vec!['a', 'a'], vec!['b'], vec!['c', 'c', 'c']]);
+    }
+
+    /// Tests a predicate that never or always fires.
+    ///
+    /// # Expected
+    /// One group holding everything, or one group per element.
+    #[test]
+    fn test_never_and_always() {
+        assert_eq!(split_when(&[1, 2, 3], |_, _| false), vec![vec![1, 2, 3]]);
+        assert_eq!(
+            split_when(&[1, 2, 3], |_, _| true),
+            vec![vec![1], vec![2], vec![3]]
+        );
+    }
+
+    /// Tests empty and single-element input.
+    ///
+    /// # Expected
+    /// No groups for an empty slice; one group for a single element, with no predicate call.
+    #[test]
+    fn test_small_inputs() {
+        assert!(split_when::<i32, _>(&[], |_, _| true).is_empty());
+        let groups = split_when(&["solo"], |_, _| panic!("predicate must not be called"));
+        assert_eq!(groups, vec![vec!["solo"]]);
+    }
+
+    /// Tests that the predicate sees adjacent pairs in order.
+    ///
+    /// # Expected
+    /// Each `(previous, next)` pair is passed exactly once, left to right.
+    #[test]
+    fn test_predicate_pairs() {
+        let mut seen = Vec::new();
+        split_when(&[1, 2, 3, 4], |a, b| {
+            seen.push((*a, *b));
+            false
+        });
+        assert_eq!(seen, vec![(1, 2), (2, 3), (3, 4)]);
+    }
+}
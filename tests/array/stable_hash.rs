@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::stable_hash::{stable_hash, StableHasher};
+    use std::hash::Hasher;
+
+    /// Tests the output against values computed independently of this crate.
+    ///
+    /// # Expected
+    /// FNV-1a followed by `fmix64` gives exactly these constants.
+    #[test]
+    fn test_known_values() {
+        assert_eq!(StableHasher::new().finish(), 0xefd0_1f60_ba99_2926);
+
+        let mut hasher = StableHasher::default();
+        hasher.write(b"abc");
+        assert_eq!(hasher.finish(), 0x33eb_af99_27cb_c5bd);
+
+        assert_eq!(stable_hash(&42u32), 0xb8ac_a8f2_54d1_6bd2);
+        assert_eq!(stable_hash("abc"), 0x3ee0_641e_1a67_4131);
+    }
+
+    /// Tests that pointer-sized integers hash as 64-bit values.
+    ///
+    /// # Expected
+    /// `usize`/`isize` agree with `u64`/`i64`, so 32-bit and 64-bit targets match.
+    #[test]
+    fn test_pointer_sized_integers() {
+        assert_eq!(stable_hash(&123usize), stable_hash(&123u64));
+        assert_eq!(stable_hash(&-5isize), stable_hash(&-5i64));
+        assert_ne!(stable_hash(&1u32), stable_hash(&1u64));
+    }
+
+    /// Tests that owned and borrowed forms hash alike.
+    ///
+    /// # Expected
+    /// `str` and `String` with the same contents give the same hash.
+    #[test]
+    fn test_borrowed_and_owned() {
+        assert_eq!(stable_hash("node-1"), stable_hash(&"node-1".to_string()));
+        assert_ne!(stable_hash("node-1"), stable_hash("node-2"));
+    }
+}
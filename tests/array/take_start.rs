@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::take_start::take_start;
+
+    /// Tests keeping fewer elements than the vector's length.
+    ///
+    /// # Expected
+    /// Only the first N elements remain, in their original order.
+    #[test]
+    fn test_take_less_than_length() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        take_start(&mut data, 2);
+        assert_eq!(data, vec![1, 2]);
+    }
+
+    /// Tests keeping exactly the vector's length.
+    ///
+    /// # Expected
+    /// The vector remains unchanged.
+    #[test]
+    fn test_take_exact_length() {
+        let mut data = vec![1, 2, 3];
+        take_start(&mut data, 3);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests keeping more elements than the vector contains.
+    ///
+    /// # Expected
+    /// The vector remains unchanged and no panic occurs.
+    #[test]
+    fn test_take_more_than_length() {
+        let mut data = vec![1, 2, 3];
+        take_start(&mut data, 10);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests keeping zero elements.
+    ///
+    /// # Expected
+    /// The vector is cleared.
+    #[test]
+    fn test_take_zero_elements() {
+        let mut data = vec![1, 2, 3];
+        take_start(&mut data, 0);
+        assert!(data.is_empty());
+    }
+
+    /// Tests an empty input vector.
+    ///
+    /// # Expected
+    /// The vector stays empty and no panic occurs.
+    #[test]
+    fn test_take_from_empty_vector() {
+        let mut data: Vec<i32> = vec![];
+        take_start(&mut data, 3);
+        assert!(data.is_empty());
+    }
+
+    /// Tests with owned `String` values.
+    ///
+    /// # Expected
+    /// The first element is kept without cloning.
+    #[test]
+    fn test_take_strings() {
+        let mut data = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        take_start(&mut data, 1);
+        assert_eq!(data, vec!["a"]);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::take_until_total::{split_at_total, take_until_total};
+
+    /// Tests a budget that is hit exactly.
+    ///
+    /// # Expected
+    /// The element that brings the total to the budget is included.
+    #[test]
+    fn test_exact_budget() {
+        let values = [3u64, 4, 3, 1];
+        assert_eq!(take_until_total(&values, |&v| v, 10), &[3, 4, 3]);
+    }
+
+    /// Tests that the result is a prefix.
+    ///
+    /// # Expected
+    /// A lighter element after an overflowing one is not taken.
+    #[test]
+    fn test_prefix_only() {
+        let values = [5u64, 10, 1];
+        assert_eq!(take_until_total(&values, |&v| v, 8), &[5]);
+    }
+
+    /// Tests budgets of zero and a first element that is too heavy.
+    ///
+    /// # Expected
+    /// Leading zero-weight elements are taken; otherwise the prefix is empty.
+    #[test]
+    fn test_zero_and_oversized() {
+        let values = [0u64, 0, 1, 0];
+        assert_eq!(take_until_total(&values, |&v| v, 0), &[0, 0]);
+        assert!(take_until_total(&[100u64], |&v| v, 99).is_empty());
+        assert!(take_until_total::<u64, _>(&[], |&v| v, 10).is_empty());
+    }
+
+    /// Tests that huge weights cannot overflow the running total.
+    ///
+    /// # Expected
+    /// An overflowing sum counts as over budget instead of wrapping.
+    #[test]
+    fn test_overflowing_sum() {
+        let values = [u64::MAX, u64::MAX, 0];
+        assert_eq!(take_until_total(&values, |&v| v, u64::MAX), &[u64::MAX]);
+    }
+
+    /// Tests paging through a slice with `split_at_total`.
+    ///
+    /// # Expected
+    /// Consecutive pages cover every element, each within budget.
+    #[test]
+    fn test_split_pages() {
+        let words = ["alpha", "beta", "gamma", "pi", "delta", "mu"];
+        let mut rest: &[&str] = &words;
+        let mut pages = Vec::new();
+        while !rest.is_empty() {
+            let (page, remaining) = split_at_total(rest, |w| w.len() as u64, 9);
+            assert!(!page.is_empty());
+            pages.push(page.to_vec());
+            rest = remaining;
+        }
+        assert_eq!(
+            pages,
+            vec![
+                vec!["alpha", "beta"],
+                vec!["gamma", "pi"],
+                vec!["delta", "mu"],
+            ]
+        );
+    }
+}
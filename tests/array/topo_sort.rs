@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::topo_sort::{topo_sort, CycleError};
+    use std::collections::HashMap;
+
+    fn graph(edges: &[(&'static str, &'static str)]) -> HashMap<&'static str, Vec<&'static str>> {
+        let mut map: HashMap<_, Vec<_>> = HashMap::new();
+        for (node, dependency) in edges {
+            map.entry(*node).or_default().push(*dependency);
+        }
+        map
+    }
+
+    /// Tests that dependencies come first.
+    ///
+    /// # Expected
+    /// Every node appears after all of its dependencies.
+    #[test]
+    fn test_dependencies_first() {
+        let deps = graph(&[
+            ("app", "lib"),
+            ("lib", "core"),
+            ("app", "core"),
+            ("cli", "app"),
+        ]);
+        let order = topo_sort(&["cli", "app", "lib", "core"], |n| {
+            deps.get(n).cloned().unwrap_or_default()
+        })
+        .unwrap();
+        assert_eq!(order, vec!["core", "lib", "app", "cli"]);
+    }
+
+    /// Tests stability.
+    ///
+    /// # Expected
+    /// Independent nodes keep their input order, and valid input is unchanged.
+    #[test]
+    fn test_stable_order() {
+        let no_deps = topo_sort(&[5, 3, 9, 1], |_| Vec::new()).unwrap();
+        assert_eq!(no_deps, vec![5, 3, 9, 1]);
+
+        let deps = graph(&[("b", "a"), ("d", "c")]);
+        let order = topo_sort(&["a", "b", "c", "d"], |n| {
+            deps.get(n).cloned().unwrap_or_default()
+        })
+        .unwrap();
+        assert_eq!(order, vec!["a", "b", "c", "d"]);
+    }
+
+    /// Tests unknown dependencies and duplicate nodes.
+    ///
+    /// # Expected
+    /// Unknown dependencies are ignored; duplicates appear once.
+    #[test]
+    fn test_unknown_and_duplicates() {
+        let order = topo_sort(&["x", "y", "x"], |n| {
+            if *n == "x" {
+                vec!["y", "external"]
+            } else {
+                vec![]
+            }
+        })
+        .unwrap();
+        assert_eq!(order, vec!["y", "x"]);
+    }
+
+    /// Tests cycle reporting.
+    ///
+    /// # Expected
+    /// The error names exactly the nodes of the cycle, in dependency order.
+    #[test]
+    fn test_cycle_reported() {
+        let deps = graph(&[("a", "b"), ("b", "c"), ("c", "b"), ("d", "a")]);
+        let error = topo_sort(&["d", "a", "b", "c"], |n| {
+            deps.get(n).cloned().unwrap_or_default()
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            CycleError {
+                cycle: vec!["b", "c"]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "dependency cycle: \"b\" -> \"c\" -> \"b\""
+        );
+    }
+
+    /// Tests a node depending on itself.
+    ///
+    /// # Expected
+    /// A single-node cycle.
+    #[test]
+    fn test_self_dependency() {
+        let error = topo_sort(&[1, 2], |n| if *n == 2 { vec![2] } else { vec![] }).unwrap_err();
+        assert_eq!(error.cycle, vec![2]);
+    }
+}
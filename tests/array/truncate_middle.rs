@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::truncate_middle::{truncate_middle, truncate_middle_with_marker};
+
+    /// Tests dropping the middle of a long vector.
+    ///
+    /// # Expected
+    /// Head and tail are kept in order and the dropped count is returned.
+    #[test]
+    fn test_keeps_both_ends() {
+        let mut data: Vec<i32> = (0..8).collect();
+        assert_eq!(truncate_middle(&mut data, 3, 2), 3);
+        assert_eq!(data, vec![0, 1, 2, 6, 7]);
+    }
+
+    /// Tests keeping only one end.
+    ///
+    /// # Expected
+    /// `keep_start = 0` or `keep_end = 0` behaves like dropping from one side.
+    #[test]
+    fn test_one_sided() {
+        let mut data = vec!['a', 'b', 'c', 'd'];
+        assert_eq!(truncate_middle(&mut data, 0, 1), 3);
+        assert_eq!(data, vec!['d']);
+
+        let mut data = vec!['a', 'b', 'c', 'd'];
+        assert_eq!(truncate_middle(&mut data, 1, 0), 3);
+        assert_eq!(data, vec!['a']);
+    }
+
+    /// Tests vectors that are already short enough.
+    ///
+    /// # Expected
+    /// The vector is unchanged, including with overflowing keep counts.
+    #[test]
+    fn test_short_vector_unchanged() {
+        let mut data = vec![1, 2, 3];
+        assert_eq!(truncate_middle(&mut data, 2, 1), 0);
+        assert_eq!(truncate_middle(&mut data, usize::MAX, usize::MAX), 0);
+        assert_eq!(data, vec![1, 2, 3]);
+    }
+
+    /// Tests inserting a marker built from the dropped count.
+    ///
+    /// # Expected
+    /// The marker replaces the dropped middle.
+    #[test]
+    fn test_with_marker() {
+        let mut data: Vec<String> = (0..5).map(|n| n.to_string()).collect();
+        let dropped = truncate_middle_with_marker(&mut data, 1, 2, |n| format!("<{n}>"));
+        assert_eq!(dropped, 2);
+        assert_eq!(data, vec!["0", "<2>", "3", "4"]);
+    }
+
+    /// Tests that the marker is skipped when nothing is dropped.
+    ///
+    /// # Expected
+    /// The marker function is not called.
+    #[test]
+    fn test_marker_not_called_when_short() {
+        let mut data = vec![1, 2];
+        let dropped = truncate_middle_with_marker(&mut data, 1, 1, |_| panic!("not called"));
+        assert_eq!(dropped, 0);
+        assert_eq!(data, vec![1, 2]);
+    }
+}
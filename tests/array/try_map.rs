@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::try_map::try_map;
+    use std::error::Error;
+
+    /// Tests a conversion where every element succeeds.
+    ///
+    /// # Expected
+    /// All converted values are returned in order.
+    #[test]
+    fn test_all_succeed() {
+        let result = try_map(&["1", "2", "3"], |s| s.parse::<i32>());
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+    }
+
+    /// Tests the first failing element is reported with context.
+    ///
+    /// # Expected
+    /// The error carries the index, the input, and the source error.
+    #[test]
+    fn test_reports_first_failure() {
+        let inputs = vec!["7".to_string(), "x".to_string(), "y".to_string()];
+        let error = try_map(&inputs, |s| s.parse::<u8>()).unwrap_err();
+
+        assert_eq!(error.index, 1);
+        assert_eq!(error.input, "x");
+        assert!(error.source().is_some());
+        assert_eq!(
+            error.to_string(),
+            "failed to map element 1 (\"x\"): invalid digit found in string"
+        );
+    }
+
+    /// Tests that mapping stops at the first error.
+    ///
+    /// # Expected
+    /// The mapper is not called for elements after the failure.
+    #[test]
+    fn test_stops_early() {
+        let mut calls = 0;
+        let result = try_map(&[1, -1, 2, 3], |n| {
+            calls += 1;
+            if *n < 0 {
+                Err("negative")
+            } else {
+                Ok(*n * 2)
+            }
+        });
+
+        assert_eq!(result.unwrap_err().source, "negative");
+        assert_eq!(calls, 2);
+    }
+
+    /// Tests an empty input.
+    ///
+    /// # Expected
+    /// Returns `Ok` with an empty vector.
+    #[test]
+    fn test_empty() {
+        let values: Vec<u8> = vec![];
+        assert_eq!(try_map(&values, |v| Ok::<_, ()>(*v)), Ok(vec![]));
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use pencil_box::array::uniq::{uniq, uniq_performant};
+    use pencil_box::array::uniq::{uniq, uniq_performant, uniq_secure};
 
     use rand::distributions::Alphanumeric;
     use rand::{rngs::StdRng, Rng, SeedableRng};
@@ -263,4 +263,23 @@ mod tests {
         let set: HashSet<_> = users.iter().cloned().collect();
         assert_eq!(users.len(), set.len());
     }
+
+    /// Tests that every hasher variant produces the same output.
+    ///
+    /// # Expected
+    /// `uniq`, `uniq_secure`, and `uniq_performant` agree, whatever the `fast-hash` feature.
+    #[test]
+    fn test_variants_agree() {
+        let original = generate_random_i128_data(500, 7);
+        let mut default = original.clone();
+        let mut secure = original.clone();
+        let mut performant = original;
+
+        uniq(&mut default);
+        uniq_secure(&mut secure);
+        uniq_performant(&mut performant);
+
+        assert_eq!(default, secure);
+        assert_eq!(default, performant);
+    }
 }
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::uniq_by_distance::uniq_by_distance;
+
+    fn edit_distance(a: &str, b: &str) -> f64 {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+        for (i, ca) in a.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, cb) in b.iter().enumerate() {
+                let substitution = previous[j] + usize::from(ca != *cb);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+        previous[b.len()] as f64
+    }
+
+    /// Tests approximate string deduplication with an edit distance.
+    ///
+    /// # Expected
+    /// Near-identical strings collapse into their first occurrence.
+    #[test]
+    fn test_strings_by_edit_distance() {
+        let mut titles = vec![
+            "Rust 1.80 released".to_string(),
+            "Rust 1.81 released".to_string(),
+            "Python 3.13 released".to_string(),
+            "rust 1.80 released".to_string(),
+        ];
+        let removed = uniq_by_distance(&mut titles, |a, b| edit_distance(a, b), 1.0);
+
+        assert_eq!(removed, 2);
+        assert_eq!(titles, vec!["Rust 1.80 released", "Python 3.13 released"]);
+    }
+
+    /// Tests that comparisons are made against kept elements only.
+    ///
+    /// # Expected
+    /// A chain of close values keeps every element that is far from all kept ones.
+    #[test]
+    fn test_greedy_against_kept_elements() {
+        let mut data = vec![0.0, 0.6, 1.2, 1.8];
+        uniq_by_distance(&mut data, |a: &f64, b: &f64| (a - b).abs(), 1.0);
+        assert_eq!(data, vec![0.0, 1.2]);
+    }
+
+    /// Tests a zero threshold and exact duplicates.
+    ///
+    /// # Expected
+    /// Behaves like exact deduplication with distance `0` for equal values.
+    #[test]
+    fn test_zero_threshold() {
+        let mut data = vec![3, 1, 3, 2, 1];
+        let removed = uniq_by_distance(&mut data, |a: &i32, b: &i32| f64::from((a - b).abs()), 0.0);
+        assert_eq!(removed, 2);
+        assert_eq!(data, vec![3, 1, 2]);
+    }
+
+    /// Tests `NaN` distances and empty input.
+    ///
+    /// # Expected
+    /// `NaN` never matches; empty input removes nothing.
+    #[test]
+    fn test_nan_and_empty() {
+        let mut data = vec![1, 1, 1];
+        assert_eq!(uniq_by_distance(&mut data, |_, _| f64::NAN, 10.0), 0);
+        assert_eq!(data.len(), 3);
+
+        let mut empty: Vec<u8> = vec![];
+        assert_eq!(uniq_by_distance(&mut empty, |_, _| 0.0, 1.0), 0);
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::windowed_rate::{windowed_counts, windowed_rate, MAX_WINDOWS};
+
+    /// Tests counting with aligned windows and a gap.
+    ///
+    /// # Expected
+    /// Empty windows between events are reported with a zero count.
+    #[test]
+    fn test_counts_with_gap() {
+        let times = [5, 12, 14, 31];
+        assert_eq!(
+            windowed_counts(&times, 10).unwrap(),
+            vec![(0, 1), (10, 2), (20, 0), (30, 1)]
+        );
+    }
+
+    /// Tests unsorted input and window boundaries.
+    ///
+    /// # Expected
+    /// Order does not matter and a timestamp on a boundary starts the next window.
+    #[test]
+    fn test_unsorted_and_boundaries() {
+        let times = [20, 9, 10, 19];
+        assert_eq!(
+            windowed_counts(&times, 10).unwrap(),
+            vec![(0, 1), (10, 2), (20, 1)]
+        );
+    }
+
+    /// Tests empty input and a zero window.
+    ///
+    /// # Expected
+    /// Empty input gives no windows; a zero window is an error.
+    #[test]
+    fn test_empty_and_invalid() {
+        assert!(windowed_counts(&[], 10).unwrap().is_empty());
+        assert!(windowed_counts(&[1, 2], 0).is_err());
+        assert!(windowed_rate(&[1, 2], 0).is_err());
+    }
+
+    /// Tests timestamps near `u64::MAX`.
+    ///
+    /// # Expected
+    /// No overflow occurs.
+    #[test]
+    fn test_large_timestamps() {
+        let times = [u64::MAX, u64::MAX - 1];
+        let counts = windowed_counts(&times, 1_000).unwrap();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].1, 2);
+    }
+
+    /// Tests rates derived from counts.
+    ///
+    /// # Expected
+    /// Each count is divided by the window size.
+    #[test]
+    fn test_rates() {
+        let times = [0, 250, 500, 750, 1_500];
+        let rates = windowed_rate(&times, 1_000).unwrap();
+        assert_eq!(rates, vec![(0, 0.004), (1_000, 0.001)]);
+    }
+
+    /// Tests time spans that would need an enormous number of windows.
+    ///
+    /// # Expected
+    /// An error is returned instead of overflowing or allocating; wide windows still work.
+    #[test]
+    fn test_extreme_span() {
+        assert_eq!(
+            windowed_counts(&[0, u64::MAX], 1),
+            Err("time span covers too many windows")
+        );
+        assert!(windowed_counts(&[0, 1 << 40], 1).is_err());
+        assert!(windowed_rate(&[0, 1_700_000_000_000], 1_000).is_err());
+
+        let counts = windowed_counts(&[0, u64::MAX], u64::MAX).unwrap();
+        assert_eq!(counts, vec![(0, 1), (u64::MAX, 1)]);
+    }
+
+    /// Tests a span of exactly `MAX_WINDOWS` windows.
+    ///
+    /// # Expected
+    /// The limit itself is accepted; one more window is rejected.
+    #[test]
+    fn test_max_windows_boundary() {
+        let last = MAX_WINDOWS as u64 - 1;
+        assert_eq!(windowed_counts(&[0, last], 1).unwrap().len(), MAX_WINDOWS);
+        assert!(windowed_counts(&[0, last + 1], 1).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::without::without;
+
+    /// Tests excluding several values.
+    ///
+    /// # Expected
+    /// Every occurrence of an excluded value is left out; order is kept.
+    #[test]
+    fn test_excludes_all_occurrences() {
+        let data = vec![1, 2, 3, 2, 4, 1];
+        assert_eq!(without(&data, &[1, 2]), vec![3, 4]);
+        assert_eq!(data, vec![1, 2, 3, 2, 4, 1]);
+    }
+
+    /// Tests that non-excluded duplicates are kept.
+    ///
+    /// # Expected
+    /// Unlike `uniq`, repeated elements survive.
+    #[test]
+    fn test_keeps_duplicates() {
+        let data = vec!["a".to_string(), "a".to_string(), "b".to_string()];
+        assert_eq!(without(&data, &["b".to_string()]), vec!["a", "a"]);
+    }
+
+    /// Tests empty inputs.
+    ///
+    /// # Expected
+    /// An empty exclusion list copies the input; an empty input stays empty.
+    #[test]
+    fn test_empty_inputs() {
+        assert_eq!(without(&[1, 2], &[]), vec![1, 2]);
+        assert!(without::<i32>(&[], &[1]).is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::collections::disjoint_set::DisjointSet;
+
+    /// ✅ Unions merge groups transitively.
+    /// Expects values connected through a chain to share a representative.
+    #[test]
+    fn test_union_is_transitive() {
+        let mut set = DisjointSet::new();
+        assert!(set.union(1, 2));
+        assert!(set.union(3, 4));
+        assert!(set.union(2, 3));
+        assert!(!set.union(1, 4));
+
+        assert!(set.connected(&1, &4));
+        assert_eq!(set.find(&1).copied(), set.find(&4).copied());
+        assert_eq!(set.group_count(), 1);
+        assert_eq!(set.len(), 4);
+    }
+
+    /// ✅ Inserted values start in their own group.
+    /// Expects separate groups until a union joins them.
+    #[test]
+    fn test_insert_creates_singletons() {
+        let mut set = DisjointSet::default();
+        assert!(set.insert("a"));
+        assert!(!set.insert("a"));
+        assert!(set.insert("b"));
+        assert_eq!(set.group_count(), 2);
+        assert!(!set.connected(&"a", &"b"));
+        assert_eq!(set.find(&"a"), Some(&"a"));
+    }
+
+    /// ❌ Unknown values are in no group.
+    /// Expects `None` from `find` and `false` from `connected`.
+    #[test]
+    fn test_unknown_values() {
+        let mut set: DisjointSet<String> = DisjointSet::new();
+        assert!(set.is_empty());
+        assert_eq!(set.find(&"x".to_string()), None);
+        assert!(!set.connected(&"x".to_string(), &"x".to_string()));
+    }
+
+    /// ✅ Groups are listed in insertion order.
+    /// Expects stable ordering of groups and their members.
+    #[test]
+    fn test_groups_order() {
+        let mut set = DisjointSet::new();
+        set.insert('z');
+        set.union('a', 'b');
+        set.union('c', 'z');
+        set.union('b', 'd');
+        assert_eq!(set.groups(), vec![vec!['z', 'c'], vec!['a', 'b', 'd']]);
+    }
+}
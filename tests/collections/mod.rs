@@ -0,0 +1,3 @@
+mod disjoint_set;
+mod slot_vec;
+mod small_ordered_map;
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::collections::slot_vec::SlotVec;
+
+    /// ✅ Keys keep resolving after other values are removed.
+    /// Expects no shifting of remaining values.
+    #[test]
+    fn test_stable_keys() {
+        let mut slots = SlotVec::new();
+        let keys: Vec<_> = (0..5).map(|i| slots.insert(i * 10)).collect();
+
+        slots.remove(keys[1]);
+        slots.remove(keys[3]);
+
+        assert_eq!(slots.get(keys[0]), Some(&0));
+        assert_eq!(slots.get(keys[2]), Some(&20));
+        assert_eq!(slots.get(keys[4]), Some(&40));
+        assert_eq!(slots.len(), 3);
+    }
+
+    /// ✅ Stale keys do not see values that reuse their slot.
+    /// Expects `None` for the old key and the new value for the new key.
+    #[test]
+    fn test_generation_check() {
+        let mut slots = SlotVec::new();
+        let old = slots.insert("old");
+        assert_eq!(slots.remove(old), Some("old"));
+        assert_eq!(slots.remove(old), None);
+
+        let new = slots.insert("new");
+        assert_eq!(new.index(), old.index());
+        assert_ne!(new.generation(), old.generation());
+        assert_eq!(slots.get(old), None);
+        assert!(!slots.contains_key(old));
+        assert_eq!(slots.get(new), Some(&"new"));
+    }
+
+    /// ✅ Values can be modified through `get_mut`.
+    /// Expects the change to be visible through `get`.
+    #[test]
+    fn test_get_mut() {
+        let mut slots = SlotVec::with_capacity(2);
+        let key = slots.insert(vec![1]);
+        slots.get_mut(key).unwrap().push(2);
+        assert_eq!(slots.get(key), Some(&vec![1, 2]));
+    }
+
+    /// ✅ Iteration yields live values with matching keys.
+    /// Expects removed slots to be skipped.
+    #[test]
+    fn test_iter() {
+        let mut slots = SlotVec::new();
+        let a = slots.insert('a');
+        let b = slots.insert('b');
+        let c = slots.insert('c');
+        slots.remove(b);
+
+        let items: Vec<_> = slots.iter().collect();
+        assert_eq!(items, vec![(a, &'a'), (c, &'c')]);
+    }
+
+    /// ✅ Clearing invalidates all keys.
+    /// Expects an empty collection and stale keys afterwards.
+    #[test]
+    fn test_clear() {
+        let mut slots: SlotVec<u8> = SlotVec::default();
+        let key = slots.insert(1);
+        slots.clear();
+        assert!(slots.is_empty());
+        assert_eq!(slots.get(key), None);
+
+        let reused = slots.insert(2);
+        assert_eq!(slots.get(reused), Some(&2));
+        assert_eq!(slots.get(key), None);
+    }
+
+    /// ✅ An empty `SlotVec` can be built in a `const` context.
+    /// Expects a usable, empty collection.
+    #[test]
+    fn test_const_new() {
+        const EMPTY: SlotVec<u8> = SlotVec::new();
+        let mut slots = EMPTY;
+        assert!(slots.is_empty());
+        let key = slots.insert(5);
+        assert_eq!(slots.get(key), Some(&5));
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::collections::small_ordered_map::SmallOrderedMap;
+
+    /// ✅ Iteration follows insertion order.
+    /// Expects keys in the order they were first inserted.
+    #[test]
+    fn test_insertion_order() {
+        let mut map = SmallOrderedMap::new();
+        map.insert("zeta", 1);
+        map.insert("alpha", 2);
+        map.insert("mid", 3);
+        assert_eq!(
+            map.keys().copied().collect::<Vec<_>>(),
+            vec!["zeta", "alpha", "mid"]
+        );
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    /// ✅ Re-inserting a key replaces the value in place.
+    /// Expects the old value back and the original position kept.
+    #[test]
+    fn test_insert_existing() {
+        let mut map: SmallOrderedMap<&str, i32> = vec![("a", 1), ("b", 2)].into();
+        assert_eq!(map.insert("a", 10), Some(1));
+        assert_eq!(map.iter().next(), Some((&"a", &10)));
+        assert_eq!(map.len(), 2);
+    }
+
+    /// ✅ Lookups work with borrowed key forms.
+    /// Expects `&str` lookups against `String` keys.
+    #[test]
+    fn test_borrowed_lookup() {
+        let mut map = SmallOrderedMap::new();
+        map.insert("host".to_string(), "localhost");
+        assert_eq!(map.get("host"), Some(&"localhost"));
+        assert!(map.contains_key("host"));
+        *map.get_mut("host").unwrap() = "example.com";
+        assert_eq!(map.get("host"), Some(&"example.com"));
+        assert_eq!(map.get("port"), None);
+    }
+
+    /// ✅ Removal keeps the remaining order.
+    /// Expects the removed value and the other keys in order.
+    #[test]
+    fn test_remove() {
+        let mut map: SmallOrderedMap<char, u8> =
+            [('a', 1), ('b', 2), ('c', 3)].into_iter().collect();
+        assert_eq!(map.remove(&'b'), Some(2));
+        assert_eq!(map.remove(&'b'), None);
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            vec![('a', 1), ('c', 3)]
+        );
+    }
+
+    /// ✅ Collecting with duplicate keys keeps the first position and the last value.
+    /// Expects deduplicated entries.
+    #[test]
+    fn test_from_iter_duplicates() {
+        let map: SmallOrderedMap<i32, &str> = vec![(1, "a"), (2, "b"), (1, "c")].into();
+        assert_eq!(Vec::from(map), vec![(1, "c"), (2, "b")]);
+    }
+
+    /// ✅ Empty maps behave sensibly.
+    /// Expects no entries and `None` lookups.
+    #[test]
+    fn test_empty() {
+        let map: SmallOrderedMap<u8, u8> = SmallOrderedMap::default();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+        assert_eq!(SmallOrderedMap::<u8, u8>::with_capacity(4).len(), 0);
+    }
+}
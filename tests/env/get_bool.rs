@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::env::get_bool::get_bool;
+
+    /// ✅ Every truthy spelling, in mixed case and with whitespace.
+    /// Expects `true` for each.
+    #[test]
+    fn test_truthy_values() {
+        for (index, value) in ["1", "true", "TRUE", "yes", "Yes", " true "].iter().enumerate() {
+            let key = format!("PB_TEST_GET_BOOL_TRUTHY_{}", index);
+            std::env::set_var(&key, value);
+            assert!(get_bool(&key), "expected {:?} to be truthy", value);
+        }
+    }
+
+    /// ✅ Falsy and unrecognised values.
+    /// Expects `false` for each.
+    #[test]
+    fn test_falsy_values() {
+        for (index, value) in ["0", "false", "no", "", "on", "y"].iter().enumerate() {
+            let key = format!("PB_TEST_GET_BOOL_FALSY_{}", index);
+            std::env::set_var(&key, value);
+            assert!(!get_bool(&key), "expected {:?} to be falsy", value);
+        }
+    }
+
+    /// ✅ Variable is not set.
+    /// Expects `false`.
+    #[test]
+    fn test_missing_is_false() {
+        assert!(!get_bool("PB_TEST_GET_BOOL_MISSING"));
+    }
+}
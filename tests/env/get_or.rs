@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::env::get_or::get_or;
+
+    /// ✅ Variable is set and valid.
+    /// Expects the parsed value instead of the default.
+    #[test]
+    fn test_uses_env_value() {
+        std::env::set_var("PB_TEST_GET_OR_SET", "7");
+        assert_eq!(get_or("PB_TEST_GET_OR_SET", 1u32), 7);
+    }
+
+    /// ✅ Variable is not set.
+    /// Expects the default.
+    #[test]
+    fn test_missing_uses_default() {
+        assert_eq!(get_or("PB_TEST_GET_OR_MISSING", 1u32), 1);
+    }
+
+    /// ✅ Variable is set but cannot be parsed.
+    /// Expects the default.
+    #[test]
+    fn test_invalid_uses_default() {
+        std::env::set_var("PB_TEST_GET_OR_BAD", "-5");
+        assert_eq!(get_or("PB_TEST_GET_OR_BAD", 9u32), 9);
+    }
+
+    /// ✅ Works with owned defaults such as `String`.
+    /// Expects the default to be returned unchanged.
+    #[test]
+    fn test_string_default() {
+        assert_eq!(
+            get_or("PB_TEST_GET_OR_STR_MISSING", "info".to_string()),
+            "info"
+        );
+    }
+}
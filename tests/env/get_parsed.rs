@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::env::get_parsed::get_parsed;
+
+    /// ✅ Parses an integer value.
+    /// Expects the parsed number.
+    #[test]
+    fn test_parses_integer() {
+        std::env::set_var("PB_TEST_GET_PARSED_INT", "42");
+        assert_eq!(get_parsed::<i32>("PB_TEST_GET_PARSED_INT"), Some(42));
+    }
+
+    /// ✅ Surrounding whitespace is ignored.
+    /// Expects the trimmed value to parse.
+    #[test]
+    fn test_trims_whitespace() {
+        std::env::set_var("PB_TEST_GET_PARSED_WS", "  3.5\n");
+        assert_eq!(get_parsed::<f64>("PB_TEST_GET_PARSED_WS"), Some(3.5));
+    }
+
+    /// ✅ Unparsable value.
+    /// Expects `None`.
+    #[test]
+    fn test_invalid_value_returns_none() {
+        std::env::set_var("PB_TEST_GET_PARSED_BAD", "abc");
+        assert_eq!(get_parsed::<u8>("PB_TEST_GET_PARSED_BAD"), None);
+    }
+
+    /// ✅ Variable is not set.
+    /// Expects `None`.
+    #[test]
+    fn test_missing_returns_none() {
+        assert_eq!(get_parsed::<u8>("PB_TEST_GET_PARSED_MISSING"), None);
+    }
+
+    /// ✅ Parses into `String`.
+    /// Expects the trimmed string value.
+    #[test]
+    fn test_parses_string() {
+        std::env::set_var("PB_TEST_GET_PARSED_STR", " hello ");
+        assert_eq!(
+            get_parsed::<String>("PB_TEST_GET_PARSED_STR"),
+            Some("hello".to_string())
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod get_bool;
+mod get_or;
+mod get_parsed;
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::{map_of, seq, set_of};
+    use std::collections::{HashMap, HashSet};
+
+    /// ✅ `seq!` fills with a cloned value or defaults.
+    /// Expects the same output as `fill_value` and `fill_default`.
+    #[test]
+    fn test_seq_fill_forms() {
+        assert_eq!(seq![7u8; 3], vec![7, 7, 7]);
+        assert_eq!(seq![vec![1]; 2], vec![vec![1], vec![1]]);
+
+        let zeros: Vec<i64> = seq![default; 4];
+        assert_eq!(zeros, vec![0; 4]);
+
+        let none: Vec<String> = seq!["x".to_string(); 0];
+        assert!(none.is_empty());
+    }
+
+    /// ✅ `seq!` builds from an index function.
+    /// Expects the closure to receive each index in order.
+    #[test]
+    fn test_seq_with() {
+        let labels = seq![with |i| format!("row-{i}"); 3];
+        assert_eq!(labels, vec!["row-0", "row-1", "row-2"]);
+    }
+
+    /// ✅ `seq!` builds numeric ranges.
+    /// Expects the same output as `range` and `range_step`.
+    #[test]
+    fn test_seq_range() {
+        assert_eq!(seq![range 0, 4], vec![0, 1, 2, 3]);
+        assert_eq!(seq![range 10u32, 0, 5], Vec::<u32>::new());
+        assert_eq!(seq![range 10i32, 0, -5], vec![10, 5]);
+    }
+
+    /// ✅ Arguments are evaluated once.
+    /// Expects a side-effecting size expression to run a single time.
+    #[test]
+    fn test_seq_evaluates_once() {
+        let mut calls = 0;
+        let mut size = || {
+            calls += 1;
+            2
+        };
+        assert_eq!(seq!['z'; size()], vec!['z', 'z']);
+        assert_eq!(calls, 1);
+    }
+
+    /// ✅ `map_of!` builds a map from pairs.
+    /// Expects the last value to win for repeated keys.
+    #[test]
+    fn test_map_of() {
+        let map = map_of! { "a" => 1, "b" => 2, "a" => 3 };
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], 3);
+
+        let empty: HashMap<u8, u8> = map_of! {};
+        assert!(empty.is_empty());
+    }
+
+    /// ✅ `set_of!` builds a set from values.
+    /// Expects duplicates to collapse and trailing commas to be accepted.
+    #[test]
+    fn test_set_of() {
+        let set = set_of![1, 2, 2, 3,];
+        assert_eq!(set, HashSet::from([1, 2, 3]));
+
+        let empty: HashSet<String> = set_of![];
+        assert!(empty.is_empty());
+    }
+}
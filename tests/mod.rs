@@ -1 +1,9 @@
-mod array;
\ No newline at end of file
+mod array;
+mod collections;
+mod env;
+mod macros;
+mod path;
+mod prob;
+mod queue;
+mod text;
+mod url;
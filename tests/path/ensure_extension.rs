@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::path::ensure_extension::ensure_extension;
+    use std::path::PathBuf;
+
+    /// ✅ Appends a missing extension.
+    /// Expects `.ext` to be added to the file name.
+    #[test]
+    fn test_appends_missing_extension() {
+        assert_eq!(
+            ensure_extension("data/report", "csv"),
+            PathBuf::from("data/report.csv")
+        );
+    }
+
+    /// ✅ Leading dot in the extension argument is accepted.
+    /// Expects a single dot in the result.
+    #[test]
+    fn test_leading_dot_in_extension() {
+        assert_eq!(
+            ensure_extension("report", ".csv"),
+            PathBuf::from("report.csv")
+        );
+    }
+
+    /// ✅ Extension already present.
+    /// Expects the path unchanged.
+    #[test]
+    fn test_already_has_extension() {
+        assert_eq!(
+            ensure_extension("a/b.json", "json"),
+            PathBuf::from("a/b.json")
+        );
+    }
+
+    /// ✅ A different extension is kept and the new one appended.
+    /// Expects a compound extension.
+    #[test]
+    fn test_different_extension_is_appended() {
+        assert_eq!(
+            ensure_extension("backup.tar", "gz"),
+            PathBuf::from("backup.tar.gz")
+        );
+        assert_eq!(
+            ensure_extension("notes.TXT", "txt"),
+            PathBuf::from("notes.TXT.txt")
+        );
+    }
+
+    /// ✅ Empty extension or a path without a file name.
+    /// Expects the path unchanged.
+    #[test]
+    fn test_unchanged_edge_cases() {
+        assert_eq!(ensure_extension("file", ""), PathBuf::from("file"));
+        assert_eq!(ensure_extension("file", "."), PathBuf::from("file"));
+        assert_eq!(ensure_extension("/", "txt"), PathBuf::from("/"));
+        assert_eq!(ensure_extension("..", "txt"), PathBuf::from(".."));
+    }
+}
@@ -0,0 +1,3 @@
+mod ensure_extension;
+mod normalize;
+mod relative_to;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::path::normalize::normalize;
+    use std::path::PathBuf;
+
+    /// ✅ Removes `.` components.
+    /// Expects the path without current-directory markers.
+    #[test]
+    fn test_removes_cur_dir() {
+        assert_eq!(normalize("./a/./b/."), PathBuf::from("a/b"));
+    }
+
+    /// ✅ Resolves `..` against preceding components.
+    /// Expects the parent component to be removed.
+    #[test]
+    fn test_resolves_parent_dir() {
+        assert_eq!(normalize("a/b/../c"), PathBuf::from("a/c"));
+        assert_eq!(normalize("/usr/local/../bin"), PathBuf::from("/usr/bin"));
+    }
+
+    /// ✅ `..` at the root is dropped.
+    /// Expects the path to stay anchored at the root.
+    #[test]
+    fn test_parent_of_root() {
+        assert_eq!(normalize("/../../etc"), PathBuf::from("/etc"));
+        assert_eq!(normalize("/.."), PathBuf::from("/"));
+    }
+
+    /// ✅ Leading `..` components of a relative path are kept.
+    /// Expects unresolvable parents to be preserved.
+    #[test]
+    fn test_leading_parent_dirs_kept() {
+        assert_eq!(normalize("../../a"), PathBuf::from("../../a"));
+        assert_eq!(normalize("a/../../b"), PathBuf::from("../b"));
+    }
+
+    /// ✅ Paths that cancel out entirely.
+    /// Expects `"."`.
+    #[test]
+    fn test_empty_result_is_cur_dir() {
+        assert_eq!(normalize(""), PathBuf::from("."));
+        assert_eq!(normalize("a/b/../.."), PathBuf::from("."));
+    }
+
+    /// ✅ Redundant separators are collapsed.
+    /// Expects a clean path.
+    #[test]
+    fn test_redundant_separators() {
+        assert_eq!(normalize("a//b///c/"), PathBuf::from("a/b/c"));
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::path::relative_to::relative_to;
+    use std::path::PathBuf;
+
+    /// ✅ Target nested under base.
+    /// Expects the remaining target components.
+    #[test]
+    fn test_descendant() {
+        assert_eq!(
+            relative_to("/a/b", "/a/b/c/d.txt"),
+            Some(PathBuf::from("c/d.txt"))
+        );
+    }
+
+    /// ✅ Target is an ancestor of base.
+    /// Expects one `..` per extra base component.
+    #[test]
+    fn test_ancestor() {
+        assert_eq!(relative_to("/a/b/c", "/a"), Some(PathBuf::from("../..")));
+    }
+
+    /// ✅ Target in a sibling branch.
+    /// Expects climbing to the common prefix then descending.
+    #[test]
+    fn test_sibling() {
+        assert_eq!(
+            relative_to("a/b/c", "a/x/y"),
+            Some(PathBuf::from("../../x/y"))
+        );
+    }
+
+    /// ✅ Identical paths, including after normalization.
+    /// Expects `"."`.
+    #[test]
+    fn test_same_path() {
+        assert_eq!(relative_to("/a/b", "/a/b"), Some(PathBuf::from(".")));
+        assert_eq!(relative_to("a/./b", "a/c/../b"), Some(PathBuf::from(".")));
+    }
+
+    /// ✅ Mixed absolute and relative inputs.
+    /// Expects `None`.
+    #[test]
+    fn test_mixed_absolute_relative() {
+        assert_eq!(relative_to("/a", "b"), None);
+        assert_eq!(relative_to("a", "/b"), None);
+    }
+
+    /// ✅ Base climbs above the shared prefix with `..`.
+    /// Expects `None`, since the way back down is unknown.
+    #[test]
+    fn test_unresolvable_base() {
+        assert_eq!(relative_to("../x", "y"), None);
+    }
+
+    /// ✅ Shared leading `..` components.
+    /// Expects them to be treated as a common prefix.
+    #[test]
+    fn test_shared_leading_parents() {
+        assert_eq!(relative_to("../x", "../y/z"), Some(PathBuf::from("../y/z")));
+    }
+
+    /// ✅ Relative to the current directory.
+    /// Expects the target unchanged.
+    #[test]
+    fn test_relative_to_cur_dir() {
+        assert_eq!(relative_to(".", "a/b"), Some(PathBuf::from("a/b")));
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::prob::approx_distinct::ApproxDistinct;
+
+    fn relative_error(estimate: u64, actual: u64) -> f64 {
+        (estimate as f64 - actual as f64).abs() / actual as f64
+    }
+
+    /// ✅ An empty counter estimates zero.
+    /// Expects `0`.
+    #[test]
+    fn test_empty() {
+        assert_eq!(ApproxDistinct::default().estimate(), 0);
+    }
+
+    /// ✅ Small cardinalities are nearly exact thanks to linear counting.
+    /// Expects an error below 2%.
+    #[test]
+    fn test_small_cardinality() {
+        let mut counter = ApproxDistinct::default();
+        for value in 0..300u32 {
+            counter.insert(&value);
+        }
+        assert!(relative_error(counter.estimate(), 300) < 0.02);
+    }
+
+    /// ✅ Large cardinalities stay within a few standard errors.
+    /// Expects an error below 5% at precision 12.
+    #[test]
+    fn test_large_cardinality() {
+        let mut counter = ApproxDistinct::new(12).unwrap();
+        for value in 0..200_000u64 {
+            counter.insert(&value);
+        }
+        assert!(relative_error(counter.estimate(), 200_000) < 0.05);
+    }
+
+    /// ✅ Repeated values do not inflate the estimate.
+    /// Expects the same estimate after re-inserting everything.
+    #[test]
+    fn test_duplicates_ignored() {
+        let mut counter = ApproxDistinct::default();
+        for value in ["a", "b", "c"] {
+            counter.insert(value);
+        }
+        let before = counter.estimate();
+        for _ in 0..100 {
+            counter.insert("a");
+        }
+        assert_eq!(counter.estimate(), before);
+        assert_eq!(before, 3);
+    }
+
+    /// ✅ Merging two counters matches a single counter over both streams.
+    /// Expects identical registers and estimates.
+    #[test]
+    fn test_merge() {
+        let mut left = ApproxDistinct::new(10).unwrap();
+        let mut right = ApproxDistinct::new(10).unwrap();
+        let mut both = ApproxDistinct::new(10).unwrap();
+        for value in 0..5_000u32 {
+            if value % 2 == 0 {
+                left.insert(&value);
+            } else {
+                right.insert(&value);
+            }
+            both.insert(&value);
+        }
+        left.merge(&right).unwrap();
+        assert_eq!(left, both);
+
+        let other = ApproxDistinct::new(11).unwrap();
+        assert!(left.merge(&other).is_err());
+    }
+
+    /// ✅ Out-of-range precision is rejected and `clear` resets the counter.
+    /// Expects errors for 3 and 19, and an estimate of zero after clearing.
+    #[test]
+    fn test_precision_bounds_and_clear() {
+        assert!(ApproxDistinct::new(3).is_err());
+        assert!(ApproxDistinct::new(19).is_err());
+
+        let mut counter = ApproxDistinct::new(4).unwrap();
+        assert_eq!(counter.precision(), 4);
+        counter.insert(&1);
+        counter.clear();
+        assert_eq!(counter.estimate(), 0);
+    }
+}
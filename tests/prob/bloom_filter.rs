@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::prob::bloom_filter::BloomFilter;
+
+    /// ✅ Every inserted value is reported as present.
+    /// Expects no false negatives.
+    #[test]
+    fn test_no_false_negatives() {
+        let values: Vec<u64> = (0..5_000).collect();
+        let filter = BloomFilter::from_slice(&values, 0.01).unwrap();
+        assert!(values.iter().all(|v| filter.contains(v)));
+    }
+
+    /// ✅ The observed false-positive rate stays near the configured rate.
+    /// Expects well under 3% for a 1% target.
+    #[test]
+    fn test_false_positive_rate() {
+        let values: Vec<u64> = (0..10_000).collect();
+        let filter = BloomFilter::from_slice(&values, 0.01).unwrap();
+        let false_positives = (10_000..60_000u64).filter(|v| filter.contains(v)).count();
+        assert!(false_positives < 1_500, "{false_positives} false positives");
+    }
+
+    /// ✅ Works with unsized `str` values from owned and borrowed strings.
+    /// Expects lookups by `&str` to succeed.
+    #[test]
+    fn test_str_values() {
+        let owned = vec!["alpha".to_string(), "beta".to_string()];
+        let mut filter: BloomFilter<str> = BloomFilter::from_slice(&owned, 0.001).unwrap();
+        assert!(filter.contains("alpha"));
+
+        filter.insert("gamma");
+        assert!(filter.contains("gamma"));
+    }
+
+    /// ✅ Empty and cleared filters contain nothing.
+    /// Expects `contains` to return `false`.
+    #[test]
+    fn test_empty_and_clear() {
+        let mut filter: BloomFilter<i32> = BloomFilter::with_rate(100, 0.05).unwrap();
+        assert!(!filter.contains(&1));
+        filter.insert(&1);
+        assert!(filter.contains(&1));
+        filter.clear();
+        assert!(!filter.contains(&1));
+    }
+
+    /// ✅ Invalid false-positive rates are rejected.
+    /// Expects an error for 0, 1, negative, and NaN rates.
+    #[test]
+    fn test_invalid_rate() {
+        for rate in [0.0, 1.0, -0.5, f64::NAN] {
+            assert!(BloomFilter::<i32>::with_rate(10, rate).is_err());
+        }
+    }
+
+    /// ✅ Sizing follows the standard formulas.
+    /// Expects about 9.6 bits and 7 probes per item at a 1% rate.
+    #[test]
+    fn test_sizing() {
+        let filter = BloomFilter::<u32>::with_rate(1_000, 0.01).unwrap();
+        assert!((9_500..9_700).contains(&filter.bit_count()));
+        assert_eq!(filter.hash_count(), 7);
+    }
+}
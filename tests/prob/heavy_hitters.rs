@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::prob::heavy_hitters::HeavyHitters;
+
+    /// ✅ With enough capacity, counts are exact.
+    /// Expects exact counts and a zero error bound.
+    #[test]
+    fn test_exact_when_under_capacity() {
+        let mut hitters = HeavyHitters::new(10).unwrap();
+        for value in [1, 2, 1, 3, 1, 2] {
+            hitters.insert(value);
+        }
+        assert_eq!(hitters.top(3), vec![(&1, 3), (&2, 2), (&3, 1)]);
+        assert_eq!(hitters.bounds(&1), Some((3, 3)));
+        assert_eq!(hitters.total(), 6);
+    }
+
+    /// ✅ A dominant value survives heavy churn from rare values.
+    /// Expects it to be tracked, ranked first, with bounds containing the true count.
+    #[test]
+    fn test_dominant_value_tracked() {
+        let mut hitters = HeavyHitters::new(5).unwrap();
+        let mut hot = 0;
+        for i in 0..10_000u32 {
+            if i % 3 == 0 {
+                hitters.insert(0);
+                hot += 1;
+            } else {
+                hitters.insert(i);
+            }
+        }
+
+        assert_eq!(hitters.top(1)[0].0, &0);
+        let (lower, upper) = hitters.bounds(&0).unwrap();
+        assert!(lower <= hot && hot <= upper);
+    }
+
+    /// ✅ Memory stays bounded by capacity.
+    /// Expects never more than `capacity` tracked values.
+    #[test]
+    fn test_bounded_capacity() {
+        let mut hitters = HeavyHitters::new(4).unwrap();
+        for value in 0..1_000 {
+            hitters.insert(value);
+        }
+        assert_eq!(hitters.top(100).len(), 4);
+        assert_eq!(hitters.capacity(), 4);
+    }
+
+    /// ✅ Untracked values and zero capacity.
+    /// Expects `None` bounds and a construction error.
+    #[test]
+    fn test_untracked_and_invalid() {
+        let hitters: HeavyHitters<&str> = HeavyHitters::new(2).unwrap();
+        assert_eq!(hitters.bounds(&"x"), None);
+        assert!(hitters.top(5).is_empty());
+        assert!(HeavyHitters::<u8>::new(0).is_err());
+    }
+
+    /// ✅ Tied counts come out in a fixed order.
+    /// Expects smaller error bounds first, then values tracked earlier, identically across trackers.
+    #[test]
+    fn test_ties_are_deterministic() {
+        let mut hitters = HeavyHitters::new(10).unwrap();
+        for value in ["c", "a", "b"] {
+            hitters.insert(value);
+        }
+        assert_eq!(hitters.top(3), vec![(&"c", 1), (&"a", 1), (&"b", 1)]);
+
+        // "y" is evicted for "z", which inherits an error of 1 and ties "x" at a count of 2.
+        let mut hitters = HeavyHitters::new(2).unwrap();
+        for value in ["x", "x", "y", "z"] {
+            hitters.insert(value);
+        }
+        assert_eq!(hitters.top(2), vec![(&"x", 2), (&"z", 2)]);
+        assert_eq!(hitters.bounds(&"z"), Some((1, 2)));
+
+        let run = || {
+            let mut hitters = HeavyHitters::new(8).unwrap();
+            for value in 0..1_000u32 {
+                hitters.insert(value % 37);
+            }
+            hitters
+                .top(8)
+                .into_iter()
+                .map(|(v, c)| (*v, c))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(), run());
+    }
+
+    /// ✅ Among counters tied for the minimum, the oldest is evicted.
+    /// Expects the first value to be replaced and the newer one to stay tracked.
+    #[test]
+    fn test_evicts_oldest_minimum() {
+        let mut hitters = HeavyHitters::new(2).unwrap();
+        for value in ["a", "b", "c"] {
+            hitters.insert(value);
+        }
+        assert_eq!(hitters.bounds(&"a"), None);
+        assert_eq!(hitters.bounds(&"b"), Some((1, 1)));
+        assert_eq!(hitters.bounds(&"c"), Some((1, 2)));
+    }
+}
@@ -0,0 +1,3 @@
+mod approx_distinct;
+mod bloom_filter;
+mod heavy_hitters;
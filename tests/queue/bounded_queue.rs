@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::queue::bounded_queue::{BoundedQueue, OverflowPolicy, PushOutcome};
+
+    fn filled(policy: OverflowPolicy) -> BoundedQueue<i32> {
+        let mut queue = BoundedQueue::new(3, policy).unwrap();
+        for value in 1..=3 {
+            assert_eq!(queue.push(value), PushOutcome::Queued);
+        }
+        queue
+    }
+
+    /// ✅ Items come out in the order they went in.
+    /// Expects FIFO order from `pop` and `peek`.
+    #[test]
+    fn test_fifo_order() {
+        let mut queue = filled(OverflowPolicy::Reject);
+        assert!(queue.is_full());
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    /// ✅ The reject policy hands the new item back.
+    /// Expects the queue unchanged and the item returned.
+    #[test]
+    fn test_reject_policy() {
+        let mut queue = filled(OverflowPolicy::Reject);
+        let outcome = queue.push(4);
+        assert!(!outcome.is_queued());
+        assert_eq!(outcome, PushOutcome::Rejected(4));
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(queue.dropped(), 1);
+    }
+
+    /// ✅ The drop-oldest policy evicts the front item.
+    /// Expects a sliding window over the most recent items.
+    #[test]
+    fn test_drop_oldest_policy() {
+        let mut queue = filled(OverflowPolicy::DropOldest);
+        assert_eq!(queue.push(4), PushOutcome::Evicted(1));
+        assert_eq!(queue.push(5), PushOutcome::Evicted(2));
+        assert_eq!(queue.drain_all(), vec![3, 4, 5]);
+        assert_eq!(queue.dropped(), 2);
+    }
+
+    /// ✅ The drop-newest policy evicts the back item.
+    /// Expects the oldest items to be kept and the latest push to be last.
+    #[test]
+    fn test_drop_newest_policy() {
+        let mut queue = filled(OverflowPolicy::DropNewest);
+        let outcome = queue.push(4);
+        assert!(outcome.is_queued());
+        assert_eq!(outcome, PushOutcome::Evicted(3));
+        assert_eq!(queue.drain_all(), vec![1, 2, 4]);
+    }
+
+    /// ✅ Batch draining takes at most the requested number of items.
+    /// Expects partial batches and an empty batch once the queue is drained.
+    #[test]
+    fn test_drain_batch() {
+        let mut queue = BoundedQueue::new(10, OverflowPolicy::Reject).unwrap();
+        for value in 0..5 {
+            let _ = queue.push(value);
+        }
+        assert_eq!(queue.drain_batch(2), vec![0, 1]);
+        assert_eq!(queue.drain_batch(0), Vec::<i32>::new());
+        assert_eq!(queue.drain_batch(10), vec![2, 3, 4]);
+        assert!(queue.drain_batch(1).is_empty());
+    }
+
+    /// ❌ Zero capacity is rejected.
+    /// Expects an error from `new`.
+    #[test]
+    fn test_zero_capacity() {
+        assert!(BoundedQueue::<u8>::new(0, OverflowPolicy::DropOldest).is_err());
+    }
+
+    /// ✅ Clearing keeps the configuration and the dropped counter.
+    /// Expects capacity, policy, and `dropped` to survive `clear`.
+    #[test]
+    fn test_clear() {
+        let mut queue = filled(OverflowPolicy::DropOldest);
+        let _ = queue.push(4);
+        queue.clear();
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.capacity(), 3);
+        assert_eq!(queue.policy(), OverflowPolicy::DropOldest);
+        assert_eq!(queue.dropped(), 1);
+    }
+}
@@ -0,0 +1 @@
+mod bounded_queue;
@@ -0,0 +1,109 @@
+#![cfg(feature = "serde")]
+
+// Kept in its own test target so `serde_json`'s `PartialEq<Value>` impls
+// do not interfere with type inference in the main test suite.
+
+mod array {
+    use pencil_box::array::diff_ops::{diff_ops, DiffOp};
+    use pencil_box::array::either::Either;
+    use pencil_box::array::range_set::RangeSet;
+
+    /// ✅ A `DiffOp` edit script round-trips through JSON.
+    /// Expects externally tagged variants.
+    #[test]
+    fn test_diff_ops_round_trip() {
+        let ops = diff_ops(&[1, 2], &[1, 3]);
+        let json = serde_json::to_string(&ops).unwrap();
+        assert_eq!(json, r#"[{"Equal":1},{"Delete":2},{"Insert":3}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<DiffOp<i32>>>(&json).unwrap(),
+            ops
+        );
+    }
+
+    /// ✅ `Either` values round-trip through JSON.
+    /// Expects externally tagged `Left`/`Right` variants.
+    #[test]
+    fn test_either_round_trip() {
+        let values: Vec<Either<u8, String>> = vec![Either::Left(1), Either::Right("x".into())];
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, r#"[{"Left":1},{"Right":"x"}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<Either<u8, String>>>(&json).unwrap(),
+            values
+        );
+    }
+
+    /// ✅ `RangeSet` serializes as a list of pairs and normalizes on the way back in.
+    /// Expects overlapping input ranges to be merged after deserializing.
+    #[test]
+    fn test_range_set_round_trip() {
+        let set: RangeSet<u32> = vec![(0, 10), (20, 30)].into();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "[[0,10],[20,30]]");
+        assert_eq!(serde_json::from_str::<RangeSet<u32>>(&json).unwrap(), set);
+
+        let messy: RangeSet<u32> = serde_json::from_str("[[5,12],[0,6],[20,30]]").unwrap();
+        assert_eq!(messy.as_slice(), &[(0, 12), (20, 30)]);
+    }
+}
+
+mod collections {
+    use pencil_box::collections::slot_vec::{SlotKey, SlotVec};
+    use pencil_box::collections::small_ordered_map::SmallOrderedMap;
+
+    /// ✅ `SlotKey` round-trips through JSON with its generation.
+    /// Expects a deserialized live key to resolve and a deserialized stale key to stay rejected.
+    #[test]
+    fn test_slot_key_round_trip() {
+        let mut sessions = SlotVec::new();
+        let stale = sessions.insert("alice");
+        sessions.remove(stale);
+        let live = sessions.insert("bob");
+
+        let json = serde_json::to_string(&[stale, live]).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"index":0,"generation":0},{"index":0,"generation":1}]"#
+        );
+
+        let keys: Vec<SlotKey> = serde_json::from_str(&json).unwrap();
+        assert_eq!(keys, vec![stale, live]);
+        assert_eq!(sessions.get(keys[0]), None);
+        assert_eq!(sessions.get(keys[1]), Some(&"bob"));
+    }
+
+    /// ✅ `SmallOrderedMap` serializes as ordered pairs.
+    /// Expects insertion order to survive a JSON round-trip.
+    #[test]
+    fn test_small_ordered_map_round_trip() {
+        let map: SmallOrderedMap<String, u32> =
+            vec![("b".to_string(), 2), ("a".to_string(), 1)].into();
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"[["b",2],["a",1]]"#);
+        assert_eq!(
+            serde_json::from_str::<SmallOrderedMap<String, u32>>(&json).unwrap(),
+            map
+        );
+    }
+}
+
+mod text {
+    use pencil_box::text::format_table::TableOptions;
+
+    /// ✅ `TableOptions` round-trips through JSON.
+    /// Expects every field to be preserved.
+    #[test]
+    fn test_table_options_round_trip() {
+        let options = TableOptions {
+            max_column_width: Some(8),
+            borders: true,
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(json, r#"{"max_column_width":8,"borders":true}"#);
+        assert_eq!(
+            serde_json::from_str::<TableOptions>(&json).unwrap(),
+            options
+        );
+    }
+}
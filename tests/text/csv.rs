@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::text::csv::{csv_escape_field, csv_join_row, csv_split_line};
+    use std::borrow::Cow;
+
+    /// ✅ Plain fields are borrowed unchanged.
+    /// Expects `Cow::Borrowed` and no quoting.
+    #[test]
+    fn test_escape_plain_is_borrowed() {
+        assert!(matches!(
+            csv_escape_field("hello world"),
+            Cow::Borrowed("hello world")
+        ));
+        assert!(matches!(csv_escape_field(""), Cow::Borrowed("")));
+    }
+
+    /// ✅ Special characters trigger quoting.
+    /// Expects commas, quotes, and line breaks to be wrapped in quotes.
+    #[test]
+    fn test_escape_special_characters() {
+        assert_eq!(csv_escape_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_escape_field("cr\r"), "\"cr\r\"");
+        assert_eq!(csv_escape_field("\"quoted\""), "\"\"\"quoted\"\"\"");
+    }
+
+    /// ✅ Joins fields with commas, escaping as needed.
+    /// Expects a single CSV line without a terminator.
+    #[test]
+    fn test_join_row() {
+        let row = vec!["1".to_string(), "x,y".to_string(), "".to_string()];
+        assert_eq!(csv_join_row(&row), "1,\"x,y\",");
+    }
+
+    /// ✅ Joining an empty slice.
+    /// Expects an empty string.
+    #[test]
+    fn test_join_empty() {
+        let empty: [&str; 0] = [];
+        assert_eq!(csv_join_row(&empty), "");
+    }
+
+    /// ✅ Splits unquoted fields, preserving empty ones and whitespace.
+    /// Expects one entry per comma-separated field.
+    #[test]
+    fn test_split_unquoted() {
+        assert_eq!(csv_split_line("a, b,,c").unwrap(), vec!["a", " b", "", "c"]);
+        assert_eq!(csv_split_line("").unwrap(), vec![""]);
+        assert_eq!(csv_split_line("a,").unwrap(), vec!["a", ""]);
+    }
+
+    /// ✅ Splits quoted fields containing separators, quotes, and newlines.
+    /// Expects quotes removed and `""` unescaped.
+    #[test]
+    fn test_split_quoted() {
+        let fields = csv_split_line("\"a,b\",\"c\"\"d\",\"e\nf\",\"\"").unwrap();
+        assert_eq!(fields, vec!["a,b", "c\"d", "e\nf", ""]);
+    }
+
+    /// ✅ A single trailing line terminator is ignored.
+    /// Expects the same fields for `\n` and `\r\n` endings.
+    #[test]
+    fn test_split_strips_line_terminator() {
+        assert_eq!(csv_split_line("a,b\n").unwrap(), vec!["a", "b"]);
+        assert_eq!(csv_split_line("a,\"b\"\r\n").unwrap(), vec!["a", "b"]);
+    }
+
+    /// ✅ Quotes inside an unquoted field are literal.
+    /// Expects the quote characters to be kept.
+    #[test]
+    fn test_split_literal_quote_in_unquoted_field() {
+        assert_eq!(csv_split_line("5\" pipe,x").unwrap(), vec!["5\" pipe", "x"]);
+    }
+
+    /// ✅ Malformed lines.
+    /// Expects an error for unterminated quotes and trailing junk after a closing quote.
+    #[test]
+    fn test_split_malformed() {
+        assert!(csv_split_line("\"open").is_err());
+        assert!(csv_split_line("\"closed\"junk,x").is_err());
+    }
+
+    /// ✅ Round-trips through join and split.
+    /// Expects the original fields back.
+    #[test]
+    fn test_round_trip() {
+        let fields = vec!["plain", "with,comma", "with \"quote\"", "multi\nline", ""];
+        let line = csv_join_row(&fields);
+        assert_eq!(csv_split_line(&line).unwrap(), fields);
+    }
+}
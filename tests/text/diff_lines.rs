@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::array::diff_ops::DiffOp;
+    use pencil_box::text::diff_lines::{diff_lines, UnifiedDiff};
+
+    /// ✅ Identical texts produce no changes.
+    /// Expects only `Equal` lines and an empty unified diff.
+    #[test]
+    fn test_identical_texts() {
+        let diff = diff_lines("a\nb\n", "a\nb");
+        assert!(diff.iter().all(|op| !op.is_change()));
+        let unified = UnifiedDiff {
+            lines: &diff,
+            context: 3,
+        };
+        assert_eq!(unified.to_string(), "");
+    }
+
+    /// ✅ CRLF and LF line endings compare equal.
+    /// Expects no changes between the two styles.
+    #[test]
+    fn test_line_endings_ignored() {
+        let diff = diff_lines("a\r\nb\r\n", "a\nb\n");
+        assert_eq!(diff, vec![DiffOp::Equal("a"), DiffOp::Equal("b")]);
+    }
+
+    /// ✅ A single changed line renders as a unified hunk with context.
+    /// Expects a header with 1-based ranges and surrounding context lines.
+    #[test]
+    fn test_unified_single_hunk() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n";
+        let new = "1\n2\n3\nfour\n5\n6\n7\n";
+        let diff = diff_lines(old, new);
+        let unified = UnifiedDiff {
+            lines: &diff,
+            context: 2,
+        };
+        assert_eq!(
+            unified.to_string(),
+            "@@ -2,5 +2,5 @@\n 2\n 3\n-4\n+four\n 5\n 6\n"
+        );
+    }
+
+    /// ✅ Distant changes are split into separate hunks; close ones are merged.
+    /// Expects two hunks for far-apart edits and one when the gap fits the context.
+    #[test]
+    fn test_unified_hunk_grouping() {
+        let old: String = (1..=10).map(|n| format!("{n}\n")).collect();
+        let new = old.replace("2\n", "two\n").replace("9\n", "nine\n");
+        let diff = diff_lines(&old, &new);
+
+        let split = UnifiedDiff {
+            lines: &diff,
+            context: 1,
+        }
+        .to_string();
+        assert_eq!(split.matches("@@ -").count(), 2);
+        assert!(split.starts_with("@@ -1,3 +1,3 @@\n 1\n-2\n+two\n 3\n"));
+        assert!(split.ends_with("@@ -8,3 +8,3 @@\n 8\n-9\n+nine\n 10\n"));
+
+        let merged = UnifiedDiff {
+            lines: &diff,
+            context: 3,
+        }
+        .to_string();
+        assert_eq!(merged.matches("@@ -").count(), 1);
+        assert!(merged.starts_with("@@ -1,10 +1,10 @@\n"));
+    }
+
+    /// ✅ Pure insertions and deletions use zero-length ranges.
+    /// Expects the `start,0` form pointing at the preceding line.
+    #[test]
+    fn test_unified_empty_ranges() {
+        let diff = diff_lines("", "new\n");
+        let unified = UnifiedDiff {
+            lines: &diff,
+            context: 3,
+        };
+        assert_eq!(unified.to_string(), "@@ -0,0 +1,1 @@\n+new\n");
+
+        let diff = diff_lines("a\nb\n", "a\n");
+        let unified = UnifiedDiff {
+            lines: &diff,
+            context: 0,
+        };
+        assert_eq!(unified.to_string(), "@@ -2,1 +1,0 @@\n-b\n");
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::text::format_table::{format_table, TableOptions};
+
+    /// ✅ Aligns columns without borders.
+    /// Expects every column padded to its widest cell, separated by two spaces.
+    #[test]
+    fn test_plain_alignment() {
+        let rows = vec![
+            vec!["a".to_string(), "bbb".to_string(), "c".to_string()],
+            vec!["dddd".to_string(), "e".to_string(), "ff".to_string()],
+        ];
+        assert_eq!(
+            format_table(&rows, TableOptions::default()),
+            "a     bbb  c\ndddd  e    ff"
+        );
+    }
+
+    /// ✅ Draws borders around every cell.
+    /// Expects a framed table with rules above and below.
+    #[test]
+    fn test_borders() {
+        let rows = vec![vec!["x", "yy"], vec!["zzz", ""]];
+        let options = TableOptions {
+            borders: true,
+            ..TableOptions::default()
+        };
+        assert_eq!(
+            format_table(&rows, options),
+            "+-----+----+\n| x   | yy |\n| zzz |    |\n+-----+----+"
+        );
+    }
+
+    /// ✅ Truncates long cells with an ellipsis.
+    /// Expects cells to be cut to the maximum width, including the `…`.
+    #[test]
+    fn test_truncation() {
+        let rows = vec![vec!["abcdefgh", "ok"], vec!["abc", "fine"]];
+        let options = TableOptions {
+            max_column_width: Some(4),
+            ..TableOptions::default()
+        };
+        assert_eq!(format_table(&rows, options), "abc…  ok\nabc   fine");
+    }
+
+    /// ✅ Truncation counts characters, not bytes.
+    /// Expects multi-byte characters to be cut on character boundaries.
+    #[test]
+    fn test_truncation_unicode() {
+        let rows = vec![vec!["héllo wörld"]];
+        let options = TableOptions {
+            max_column_width: Some(5),
+            ..TableOptions::default()
+        };
+        assert_eq!(format_table(&rows, options), "héll…");
+    }
+
+    /// ✅ Zero and one character maximum widths.
+    /// Expects empty cells and a lone ellipsis respectively.
+    #[test]
+    fn test_tiny_max_width() {
+        let rows = vec![vec!["abc", "d"]];
+        let zero = TableOptions {
+            max_column_width: Some(0),
+            borders: true,
+        };
+        assert_eq!(format_table(&rows, zero), "+--+--+\n|  |  |\n+--+--+");
+
+        let one = TableOptions {
+            max_column_width: Some(1),
+            ..TableOptions::default()
+        };
+        assert_eq!(format_table(&rows, one), "…  d");
+    }
+
+    /// ✅ Ragged rows are padded with empty cells.
+    /// Expects short rows to be filled and trailing whitespace trimmed.
+    #[test]
+    fn test_ragged_rows() {
+        let rows: Vec<Vec<&str>> = vec![vec!["a", "b", "c"], vec!["d"]];
+        assert_eq!(format_table(&rows, TableOptions::default()), "a  b  c\nd");
+    }
+
+    /// ✅ Empty input.
+    /// Expects an empty string, with or without borders.
+    #[test]
+    fn test_empty_input() {
+        let rows: Vec<Vec<String>> = vec![];
+        assert_eq!(format_table(&rows, TableOptions::default()), "");
+
+        let empty_rows: Vec<Vec<String>> = vec![vec![], vec![]];
+        let options = TableOptions {
+            borders: true,
+            ..TableOptions::default()
+        };
+        assert_eq!(format_table(&empty_rows, options), "");
+    }
+}
@@ -0,0 +1,3 @@
+mod csv;
+mod diff_lines;
+mod format_table;
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use pencil_box::url::query::{decode_query, encode_query};
+
+    /// ✅ Encodes simple pairs.
+    /// Expects `key=value` pairs joined with `&`.
+    #[test]
+    fn test_encode_simple() {
+        assert_eq!(encode_query(&[("a", "1"), ("b", "2")]), "a=1&b=2");
+    }
+
+    /// ✅ Encodes reserved characters, spaces, and non-ASCII text.
+    /// Expects uppercase `%XX` escapes and unreserved characters untouched.
+    #[test]
+    fn test_encode_escapes() {
+        let pairs = vec![
+            ("k y".to_string(), "a&b=c+d".to_string()),
+            ("café".to_string(), "-._~".to_string()),
+        ];
+        assert_eq!(encode_query(&pairs), "k%20y=a%26b%3Dc%2Bd&caf%C3%A9=-._~");
+    }
+
+    /// ✅ Encoding an empty slice.
+    /// Expects an empty string.
+    #[test]
+    fn test_encode_empty() {
+        let pairs: [(&str, &str); 0] = [];
+        assert_eq!(encode_query(&pairs), "");
+    }
+
+    /// ✅ Decodes escapes, `+`, and a leading `?`.
+    /// Expects the original text.
+    #[test]
+    fn test_decode_escapes() {
+        assert_eq!(
+            decode_query("?name=J%C3%BCrgen+M&x=%2b"),
+            vec![
+                ("name".to_string(), "Jürgen M".to_string()),
+                ("x".to_string(), "+".to_string()),
+            ]
+        );
+    }
+
+    /// ✅ Segments without `=`, empty segments, and `=` inside values.
+    /// Expects empty values, skipped segments, and a split on the first `=` only.
+    #[test]
+    fn test_decode_segment_shapes() {
+        assert_eq!(
+            decode_query("flag&&a=b=c&=v"),
+            vec![
+                ("flag".to_string(), String::new()),
+                ("a".to_string(), "b=c".to_string()),
+                (String::new(), "v".to_string()),
+            ]
+        );
+    }
+
+    /// ✅ Malformed escapes are kept literally.
+    /// Expects `%` sequences that are not valid hex to pass through unchanged.
+    #[test]
+    fn test_decode_malformed_escapes() {
+        assert_eq!(
+            decode_query("a=%G1&b=%4&c=100%"),
+            vec![
+                ("a".to_string(), "%G1".to_string()),
+                ("b".to_string(), "%4".to_string()),
+                ("c".to_string(), "100%".to_string()),
+            ]
+        );
+    }
+
+    /// ✅ Invalid UTF-8 after decoding.
+    /// Expects the replacement character instead of a panic.
+    #[test]
+    fn test_decode_invalid_utf8() {
+        assert_eq!(
+            decode_query("x=%FF"),
+            vec![("x".to_string(), "\u{FFFD}".to_string())]
+        );
+    }
+
+    /// ✅ Decoding an empty string.
+    /// Expects no pairs.
+    #[test]
+    fn test_decode_empty() {
+        assert!(decode_query("").is_empty());
+        assert!(decode_query("?").is_empty());
+    }
+
+    /// ✅ Round-trips through encode and decode.
+    /// Expects the original pairs back, including duplicates and order.
+    #[test]
+    fn test_round_trip() {
+        let pairs = vec![
+            ("q".to_string(), "a b&c".to_string()),
+            ("q".to_string(), "100% ✓".to_string()),
+            ("empty".to_string(), String::new()),
+        ];
+        assert_eq!(decode_query(&encode_query(&pairs)), pairs);
+    }
+}